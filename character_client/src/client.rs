@@ -0,0 +1,775 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use grpcio::{ChannelBuilder, ChannelCredentialsBuilder, EnvBuilder};
+use proto_rs::{
+    character::Character,
+    characters::{
+        CharactersClient, CreateCharacter, ReadLatestRevision, ReadRevision,
+        WriteRevision,
+    },
+};
+use tracing::warn;
+
+use crate::{
+    outbox::Outbox, transport::GrpcTransport, Error, ErrorCode, RetryPolicy,
+    Transport,
+};
+
+// ClientBuilder configures how a Client connects to the character service.
+pub struct ClientBuilder {
+    endpoint: String,
+    tls: bool,
+    root_cert: Option<Vec<u8>>,
+    identity: Option<(Vec<u8>, Vec<u8>)>,
+    auth_token: Option<String>,
+    timeout: Duration,
+    retry: RetryPolicy,
+    outbox: Option<PathBuf>,
+}
+
+impl ClientBuilder {
+    // new ClientBuilder connecting to endpoint, e.g. 127.0.0.1:8080.
+    pub fn new(endpoint: &str) -> ClientBuilder {
+        ClientBuilder {
+            endpoint: endpoint.to_owned(),
+            tls: false,
+            root_cert: None,
+            identity: None,
+            auth_token: None,
+            timeout: Duration::from_secs(10),
+            retry: RetryPolicy::default(),
+            outbox: None,
+        }
+    }
+
+    // tls connects over TLS, verifying the server with the system roots.
+    pub fn tls(mut self) -> ClientBuilder {
+        self.tls = true;
+        self
+    }
+
+    // root_cert connects over TLS, verifying the server with the PEM encoded
+    // CA certificate.
+    pub fn root_cert(mut self, cert: Vec<u8>) -> ClientBuilder {
+        self.tls = true;
+        self.root_cert = Some(cert);
+        self
+    }
+
+    // client_identity presents the PEM encoded certificate and key to servers
+    // requiring mutual TLS.
+    pub fn client_identity(
+        mut self,
+        cert: Vec<u8>,
+        key: Vec<u8>,
+    ) -> ClientBuilder {
+        self.tls = true;
+        self.identity = Some((cert, key));
+        self
+    }
+
+    // auth_token is sent as bearer token with every call.
+    pub fn auth_token(mut self, token: &str) -> ClientBuilder {
+        self.auth_token = Some(token.to_owned());
+        self
+    }
+
+    // timeout of every call attempt.
+    pub fn timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn retry(mut self, retry: RetryPolicy) -> ClientBuilder {
+        self.retry = retry;
+        self
+    }
+
+    // outbox persists writes failing with transient errors in the directory
+    // to replay them later. Without an outbox these writes fail.
+    pub fn outbox(mut self, root: impl Into<PathBuf>) -> ClientBuilder {
+        self.outbox = Some(root.into());
+        self
+    }
+
+    pub fn build(self) -> Result<Client<GrpcTransport>, Error> {
+        let env = Arc::new(EnvBuilder::new().build());
+        let mut channel = ChannelBuilder::new(env);
+        if self.tls {
+            let mut credentials = ChannelCredentialsBuilder::new();
+            if let Some(cert) = self.root_cert.clone() {
+                credentials = credentials.root_cert(cert);
+            }
+            if let Some((cert, key)) = self.identity.clone() {
+                credentials = credentials.cert(cert, key);
+            }
+            channel = channel.set_credentials(credentials.build());
+        }
+
+        let transport = GrpcTransport::new(
+            CharactersClient::new(channel.connect(&self.endpoint)),
+            self.auth_token.clone(),
+            self.timeout,
+        );
+        self.build_with(transport)
+    }
+
+    // build_with creates a Client sending its calls through transport, the
+    // connection settings are ignored.
+    pub fn build_with<T: Transport>(
+        self,
+        transport: T,
+    ) -> Result<Client<T>, Error> {
+        let outbox = match self.outbox {
+            Some(root) => Some(tokio::sync::Mutex::new(Outbox::open(root)?)),
+            None => None,
+        };
+
+        Ok(Client {
+            transport,
+            retry: self.retry,
+            revisions: Mutex::new(HashMap::new()),
+            outbox,
+        })
+    }
+}
+
+// Write is the outcome of writing a revision.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Write {
+    // Written as the given revision.
+    Written(u64),
+    // Queued in the outbox until the service is reachable again.
+    Queued,
+}
+
+// Replay summarizes sending the writes queued in the outbox.
+#[derive(Debug, Default, PartialEq)]
+pub struct Replay {
+    pub sent: usize,
+    // Rejected writes are removed from the outbox.
+    pub rejected: Vec<Error>,
+    // Writes still queued because the service is unavailable.
+    pub queued: usize,
+}
+
+// Client is a typed client for the character service. Revision numbers are
+// assigned automatically and transient failures are retried.
+pub struct Client<T = GrpcTransport> {
+    transport: T,
+    retry: RetryPolicy,
+    // Latest known revision per character, None for characters without
+    // revisions.
+    revisions: Mutex<HashMap<String, Option<u64>>>,
+    outbox: Option<tokio::sync::Mutex<Outbox>>,
+}
+
+impl<T: Transport> Client<T> {
+    // create a new character, returning its uuid.
+    pub async fn create(&self) -> Result<String, Error> {
+        let created = self
+            .retry
+            .run(|| self.transport.create(CreateCharacter {}))
+            .await?;
+
+        self.set_latest_revision(&created.uuid, None);
+        Ok(created.uuid)
+    }
+
+    pub async fn read(
+        &self,
+        uuid: &str,
+        revision: u64,
+    ) -> Result<Character, Error> {
+        let req = ReadRevision {
+            uuid: uuid.to_owned(),
+            revision,
+        };
+        let read = self
+            .retry
+            .run(|| self.transport.read_revision(req.clone()))
+            .await?;
+
+        Ok(read.character.unwrap_or_default())
+    }
+
+    // read_latest returns the latest revision of the character and its
+    // number.
+    pub async fn read_latest(
+        &self,
+        uuid: &str,
+    ) -> Result<(u64, Character), Error> {
+        let req = ReadLatestRevision {
+            uuid: uuid.to_owned(),
+        };
+        let read = self
+            .retry
+            .run(|| self.transport.read_latest_revision(req.clone()))
+            .await?;
+
+        self.set_latest_revision(uuid, Some(read.revision));
+        Ok((read.revision, read.character.unwrap_or_default()))
+    }
+
+    // write character as the next revision. Writes conflicting with revisions
+    // of other clients fail with OutOfOrder, call read_latest to merge them
+    // before writing again. If the service is unavailable and an outbox is
+    // configured, the write is queued instead. Writes are queued as long as
+    // the outbox isn't empty to keep them in order. Writes exceeding their
+    // deadline might have been applied, so they are neither retried nor
+    // queued, call read_latest to find out.
+    pub async fn write(
+        &self,
+        uuid: &str,
+        character: Character,
+    ) -> Result<Write, Error> {
+        let write = WriteRevision {
+            uuid: uuid.to_owned(),
+            character: Some(character),
+            ..Default::default()
+        };
+
+        let mut outbox = match self.outbox.as_ref() {
+            Some(outbox) => outbox.lock().await,
+            None => {
+                return self.send(self.next(write)).await.map(Write::Written)
+            }
+        };
+
+        self.replay_outbox(&mut outbox).await?;
+        let write = self.next(write);
+        if !outbox.is_empty() {
+            return self.queue(&mut outbox, write);
+        }
+
+        match self.send(write.clone()).await {
+            Err(err) if err.is_unavailable() => {
+                warn!(err = %err, uuid, "queueing write");
+                self.queue(&mut outbox, write)
+            }
+            res => res.map(Write::Written),
+        }
+    }
+
+    // replay sends the writes queued in the outbox, stopping at the first
+    // transient failure. Writes found already applied by an earlier attempt
+    // count as sent.
+    pub async fn replay(&self) -> Result<Replay, Error> {
+        match self.outbox.as_ref() {
+            Some(outbox) => self.replay_outbox(&mut *outbox.lock().await).await,
+            None => Ok(Replay::default()),
+        }
+    }
+
+    async fn replay_outbox(
+        &self,
+        outbox: &mut Outbox,
+    ) -> Result<Replay, Error> {
+        let mut replay = Replay::default();
+        while let Some(write) = outbox.front()? {
+            match self.send(write.clone()).await {
+                Ok(_) => replay.sent += 1,
+                Err(err) if err.is_transient() => break,
+                Err(err) => match self.applied(&write, &err).await {
+                    Ok(true) => replay.sent += 1,
+                    Ok(false) => {
+                        warn!(err = %err, "dropping rejected write");
+                        replay.rejected.push(err);
+                    }
+                    Err(err) if err.is_transient() => break,
+                    Err(err) => return Err(err),
+                },
+            }
+            outbox.pop()?;
+        }
+        replay.queued = outbox.len();
+
+        Ok(replay)
+    }
+
+    // applied is true if write was rejected as out of order because an
+    // earlier attempt, which exceeded its deadline, already wrote it.
+    async fn applied(
+        &self,
+        write: &WriteRevision,
+        err: &Error,
+    ) -> Result<bool, Error> {
+        if err.code() != ErrorCode::OutOfOrder
+            || err.latest_revision() != Some(write.revision)
+        {
+            return Ok(false);
+        }
+        let written = self.read(&write.uuid, write.revision).await?;
+        Ok(Some(written) == write.character)
+    }
+
+    // next sets the revision of write to the one following the latest known
+    // revision.
+    fn next(&self, mut write: WriteRevision) -> WriteRevision {
+        write.revision = self.next_revision(&write.uuid);
+        write
+    }
+
+    // queue write in the outbox. Its revision is kept, so writes of other
+    // clients in between are detected on replay instead of overwritten.
+    fn queue(
+        &self,
+        outbox: &mut Outbox,
+        write: WriteRevision,
+    ) -> Result<Write, Error> {
+        outbox.push(&write)?;
+        self.set_latest_revision(&write.uuid, Some(write.revision));
+        Ok(Write::Queued)
+    }
+
+    // send write, failing with OutOfOrder and the latest revision if another
+    // client wrote revisions we didn't know about yet.
+    async fn send(&self, write: WriteRevision) -> Result<u64, Error> {
+        let written = self.write_revision(&write).await?;
+
+        self.set_latest_revision(&write.uuid, Some(written));
+        Ok(written)
+    }
+
+    async fn write_revision(
+        &self,
+        write: &WriteRevision,
+    ) -> Result<u64, Error> {
+        // Only calls which didn't reach the service are retried, a write
+        // exceeding its deadline might have been applied.
+        let written = self
+            .retry
+            .run_if(Error::is_unavailable, || {
+                self.transport.write_revision(write.clone())
+            })
+            .await?;
+
+        Ok(written.revision)
+    }
+
+    // next_revision of the character, unknown characters start at 0.
+    fn next_revision(&self, uuid: &str) -> u64 {
+        // unwrapping the lock() here to panic on a poisoned lock
+        match self.revisions.lock().unwrap().get(uuid) {
+            Some(Some(revision)) => revision + 1,
+            _ => 0,
+        }
+    }
+
+    fn set_latest_revision(&self, uuid: &str, revision: Option<u64>) {
+        self.revisions
+            .lock()
+            .unwrap()
+            .insert(uuid.to_owned(), revision);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashMap,
+        future::ready,
+        sync::{Arc, Mutex},
+    };
+
+    use grpcio::{RpcStatus, RpcStatusCode};
+    use proto_rs::{
+        character::Character,
+        characters::{
+            error_details::Reason, CharacterCreated, CreateCharacter,
+            ErrorDetails, ReadLatestRevision, ReadRevision, RevisionRead,
+            RevisionWritten, WriteRevision,
+        },
+        details::rpc_status,
+    };
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::{Client, ClientBuilder, Replay, Write};
+    use crate::{transport::Call, Error, ErrorCode, Transport};
+
+    // Service fakes the character service, keeping revisions in memory.
+    #[derive(Default)]
+    struct Service {
+        characters: Mutex<HashMap<String, Vec<Character>>>,
+        // Calls fail with Unavailable while the service is offline.
+        offline: Mutex<bool>,
+        // The next calls failing with Unavailable.
+        failures: Mutex<usize>,
+        // The next writes failing with DeadlineExceeded after being applied.
+        deadlines: Mutex<usize>,
+        writes: Mutex<usize>,
+    }
+
+    impl Service {
+        fn set_offline(&self, offline: bool) {
+            *self.offline.lock().unwrap() = offline;
+        }
+
+        fn add_character(&self, uuid: &str, revisions: usize) {
+            self.characters
+                .lock()
+                .unwrap()
+                .insert(uuid.to_owned(), vec![character(""); revisions]);
+        }
+
+        fn revisions(&self, uuid: &str) -> Vec<Character> {
+            self.characters.lock().unwrap()[uuid].clone()
+        }
+
+        fn check_available(&self) -> Result<(), Error> {
+            let mut failures = self.failures.lock().unwrap();
+            if *self.offline.lock().unwrap() || *failures > 0 {
+                *failures = failures.saturating_sub(1);
+                return Err(RpcStatus::with_message(
+                    RpcStatusCode::UNAVAILABLE,
+                    "offline".to_owned(),
+                )
+                .into());
+            }
+            Ok(())
+        }
+
+        fn write(&self, req: WriteRevision) -> Result<RevisionWritten, Error> {
+            self.check_available()?;
+            *self.writes.lock().unwrap() += 1;
+
+            let mut characters = self.characters.lock().unwrap();
+            let revisions = characters.get_mut(&req.uuid).ok_or_else(|| {
+                RpcStatus::with_message(
+                    RpcStatusCode::NOT_FOUND,
+                    "character does not exist".to_owned(),
+                )
+            })?;
+            if req.revision as usize != revisions.len() {
+                let details = ErrorDetails {
+                    reason: Reason::OutOfOrder.into(),
+                    latest_revision: revisions.len() as u64 - 1,
+                    ..Default::default()
+                };
+                return Err(rpc_status(
+                    RpcStatusCode::INVALID_ARGUMENT,
+                    "out of order".to_owned(),
+                    &details,
+                )
+                .into());
+            }
+            revisions.push(req.character.unwrap_or_default());
+
+            let mut deadlines = self.deadlines.lock().unwrap();
+            if *deadlines > 0 {
+                *deadlines -= 1;
+                return Err(RpcStatus::with_message(
+                    RpcStatusCode::DEADLINE_EXCEEDED,
+                    "deadline exceeded".to_owned(),
+                )
+                .into());
+            }
+            Ok(RevisionWritten {
+                uuid: req.uuid,
+                revision: req.revision,
+            })
+        }
+
+        fn read(
+            &self,
+            uuid: &str,
+            revision: u64,
+        ) -> Result<RevisionRead, Error> {
+            self.check_available()?;
+
+            let characters = self.characters.lock().unwrap();
+            Ok(RevisionRead {
+                uuid: uuid.to_owned(),
+                revision,
+                character: Some(characters[uuid][revision as usize].clone()),
+            })
+        }
+    }
+
+    impl Transport for Arc<Service> {
+        fn create(&self, _: CreateCharacter) -> Call<CharacterCreated> {
+            let res = self.check_available().map(|_| {
+                let uuid = format!("{}", self.characters.lock().unwrap().len());
+                self.add_character(&uuid, 0);
+                CharacterCreated { uuid }
+            });
+            Box::pin(ready(res))
+        }
+
+        fn write_revision(&self, req: WriteRevision) -> Call<RevisionWritten> {
+            Box::pin(ready(self.write(req)))
+        }
+
+        fn read_revision(&self, req: ReadRevision) -> Call<RevisionRead> {
+            Box::pin(ready(self.read(&req.uuid, req.revision)))
+        }
+
+        fn read_latest_revision(
+            &self,
+            req: ReadLatestRevision,
+        ) -> Call<RevisionRead> {
+            let latest = self.revisions(&req.uuid).len() as u64 - 1;
+            Box::pin(ready(self.read(&req.uuid, latest)))
+        }
+    }
+
+    fn character(name: &str) -> Character {
+        Character {
+            name: name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn client(service: &Arc<Service>) -> Client<Arc<Service>> {
+        ClientBuilder::new("").build_with(service.clone()).unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn increments_revisions() {
+        let service = Arc::new(Service::default());
+        let client = client(&service);
+
+        let uuid = client.create().await.unwrap();
+        for revision in 0..3 {
+            assert_eq!(
+                client.write(&uuid, character("a")).await.unwrap(),
+                Write::Written(revision)
+            );
+        }
+
+        assert_eq!(service.revisions(&uuid).len(), 3);
+        assert_eq!(*service.writes.lock().unwrap(), 3);
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn rejects_writes_over_unknown_revisions() {
+        let service = Arc::new(Service::default());
+        service.add_character("a", 3);
+        let client = client(&service);
+
+        let err = client.write("a", character("a")).await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::OutOfOrder);
+        assert_eq!(err.latest_revision(), Some(2));
+        assert_eq!(
+            client.write("a", character("a")).await.unwrap_err().code(),
+            ErrorCode::OutOfOrder,
+            "should not overwrite after a conflict"
+        );
+        assert_eq!(service.revisions("a").len(), 3);
+
+        client.read_latest("a").await.unwrap();
+        assert_eq!(
+            client.write("a", character("a")).await.unwrap(),
+            Write::Written(3)
+        );
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn rejects_writes_conflicting_with_other_clients() {
+        let service = Arc::new(Service::default());
+        service.add_character("a", 1);
+        let first = client(&service);
+        let other = client(&service);
+        first.read_latest("a").await.unwrap();
+        other.read_latest("a").await.unwrap();
+
+        other.write("a", character("other")).await.unwrap();
+        assert_eq!(
+            first.write("a", character("a")).await.unwrap_err().code(),
+            ErrorCode::OutOfOrder
+        );
+        assert_eq!(service.revisions("a")[1].name, "other");
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn read_latest_updates_revision() {
+        let service = Arc::new(Service::default());
+        service.add_character("a", 2);
+        let client = client(&service);
+
+        let (revision, _) = client.read_latest("a").await.unwrap();
+        assert_eq!(revision, 1);
+        assert_eq!(
+            client.write("a", character("a")).await.unwrap(),
+            Write::Written(2)
+        );
+        assert_eq!(*service.writes.lock().unwrap(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn retries_transient_failures() {
+        let service = Arc::new(Service::default());
+        service.add_character("a", 0);
+        *service.failures.lock().unwrap() = 2;
+        let client = client(&service);
+
+        assert_eq!(
+            client.write("a", character("a")).await.unwrap(),
+            Write::Written(0)
+        );
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn does_not_retry_writes_exceeding_deadline() {
+        let outbox = tempdir().unwrap();
+        let service = Arc::new(Service::default());
+        service.add_character("a", 0);
+        *service.deadlines.lock().unwrap() = 1;
+        let client = ClientBuilder::new("")
+            .outbox(outbox.path())
+            .build_with(service.clone())
+            .unwrap();
+
+        assert_eq!(
+            client.write("a", character("a")).await.unwrap_err().code(),
+            ErrorCode::DeadlineExceeded
+        );
+        assert_eq!(*service.writes.lock().unwrap(), 1);
+        assert_eq!(client.replay().await.unwrap(), Replay::default());
+        assert_eq!(client.read_latest("a").await.unwrap().1, character("a"));
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn replays_writes_applied_before_deadline() {
+        let outbox = tempdir().unwrap();
+        let service = Arc::new(Service::default());
+        service.add_character("a", 0);
+        let client = ClientBuilder::new("")
+            .outbox(outbox.path())
+            .build_with(service.clone())
+            .unwrap();
+
+        service.set_offline(true);
+        client.write("a", character("a")).await.unwrap();
+        service.set_offline(false);
+        *service.deadlines.lock().unwrap() = 1;
+        assert_eq!(client.replay().await.unwrap().queued, 1);
+
+        assert_eq!(
+            client.replay().await.unwrap(),
+            Replay {
+                sent: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(service.revisions("a"), vec![character("a")]);
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn fails_without_outbox() {
+        let service = Arc::new(Service::default());
+        service.add_character("a", 0);
+        service.set_offline(true);
+        let client = client(&service);
+
+        assert_eq!(
+            client.write("a", character("a")).await.unwrap_err().code(),
+            ErrorCode::Unavailable
+        );
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn replays_queued_writes() {
+        let outbox = tempdir().unwrap();
+        let service = Arc::new(Service::default());
+        service.add_character("a", 1);
+
+        {
+            let client = ClientBuilder::new("")
+                .outbox(outbox.path())
+                .build_with(service.clone())
+                .unwrap();
+            client.read_latest("a").await.unwrap();
+            service.set_offline(true);
+            for name in ["b", "c"] {
+                assert_eq!(
+                    client.write("a", character(name)).await.unwrap(),
+                    Write::Queued
+                );
+            }
+            assert_eq!(client.replay().await.unwrap().queued, 2);
+        }
+
+        // The queued writes survive restarting the client.
+        service.set_offline(false);
+        let client = ClientBuilder::new("")
+            .outbox(outbox.path())
+            .build_with(service.clone())
+            .unwrap();
+        assert_eq!(
+            client.write("a", character("d")).await.unwrap(),
+            Write::Written(3)
+        );
+
+        let names: Vec<String> = service
+            .revisions("a")
+            .into_iter()
+            .map(|character| character.name)
+            .collect();
+        assert_eq!(names, vec!["", "b", "c", "d"]);
+        assert_eq!(client.replay().await.unwrap().queued, 0);
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn rejects_queued_writes_conflicting_with_other_clients() {
+        let outbox = tempdir().unwrap();
+        let service = Arc::new(Service::default());
+        service.add_character("a", 0);
+        let queued = ClientBuilder::new("")
+            .outbox(outbox.path())
+            .build_with(service.clone())
+            .unwrap();
+        let other = client(&service);
+
+        service.set_offline(true);
+        queued.write("a", character("queued")).await.unwrap();
+        service.set_offline(false);
+        other.write("a", character("other")).await.unwrap();
+
+        let replay = queued.replay().await.unwrap();
+        assert_eq!(replay.rejected.len(), 1);
+        assert_eq!(replay.rejected[0].code(), ErrorCode::OutOfOrder);
+        assert_eq!(replay.rejected[0].latest_revision(), Some(0));
+        assert_eq!(service.revisions("a")[0].name, "other");
+    }
+
+    #[rstest]
+    #[tokio::test(start_paused = true)]
+    async fn drops_rejected_writes() {
+        let outbox = tempdir().unwrap();
+        let service = Arc::new(Service::default());
+        service.add_character("a", 0);
+        service.set_offline(true);
+        let client = ClientBuilder::new("")
+            .outbox(outbox.path())
+            .build_with(service.clone())
+            .unwrap();
+
+        client.write("missing", character("a")).await.unwrap();
+        client.write("a", character("a")).await.unwrap();
+        service.set_offline(false);
+
+        let replay = client.replay().await.unwrap();
+        assert_eq!(replay.sent, 1);
+        assert_eq!(replay.queued, 0);
+        assert_eq!(replay.rejected.len(), 1);
+        assert_eq!(replay.rejected[0].code(), ErrorCode::NotFound);
+        assert_eq!(service.revisions("a").len(), 1);
+    }
+}
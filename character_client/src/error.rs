@@ -0,0 +1,231 @@
+use std::fmt::Display;
+
+use grpcio::{RpcStatus, RpcStatusCode};
+use proto_rs::{
+    characters::{error_details::Reason, FieldViolation},
+    details::error_details,
+};
+
+// ErrorCode mirrors the error codes of the character service.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ErrorCode {
+    Unknown,
+    NotFound,
+    Internal,
+    Unauthorized,
+    OutOfOrder,
+    Exists,
+    Unavailable,
+    // DeadlineExceeded calls might still have been applied by the service.
+    DeadlineExceeded,
+    InvalidArgument,
+    ResourceExhausted,
+    // Storage errors are failures to access the local outbox.
+    Storage,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Error {
+    code: ErrorCode,
+    message: String,
+    latest_revision: Option<u64>,
+    violations: Vec<FieldViolation>,
+}
+
+impl Error {
+    pub fn new(code: ErrorCode, message: &str) -> Error {
+        Error {
+            code,
+            message: message.to_owned(),
+            latest_revision: None,
+            violations: vec![],
+        }
+    }
+
+    pub fn message(&self) -> String {
+        self.message.to_owned()
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    // latest_revision of the character, known if a revision was written out
+    // of order.
+    pub fn latest_revision(&self) -> Option<u64> {
+        self.latest_revision
+    }
+
+    // violations lists the invalid fields of a rejected revision.
+    pub fn violations(&self) -> &[FieldViolation] {
+        &self.violations
+    }
+
+    // is_transient is true for errors that might not occur when retrying the
+    // call later.
+    pub fn is_transient(&self) -> bool {
+        self.is_unavailable() || self.code == ErrorCode::DeadlineExceeded
+    }
+
+    // is_unavailable is true for calls which didn't reach the service, so
+    // even writes can be sent again.
+    pub fn is_unavailable(&self) -> bool {
+        self.code == ErrorCode::Unavailable
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<RpcStatus> for Error {
+    fn from(status: RpcStatus) -> Self {
+        let mut err = Error::new(status.code().into(), status.message());
+
+        // Older servers don't send details, fall back to the status code.
+        if let Ok(Some(details)) = error_details(&status) {
+            err.code = details.reason().into();
+            if err.code == ErrorCode::OutOfOrder {
+                err.latest_revision = Some(details.latest_revision);
+            }
+            err.violations = details.field_violations;
+        }
+
+        err
+    }
+}
+
+impl From<grpcio::Error> for Error {
+    fn from(err: grpcio::Error) -> Self {
+        match err {
+            grpcio::Error::RpcFailure(status) => status.into(),
+            grpcio::Error::RemoteStopped => {
+                Error::new(ErrorCode::Unavailable, &err.to_string())
+            }
+            _ => Error::new(ErrorCode::Internal, &err.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::new(ErrorCode::Storage, &err.to_string())
+    }
+}
+
+impl From<prost::DecodeError> for Error {
+    fn from(err: prost::DecodeError) -> Self {
+        Error::new(ErrorCode::Storage, &err.to_string())
+    }
+}
+
+impl From<RpcStatusCode> for ErrorCode {
+    fn from(val: RpcStatusCode) -> Self {
+        match val {
+            RpcStatusCode::NOT_FOUND => ErrorCode::NotFound,
+            RpcStatusCode::INTERNAL => ErrorCode::Internal,
+            RpcStatusCode::PERMISSION_DENIED
+            | RpcStatusCode::UNAUTHENTICATED => ErrorCode::Unauthorized,
+            RpcStatusCode::ALREADY_EXISTS => ErrorCode::Exists,
+            RpcStatusCode::UNAVAILABLE => ErrorCode::Unavailable,
+            RpcStatusCode::DEADLINE_EXCEEDED => ErrorCode::DeadlineExceeded,
+            RpcStatusCode::INVALID_ARGUMENT => ErrorCode::InvalidArgument,
+            RpcStatusCode::RESOURCE_EXHAUSTED => ErrorCode::ResourceExhausted,
+            _ => ErrorCode::Unknown,
+        }
+    }
+}
+
+impl From<Reason> for ErrorCode {
+    fn from(val: Reason) -> Self {
+        match val {
+            Reason::Unknown => ErrorCode::Unknown,
+            Reason::NotFound => ErrorCode::NotFound,
+            Reason::Internal => ErrorCode::Internal,
+            Reason::Unauthorized => ErrorCode::Unauthorized,
+            Reason::OutOfOrder => ErrorCode::OutOfOrder,
+            Reason::Exists => ErrorCode::Exists,
+            Reason::Unavailable => ErrorCode::Unavailable,
+            Reason::InvalidArgument => ErrorCode::InvalidArgument,
+            Reason::ResourceExhausted => ErrorCode::ResourceExhausted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use grpcio::{RpcStatus, RpcStatusCode};
+    use proto_rs::{
+        characters::{error_details::Reason, ErrorDetails, FieldViolation},
+        details::rpc_status,
+    };
+    use rstest::rstest;
+
+    use super::{Error, ErrorCode};
+
+    #[rstest]
+    fn maps_out_of_order_details() {
+        let details = ErrorDetails {
+            reason: Reason::OutOfOrder.into(),
+            latest_revision: 7,
+            ..Default::default()
+        };
+        let status = rpc_status(
+            RpcStatusCode::INVALID_ARGUMENT,
+            "out of order".to_owned(),
+            &details,
+        );
+
+        let err = Error::from(status);
+        assert_eq!(err.code(), ErrorCode::OutOfOrder);
+        assert_eq!(err.message(), "out of order");
+        assert_eq!(err.latest_revision(), Some(7));
+        assert!(!err.is_transient());
+    }
+
+    #[rstest]
+    fn maps_violations() {
+        let violation = FieldViolation {
+            field: "character.uuid".to_owned(),
+            ..Default::default()
+        };
+        let details = ErrorDetails {
+            reason: Reason::InvalidArgument.into(),
+            field_violations: vec![violation.clone()],
+            ..Default::default()
+        };
+        let status = rpc_status(
+            RpcStatusCode::INVALID_ARGUMENT,
+            "invalid character".to_owned(),
+            &details,
+        );
+
+        let err = Error::from(status);
+        assert_eq!(err.code(), ErrorCode::InvalidArgument);
+        assert_eq!(err.latest_revision(), None);
+        assert_eq!(err.violations(), &[violation]);
+    }
+
+    #[rstest]
+    #[case(RpcStatusCode::UNAVAILABLE, ErrorCode::Unavailable, true)]
+    #[case(RpcStatusCode::DEADLINE_EXCEEDED, ErrorCode::DeadlineExceeded, true)]
+    #[case(RpcStatusCode::NOT_FOUND, ErrorCode::NotFound, false)]
+    #[case(RpcStatusCode::PERMISSION_DENIED, ErrorCode::Unauthorized, false)]
+    #[case(RpcStatusCode::CANCELLED, ErrorCode::Unknown, false)]
+    fn maps_status_without_details(
+        #[case] status_code: RpcStatusCode,
+        #[case] code: ErrorCode,
+        #[case] transient: bool,
+    ) {
+        let err = Error::from(grpcio::Error::RpcFailure(
+            RpcStatus::with_message(status_code, "failed".to_owned()),
+        ));
+
+        assert_eq!(err.code(), code);
+        assert_eq!(err.is_transient(), transient);
+    }
+}
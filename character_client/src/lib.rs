@@ -0,0 +1,10 @@
+mod client;
+mod error;
+mod outbox;
+mod retry;
+mod transport;
+
+pub use client::{Client, ClientBuilder, Replay, Write};
+pub use error::{Error, ErrorCode};
+pub use retry::RetryPolicy;
+pub use transport::{Call, GrpcTransport, Transport};
@@ -0,0 +1,161 @@
+use std::{fs, io::Write, path::PathBuf};
+
+use prost::Message;
+use proto_rs::characters::WriteRevision;
+
+use crate::Error;
+
+const EXTENSION: &str = "pb";
+
+// Outbox persists writes that couldn't be sent, to replay them in order once
+// the service is reachable again. Every write is stored as a file named after
+// its position in the queue.
+pub struct Outbox {
+    root: PathBuf,
+    // Positions of the queued writes, oldest first.
+    queue: Vec<u64>,
+}
+
+impl Outbox {
+    // open the Outbox stored in root, creating it if necessary.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Outbox, Error> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+
+        let mut queue = vec![];
+        for entry in fs::read_dir(&root)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(EXTENSION)
+            {
+                continue;
+            }
+            if let Some(position) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse().ok())
+            {
+                queue.push(position);
+            }
+        }
+        queue.sort_unstable();
+
+        Ok(Outbox { root, queue })
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    // push write to the end of the queue. The write is synced to disk before
+    // returning.
+    pub fn push(&mut self, write: &WriteRevision) -> Result<(), Error> {
+        let position = self.queue.last().map_or(0, |last| last + 1);
+        let path = self.path(position);
+
+        // Writes are renamed into place so a crash never leaves a partially
+        // written entry behind.
+        let tmp = path.with_extension("tmp");
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(&write.encode_to_vec())?;
+        file.sync_all()?;
+        fs::rename(&tmp, &path)?;
+
+        self.queue.push(position);
+        Ok(())
+    }
+
+    // front returns the oldest queued write.
+    pub fn front(&self) -> Result<Option<WriteRevision>, Error> {
+        match self.queue.first() {
+            Some(position) => Ok(Some(WriteRevision::decode(
+                fs::read(self.path(*position))?.as_slice(),
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    // pop removes the oldest queued write.
+    pub fn pop(&mut self) -> Result<(), Error> {
+        if let Some(position) = self.queue.first() {
+            fs::remove_file(self.path(*position))?;
+            self.queue.remove(0);
+        }
+        Ok(())
+    }
+
+    fn path(&self, position: u64) -> PathBuf {
+        self.root.join(format!("{position:020}.{EXTENSION}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use proto_rs::characters::WriteRevision;
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::Outbox;
+
+    fn write(uuid: &str) -> WriteRevision {
+        WriteRevision {
+            uuid: uuid.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn queues_writes_in_order() {
+        let root = tempdir().unwrap();
+        let mut outbox = Outbox::open(root.path()).unwrap();
+        assert!(outbox.is_empty());
+        assert_eq!(outbox.front().unwrap(), None);
+
+        outbox.push(&write("a")).unwrap();
+        outbox.push(&write("b")).unwrap();
+        assert_eq!(outbox.len(), 2);
+
+        assert_eq!(outbox.front().unwrap(), Some(write("a")));
+        outbox.pop().unwrap();
+        assert_eq!(outbox.front().unwrap(), Some(write("b")));
+        outbox.pop().unwrap();
+        assert!(outbox.is_empty());
+        assert_eq!(fs::read_dir(root.path()).unwrap().count(), 0);
+    }
+
+    #[rstest]
+    fn persists_writes() {
+        let root = tempdir().unwrap();
+        {
+            let mut outbox = Outbox::open(root.path()).unwrap();
+            for uuid in ["a", "b", "c"] {
+                outbox.push(&write(uuid)).unwrap();
+            }
+            outbox.pop().unwrap();
+        }
+
+        let mut outbox = Outbox::open(root.path()).unwrap();
+        assert_eq!(outbox.len(), 2);
+        assert_eq!(outbox.front().unwrap(), Some(write("b")));
+
+        outbox.push(&write("d")).unwrap();
+        outbox.pop().unwrap();
+        outbox.pop().unwrap();
+        assert_eq!(outbox.front().unwrap(), Some(write("d")));
+    }
+
+    #[rstest]
+    fn ignores_partial_writes() {
+        let root = tempdir().unwrap();
+        fs::write(root.path().join("00000000000000000000.tmp"), b"partial")
+            .unwrap();
+
+        let outbox = Outbox::open(root.path()).unwrap();
+        assert!(outbox.is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+use std::{future::Future, time::Duration};
+
+use tracing::debug;
+
+use crate::Error;
+
+// RetryPolicy retries transient failures with exponential backoff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    // Calls are attempted at most max_attempts times, 1 disables retries.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    // backoff before the given retry, starting at 1 for the first retry.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.powf(f64::from(retry.saturating_sub(1)));
+        Duration::from_secs_f64(
+            (self.initial_backoff.as_secs_f64() * factor)
+                .min(self.max_backoff.as_secs_f64()),
+        )
+    }
+
+    // run call until it succeeds, fails with a permanent error or runs out of
+    // attempts.
+    pub async fn run<T, F, Fut>(&self, call: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        self.run_if(Error::is_transient, call).await
+    }
+
+    // run_if is run retrying only the errors for which retryable is true.
+    pub async fn run_if<T, F, Fut>(
+        &self,
+        retryable: fn(&Error) -> bool,
+        mut call: F,
+    ) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut retry = 0;
+        loop {
+            match call().await {
+                Err(err)
+                    if retryable(&err) && retry + 1 < self.max_attempts =>
+                {
+                    retry += 1;
+                    let backoff = self.backoff(retry);
+                    debug!(err = %err, retry, ?backoff, "retrying call");
+                    tokio::time::sleep(backoff).await;
+                }
+                res => return res,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, time::Duration};
+
+    use rstest::rstest;
+    use tokio::time::Instant;
+
+    use super::RetryPolicy;
+    use crate::{Error, ErrorCode};
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(3),
+            multiplier: 2.0,
+        }
+    }
+
+    #[rstest]
+    #[case(1, Duration::from_secs(1))]
+    #[case(2, Duration::from_secs(2))]
+    #[case(3, Duration::from_secs(3))]
+    #[case(10, Duration::from_secs(3))]
+    #[case(u32::MAX, Duration::from_secs(3))]
+    fn backoff(#[case] retry: u32, #[case] expected: Duration) {
+        assert_eq!(policy().backoff(retry), expected);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_transient_errors() {
+        let attempts = Cell::new(0);
+        let start = Instant::now();
+
+        let res = policy()
+            .run(|| async {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() < 3 {
+                    return Err(Error::new(ErrorCode::Unavailable, "offline"));
+                }
+                Ok(attempts.get())
+            })
+            .await;
+
+        assert_eq!(res, Ok(3));
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn gives_up_after_max_attempts() {
+        let attempts = Cell::new(0);
+
+        let res: Result<(), Error> = policy()
+            .run(|| async {
+                attempts.set(attempts.get() + 1);
+                Err(Error::new(ErrorCode::Unavailable, "offline"))
+            })
+            .await;
+
+        assert_eq!(res.unwrap_err().code(), ErrorCode::Unavailable);
+        assert_eq!(attempts.get(), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn does_not_retry_permanent_errors() {
+        let attempts = Cell::new(0);
+
+        let res: Result<(), Error> = policy()
+            .run(|| async {
+                attempts.set(attempts.get() + 1);
+                Err(Error::new(ErrorCode::NotFound, "missing"))
+            })
+            .await;
+
+        assert_eq!(res.unwrap_err().code(), ErrorCode::NotFound);
+        assert_eq!(attempts.get(), 1);
+    }
+}
@@ -0,0 +1,101 @@
+use std::{future::Future, pin::Pin, time::Duration};
+
+use grpcio::{CallOption, ClientUnaryReceiver, MetadataBuilder};
+use proto_rs::characters::{
+    CharacterCreated, CharactersClient, CreateCharacter, ReadLatestRevision,
+    ReadRevision, RevisionRead, RevisionWritten, WriteRevision,
+};
+
+use crate::Error;
+
+pub type Call<T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send>>;
+
+// Transport sends single calls to the character service. The Client adds
+// retries, revision tracking and the outbox on top.
+pub trait Transport: Send + Sync {
+    fn create(&self, req: CreateCharacter) -> Call<CharacterCreated>;
+    fn write_revision(&self, req: WriteRevision) -> Call<RevisionWritten>;
+    fn read_revision(&self, req: ReadRevision) -> Call<RevisionRead>;
+    fn read_latest_revision(
+        &self,
+        req: ReadLatestRevision,
+    ) -> Call<RevisionRead>;
+}
+
+// GrpcTransport calls the service through the generated CharactersClient.
+pub struct GrpcTransport {
+    client: CharactersClient,
+    auth_token: Option<String>,
+    timeout: Duration,
+}
+
+impl GrpcTransport {
+    pub fn new(
+        client: CharactersClient,
+        auth_token: Option<String>,
+        timeout: Duration,
+    ) -> GrpcTransport {
+        GrpcTransport {
+            client,
+            auth_token,
+            timeout,
+        }
+    }
+
+    // call_option sets the deadline and authorization header of a call.
+    fn call_option(&self) -> Result<CallOption, Error> {
+        let option = CallOption::default().timeout(self.timeout);
+        let token = match self.auth_token.as_ref() {
+            Some(token) => token,
+            None => return Ok(option),
+        };
+
+        let mut headers = MetadataBuilder::new();
+        headers.add_str("authorization", &format!("Bearer {token}"))?;
+        Ok(option.headers(headers.build()))
+    }
+}
+
+// call awaits the response of a started call.
+fn call<T: Unpin + Send + 'static>(
+    receiver: Result<ClientUnaryReceiver<T>, Error>,
+) -> Call<T> {
+    Box::pin(async move { Ok(receiver?.await?) })
+}
+
+impl Transport for GrpcTransport {
+    fn create(&self, req: CreateCharacter) -> Call<CharacterCreated> {
+        call(
+            self.call_option().and_then(|option| {
+                Ok(self.client.create_async_opt(&req, option)?)
+            }),
+        )
+    }
+
+    fn write_revision(&self, req: WriteRevision) -> Call<RevisionWritten> {
+        call(self.call_option().and_then(|option| {
+            Ok(self
+                .client
+                .write_character_revision_async_opt(&req, option)?)
+        }))
+    }
+
+    fn read_revision(&self, req: ReadRevision) -> Call<RevisionRead> {
+        call(self.call_option().and_then(|option| {
+            Ok(self
+                .client
+                .read_character_revision_async_opt(&req, option)?)
+        }))
+    }
+
+    fn read_latest_revision(
+        &self,
+        req: ReadLatestRevision,
+    ) -> Call<RevisionRead> {
+        call(self.call_option().and_then(|option| {
+            Ok(self
+                .client
+                .read_latest_character_revision_async_opt(&req, option)?)
+        }))
+    }
+}
@@ -0,0 +1,19 @@
+use std::{env, error::Error, path::PathBuf};
+
+// main generates the types of storage.proto, which only this service uses to
+// persist character metadata and revisions.
+fn main() -> Result<(), Box<dyn Error>> {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?).join("../proto");
+    let storage = root.join("storage.proto");
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    // Use the vendored protoc unless one is configured explicitly.
+    if env::var_os("PROTOC").is_none() {
+        env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+
+    prost_build::Config::new()
+        .extern_path(".character", "::proto_rs::character")
+        .compile_protos(&[storage], &[root])?;
+    Ok(())
+}
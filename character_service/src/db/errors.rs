@@ -1,6 +1,10 @@
 use std::fmt::Display;
 
 use grpcio::{RpcStatus, RpcStatusCode};
+use proto_rs::{
+    characters::{error_details::Reason, ErrorDetails, FieldViolation},
+    details::rpc_status,
+};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum ErrorCode {
@@ -10,12 +14,33 @@ pub enum ErrorCode {
     Unauthorized,
     OutOfOrder,
     Exists,
+    Unavailable,
+    InvalidArgument,
+    ResourceExhausted,
+}
+
+// Violation describes why the field at the given path is invalid, e.g.
+// character.stats.might.pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub field: String,
+    pub description: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.description)
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Error {
     code: ErrorCode,
     message: String,
+    // latest_revision of the character a revision was written out of order
+    // for.
+    latest_revision: Option<u64>,
+    violations: Vec<Violation>,
 }
 
 impl Error {
@@ -23,9 +48,21 @@ impl Error {
         Error {
             code,
             message: message.to_owned(),
+            latest_revision: None,
+            violations: vec![],
         }
     }
 
+    pub fn with_latest_revision(mut self, latest_revision: u64) -> Error {
+        self.latest_revision = Some(latest_revision);
+        self
+    }
+
+    pub fn with_violations(mut self, violations: Vec<Violation>) -> Error {
+        self.violations = violations;
+        self
+    }
+
     pub fn message(&self) -> String {
         self.message.to_owned()
     }
@@ -33,6 +70,30 @@ impl Error {
     pub fn code(&self) -> ErrorCode {
         self.code
     }
+
+    pub fn latest_revision(&self) -> Option<u64> {
+        self.latest_revision
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    // details for clients to handle the error without parsing the message.
+    pub fn details(&self) -> ErrorDetails {
+        ErrorDetails {
+            reason: Reason::from(self.code).into(),
+            latest_revision: self.latest_revision.unwrap_or_default(),
+            field_violations: self
+                .violations
+                .iter()
+                .map(|violation| FieldViolation {
+                    field: violation.field.clone(),
+                    description: violation.description.clone(),
+                })
+                .collect(),
+        }
+    }
 }
 
 impl Display for Error {
@@ -43,7 +104,24 @@ impl Display for Error {
 
 impl From<Error> for RpcStatus {
     fn from(val: Error) -> Self {
-        RpcStatus::with_message(val.code, val.to_string())
+        let message = val.to_string();
+        rpc_status(val.code.into(), message, &val.details())
+    }
+}
+
+impl From<ErrorCode> for Reason {
+    fn from(val: ErrorCode) -> Self {
+        match val {
+            ErrorCode::Unknown => Reason::Unknown,
+            ErrorCode::NotFound => Reason::NotFound,
+            ErrorCode::Internal => Reason::Internal,
+            ErrorCode::Unauthorized => Reason::Unauthorized,
+            ErrorCode::OutOfOrder => Reason::OutOfOrder,
+            ErrorCode::Exists => Reason::Exists,
+            ErrorCode::Unavailable => Reason::Unavailable,
+            ErrorCode::InvalidArgument => Reason::InvalidArgument,
+            ErrorCode::ResourceExhausted => Reason::ResourceExhausted,
+        }
     }
 }
 
@@ -56,6 +134,64 @@ impl From<ErrorCode> for RpcStatusCode {
             ErrorCode::Unauthorized => RpcStatusCode::PERMISSION_DENIED,
             ErrorCode::OutOfOrder => RpcStatusCode::INVALID_ARGUMENT,
             ErrorCode::Exists => RpcStatusCode::ALREADY_EXISTS,
+            ErrorCode::Unavailable => RpcStatusCode::UNAVAILABLE,
+            ErrorCode::InvalidArgument => RpcStatusCode::INVALID_ARGUMENT,
+            ErrorCode::ResourceExhausted => RpcStatusCode::RESOURCE_EXHAUSTED,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use grpcio::{RpcStatus, RpcStatusCode};
+    use proto_rs::{characters::error_details::Reason, details::error_details};
+    use rstest::rstest;
+
+    use super::{Error, ErrorCode, Violation};
+
+    #[rstest]
+    fn out_of_order_details() {
+        let status: RpcStatus =
+            Error::new(ErrorCode::OutOfOrder, "out of order")
+                .with_latest_revision(4)
+                .into();
+
+        assert_eq!(status.code(), RpcStatusCode::INVALID_ARGUMENT);
+        assert_eq!(status.message(), "OutOfOrder: out of order");
+        let details = error_details(&status).unwrap().unwrap();
+        assert_eq!(details.reason(), Reason::OutOfOrder);
+        assert_eq!(details.latest_revision, 4);
+        assert!(details.field_violations.is_empty());
+    }
+
+    #[rstest]
+    fn violation_details() {
+        let status: RpcStatus =
+            Error::new(ErrorCode::InvalidArgument, "invalid character")
+                .with_violations(vec![Violation {
+                    field: "character.uuid".to_owned(),
+                    description: "must not be empty".to_owned(),
+                }])
+                .into();
+
+        assert_eq!(status.code(), RpcStatusCode::INVALID_ARGUMENT);
+        let details = error_details(&status).unwrap().unwrap();
+        assert_eq!(details.reason(), Reason::InvalidArgument);
+        let violations = details.field_violations;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "character.uuid");
+        assert_eq!(violations[0].description, "must not be empty");
+    }
+
+    #[rstest]
+    #[case(ErrorCode::NotFound, Reason::NotFound)]
+    #[case(ErrorCode::Exists, Reason::Exists)]
+    #[case(ErrorCode::ResourceExhausted, Reason::ResourceExhausted)]
+    fn reason_details(#[case] code: ErrorCode, #[case] reason: Reason) {
+        let status: RpcStatus = Error::new(code, "failed").into();
+
+        let details = error_details(&status).unwrap().unwrap();
+        assert_eq!(details.reason(), reason);
+        assert_eq!(details.latest_revision, 0);
+    }
+}
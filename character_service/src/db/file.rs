@@ -1,16 +1,24 @@
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    collections::{hash_map::Entry, BTreeSet, HashMap},
     fs::{self, create_dir_all, File, OpenOptions},
-    io::Write,
+    io::{Read, Write},
     path::{Path, PathBuf},
-    sync::{Mutex, RwLock},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
+    },
+    time::Instant,
 };
 
-use protobuf::{Message, SingularPtrField};
-use tracing::{error, trace, warn};
+use prost::Message;
+use tracing::{error, trace, trace_span, warn};
 
 use crate::{
-    db::errors::{Error, ErrorCode},
+    db::{
+        errors::{Error, ErrorCode},
+        schema::{self, SCHEMA_VERSION},
+    },
+    metrics::{Lock, Metrics},
     proto::storage::CharacterMetadata,
 };
 
@@ -25,8 +33,45 @@ pub struct FileStore {
     root: PathBuf,
 
     characters: Characters,
+
+    // Files written since the last sync, they are synced to disk on flush or
+    // once MAX_PENDING files are waiting.
+    pending: Mutex<BTreeSet<PathBuf>>,
+
+    // Set once load_from_storage finished, the store rejects requests before.
+    loaded: AtomicBool,
+
+    metrics: Arc<Metrics>,
+
+    quotas: Quotas,
+    usage: Mutex<HashMap<User, Usage>>,
+}
+
+// Quotas limit what a single owner can store, a quota of 0 is unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Quotas {
+    pub max_characters: usize,
+    pub max_stored_bytes: u64,
+}
+
+// Migration counts the characters and revisions FileStore::migrate rewrote.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Migration {
+    pub characters: usize,
+    pub revisions: usize,
+}
+
+// Usage of a single owner counted towards their Quotas.
+#[derive(Debug, Default)]
+struct Usage {
+    characters: usize,
+    stored_bytes: u64,
 }
 
+// MAX_PENDING bounds the files written without being synced to disk, which
+// limits the writes a crash can lose.
+const MAX_PENDING: usize = 64;
+
 type User = String;
 type Uuid<'a> = &'a str;
 type Revision = u64;
@@ -38,30 +83,70 @@ type Characters = RwLock<HashMap<String, Mutex<CharacterMetadata>>>;
 
 impl FileStore {
     // new FileStore using the provided root directory
+    #[cfg(test)]
     pub fn new(root: PathBuf) -> Result<FileStore, Error> {
-        let store = FileStore {
+        let store = FileStore::open(
             root,
-            characters: RwLock::new(HashMap::new()),
-        };
+            Arc::new(Metrics::new().unwrap()),
+            Default::default(),
+        );
 
         store.load_from_storage()?;
 
         Ok(store)
     }
 
+    // open a FileStore without loading existing characters, it becomes
+    // available once load_from_storage finished.
+    pub fn open(
+        root: PathBuf,
+        metrics: Arc<Metrics>,
+        quotas: Quotas,
+    ) -> FileStore {
+        FileStore {
+            root,
+            characters: RwLock::new(HashMap::new()),
+            pending: Mutex::new(BTreeSet::new()),
+            loaded: AtomicBool::new(false),
+            metrics,
+            quotas,
+            usage: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // healthy reports if the store is loaded and its root is accessible.
+    pub fn healthy(&self) -> bool {
+        if !self.loaded.load(Ordering::Acquire) {
+            return false;
+        }
+
+        match fs::read_dir(&self.root) {
+            Ok(_) => true,
+            Err(err) => {
+                warn!(dir = ?self.root, err = %err, "failed to access root");
+                false
+            }
+        }
+    }
+
     pub fn create_character(&self, owner: User) -> Result<String, Error> {
         trace!("creating character");
 
+        self.check_loaded()?;
+
         let uuid = FileStore::new_uuid();
 
         let metadata = CharacterMetadata {
             uuid: uuid.clone(),
-            owner,
+            owner: owner.clone(),
             ..Default::default()
         };
 
-        // unwrapping the write() here to panic on a poisoned lock
-        let mut characters = self.characters.write().unwrap();
+        let mut characters = self.write_characters();
+
+        // Holding the characters write lock serializes creations, so the
+        // quota can't be exceeded by concurrent requests.
+        self.check_character_quota(&owner)?;
 
         match characters.entry(uuid.clone()) {
             Entry::Occupied(_) => {
@@ -75,9 +160,19 @@ impl FileStore {
             Entry::Vacant(entry) => {
                 metadata.create_character_directory(&self.root)?;
                 metadata.write_to_root(&self.root)?;
+                self.mark_pending(CharacterMetadata::metadata_path(
+                    &self.root, &uuid,
+                ));
                 entry.insert(Mutex::new(metadata))
             }
         };
+        self.usage
+            .lock()
+            .unwrap()
+            .entry(owner)
+            .or_default()
+            .characters += 1;
+        self.metrics.characters.inc();
 
         Ok(uuid)
     }
@@ -91,10 +186,11 @@ impl FileStore {
     ) -> Result<Revision, Error> {
         trace!("writing character revision");
 
-        // unwrapping the read() here to panic on a poisoned lock
-        let characters = self.characters.read().unwrap();
+        self.check_loaded()?;
+
+        let characters = self.read_characters();
 
-        let mut metadata = match characters.get(uuid) {
+        let mut metadata = self.lock_character(match characters.get(uuid) {
             Some(metadata) => metadata,
             None => {
                 return Err(Error::new(
@@ -102,16 +198,30 @@ impl FileStore {
                     "character does not exist",
                 ));
             }
-        }
-        .lock()
-        .unwrap();
+        });
 
         metadata.authorize(user)?;
         metadata.check_revision_order(revision)?;
 
-        metadata.write_revision(&self.root, revision, character)?;
+        let bytes = trace_span!("encode_revision", uuid = uuid)
+            .in_scope(|| schema::encode(character));
+        let size = bytes.len() as u64;
+        self.reserve_storage(&metadata.owner, size)?;
+        if let Err(err) = metadata.write_revision(&self.root, revision, &bytes)
+        {
+            self.release_storage(&metadata.owner, size);
+            return Err(err);
+        }
+        self.metrics.revisions_written.inc();
+        self.metrics.stored_bytes.add(size as i64);
+
+        metadata.stored_bytes += size;
 
         metadata.update_latest_revision(&self.root, revision)?;
+        self.mark_pending(CharacterMetadata::revision_path(
+            &self.root, uuid, revision,
+        ));
+        self.mark_pending(CharacterMetadata::metadata_path(&self.root, uuid));
 
         Ok(revision)
     }
@@ -124,10 +234,11 @@ impl FileStore {
     ) -> Result<RevisionRead, Error> {
         trace!("reading character revision");
 
-        // unwrapping the read() here to panic on a poisoned lock
-        let characters = self.characters.read().unwrap();
+        self.check_loaded()?;
 
-        let metadata = match characters.get(uuid) {
+        let characters = self.read_characters();
+
+        let metadata = self.lock_character(match characters.get(uuid) {
             Some(metadata) => metadata,
             None => {
                 return Err(Error::new(
@@ -135,9 +246,7 @@ impl FileStore {
                     "character does not exist",
                 ));
             }
-        }
-        .lock()
-        .unwrap();
+        });
 
         metadata.authorize(user)?;
 
@@ -146,8 +255,7 @@ impl FileStore {
         Ok(RevisionRead {
             uuid: metadata.uuid.clone(),
             revision,
-            character: SingularPtrField::some(character),
-            ..Default::default()
+            character: Some(character),
         })
     }
 
@@ -158,10 +266,11 @@ impl FileStore {
     ) -> Result<RevisionRead, Error> {
         trace!("reading latest character revision");
 
-        // unwrapping the read() here to panic on a poisoned lock
-        let characters = self.characters.read().unwrap();
+        self.check_loaded()?;
+
+        let characters = self.read_characters();
 
-        let metadata = match characters.get(uuid) {
+        let metadata = self.lock_character(match characters.get(uuid) {
             Some(metadata) => metadata,
             None => {
                 return Err(Error::new(
@@ -169,67 +278,177 @@ impl FileStore {
                     "character does not exist",
                 ));
             }
-        }
-        .lock()
-        .unwrap();
+        });
 
         metadata.authorize(user)?;
 
-        if !metadata.has_latest_revision() {
+        if metadata.latest_revision.is_none() {
             return Err(Error::new(ErrorCode::NotFound, "failed to read latest revision for character without revisions"));
         }
 
-        let character = metadata
-            .read_revision(&self.root, metadata.get_latest_revision())?;
+        let character =
+            metadata.read_revision(&self.root, metadata.latest_revision())?;
 
         Ok(RevisionRead {
             uuid: metadata.uuid.clone(),
-            revision: metadata.get_latest_revision(),
-            character: SingularPtrField::some(character),
-            ..Default::default()
+            revision: metadata.latest_revision(),
+            character: Some(character),
         })
     }
 
-    fn load_from_storage(&self) -> Result<(), Error> {
-        let paths = match fs::read_dir(&self.root) {
-            Ok(paths) => paths,
-            Err(err) => {
-                error!(dir = ?self.root, err = %err, "failed to read characters from root");
-                return Err(Error::new(
-                    ErrorCode::Internal,
-                    "failed to read characters from root",
-                ));
+    // flush waits for ongoing writes and syncs all files written since the
+    // last flush to disk.
+    pub fn flush(&self) -> Result<(), Error> {
+        trace!("flushing pending writes");
+
+        // Taking the write lock waits for all in-flight operations to finish.
+        let _characters = self.write_characters();
+        sync_pending(&mut self.pending.lock().unwrap())
+    }
+
+    // mark_pending adds path to the files to sync, syncing them all once
+    // MAX_PENDING are waiting. Files failing to sync, which might belong to
+    // other characters, don't fail the write but are left for flush.
+    fn mark_pending(&self, path: PathBuf) {
+        let mut pending = self.pending.lock().unwrap();
+        pending.insert(path);
+        if pending.len() >= MAX_PENDING {
+            trace!("syncing pending writes");
+            if sync_pending(&mut pending).is_err() {
+                warn!(
+                    files = pending.len(),
+                    "leaving files which failed to sync for flush"
+                );
             }
-        };
+        }
+    }
 
-        let mut characters = self.characters.write().unwrap();
+    // read_characters acquires the characters read lock and records the time
+    // spent waiting for it.
+    fn read_characters(
+        &self,
+    ) -> RwLockReadGuard<'_, HashMap<String, Mutex<CharacterMetadata>>> {
+        let _span = trace_span!("lock_characters").entered();
+        let started = Instant::now();
+        // unwrapping the read() here to panic on a poisoned lock
+        let characters = self.characters.read().unwrap();
+        self.metrics.record_lock_wait(Lock::Characters, started);
+        characters
+    }
 
-        for path in paths {
-            let path = match path {
-                Ok(path) => path,
-                Err(err) => {
-                    warn!(err = %err, "failed to process path");
-                    continue;
-                }
-            };
-            if !path.metadata().unwrap().is_dir() {
-                continue;
+    fn write_characters(
+        &self,
+    ) -> RwLockWriteGuard<'_, HashMap<String, Mutex<CharacterMetadata>>> {
+        let _span = trace_span!("lock_characters", write = true).entered();
+        let started = Instant::now();
+        // unwrapping the write() here to panic on a poisoned lock
+        let characters = self.characters.write().unwrap();
+        self.metrics.record_lock_wait(Lock::Characters, started);
+        characters
+    }
+
+    fn lock_character<'a>(
+        &self,
+        metadata: &'a Mutex<CharacterMetadata>,
+    ) -> MutexGuard<'a, CharacterMetadata> {
+        let _span = trace_span!("lock_character").entered();
+        let started = Instant::now();
+        // unwrapping the lock() here to panic on a poisoned lock
+        let metadata = metadata.lock().unwrap();
+        self.metrics.record_lock_wait(Lock::Character, started);
+        metadata
+    }
+
+    pub fn load_from_storage(&self) -> Result<(), Error> {
+        let uuids = character_uuids(&self.root)?;
+
+        let mut characters = self.write_characters();
+        let mut stored_bytes = 0;
+
+        for uuid in uuids {
+            let mut metadata =
+                CharacterMetadata::read_from_root(&self.root, &uuid)?;
+            // Metadata written before stored bytes were tracked needs to be
+            // counted from the revisions.
+            if metadata.stored_bytes == 0 {
+                metadata.stored_bytes = metadata.revisions_size(&self.root);
             }
+            stored_bytes += metadata.stored_bytes;
 
-            let path = path.file_name();
-            let uuid = match path.to_str() {
-                Some(uuid) => uuid,
-                None => {
-                    warn!(path = ?path, "failed to read uuid from path");
-                    continue;
-                }
-            };
+            let mut usage = self.usage.lock().unwrap();
+            let usage = usage.entry(metadata.owner.clone()).or_default();
+            usage.characters += 1;
+            usage.stored_bytes += metadata.stored_bytes;
+
+            characters.insert(uuid, Mutex::new(metadata));
+        }
 
-            let metadata = CharacterMetadata::read_from_root(&self.root, uuid)?;
+        self.metrics.characters.set(characters.len() as i64);
+        self.metrics.stored_bytes.set(stored_bytes as i64);
 
-            characters.insert(uuid.to_owned(), Mutex::new(metadata));
+        self.loaded.store(true, Ordering::Release);
+
+        Ok(())
+    }
+
+    // migrate rewrites all revisions in the store at root that were written
+    // with an older schema version to SCHEMA_VERSION. The store must not be
+    // served while it is migrated.
+    pub fn migrate(root: &Path) -> Result<Migration, Error> {
+        let mut migration = Migration::default();
+        for uuid in character_uuids(root)? {
+            let mut metadata = CharacterMetadata::read_from_root(root, &uuid)?;
+            let revisions = metadata.migrate(root)?;
+            if revisions > 0 {
+                migration.characters += 1;
+                migration.revisions += revisions;
+            }
+        }
+        Ok(migration)
+    }
+
+    fn check_character_quota(&self, owner: &str) -> Result<(), Error> {
+        let max = self.quotas.max_characters;
+        let usage = self.usage.lock().unwrap();
+        let characters = usage.get(owner).map_or(0, |usage| usage.characters);
+        if max > 0 && characters >= max {
+            return Err(Error::new(
+                ErrorCode::ResourceExhausted,
+                &format!("quota of {max} characters exceeded"),
+            ));
         }
+        Ok(())
+    }
 
+    // reserve_storage counts size towards the owner's storage quota, failing
+    // if the quota would be exceeded.
+    fn reserve_storage(&self, owner: &str, size: u64) -> Result<(), Error> {
+        let max = self.quotas.max_stored_bytes;
+        let mut usage = self.usage.lock().unwrap();
+        let usage = usage.entry(owner.to_owned()).or_default();
+        if max > 0 && usage.stored_bytes + size > max {
+            return Err(Error::new(
+                ErrorCode::ResourceExhausted,
+                &format!("storage quota of {max} bytes exceeded"),
+            ));
+        }
+        usage.stored_bytes += size;
+        Ok(())
+    }
+
+    fn release_storage(&self, owner: &str, size: u64) {
+        if let Some(usage) = self.usage.lock().unwrap().get_mut(owner) {
+            usage.stored_bytes = usage.stored_bytes.saturating_sub(size);
+        }
+    }
+
+    fn check_loaded(&self) -> Result<(), Error> {
+        if !self.loaded.load(Ordering::Acquire) {
+            return Err(Error::new(
+                ErrorCode::Unavailable,
+                "characters are still being loaded",
+            ));
+        }
         Ok(())
     }
 
@@ -242,6 +461,88 @@ impl FileStore {
     }
 }
 
+// character_uuids lists the uuids of all characters stored in root.
+fn character_uuids(root: &Path) -> Result<Vec<String>, Error> {
+    let paths = match fs::read_dir(root) {
+        Ok(paths) => paths,
+        Err(err) => {
+            error!(dir = ?root, err = %err, "failed to read characters from root");
+            return Err(Error::new(
+                ErrorCode::Internal,
+                "failed to read characters from root",
+            ));
+        }
+    };
+
+    let mut uuids = vec![];
+    for path in paths {
+        let path = match path {
+            Ok(path) => path,
+            Err(err) => {
+                warn!(err = %err, "failed to process path");
+                continue;
+            }
+        };
+        if !path.metadata().unwrap().is_dir() {
+            continue;
+        }
+
+        let path = path.file_name();
+        match path.to_str() {
+            Some(uuid) => uuids.push(uuid.to_owned()),
+            None => {
+                warn!(path = ?path, "failed to read uuid from path");
+                continue;
+            }
+        };
+    }
+    Ok(uuids)
+}
+
+// file_size returns the size of the file at path, 0 if it can't be read.
+fn file_size(path: &Path) -> u64 {
+    match fs::metadata(path) {
+        Ok(metadata) => metadata.len(),
+        Err(err) => {
+            warn!(path = ?path, err = %err, "failed to read file size");
+            0
+        }
+    }
+}
+
+// sync_pending syncs the files in pending and their directories, removing
+// the synced ones from pending.
+fn sync_pending(pending: &mut BTreeSet<PathBuf>) -> Result<(), Error> {
+    let directories: BTreeSet<PathBuf> = pending
+        .iter()
+        .filter_map(|path| path.parent().map(Path::to_owned))
+        .collect();
+    // Files failing to sync stay pending for the next attempt.
+    pending.retain(|path| sync_path(path).is_err());
+    // Syncing the directories persists newly created files in them.
+    for path in directories {
+        if sync_path(&path).is_err() {
+            pending.insert(path);
+        }
+    }
+
+    match pending.is_empty() {
+        true => Ok(()),
+        false => Err(Error::new(ErrorCode::Internal, "failed to sync files")),
+    }
+}
+
+fn sync_path(path: &Path) -> Result<(), Error> {
+    let _span = trace_span!("sync_file", path = ?path).entered();
+    match File::open(path).and_then(|file| file.sync_all()) {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            error!(path = ?path, err = %err, "failed to sync file");
+            Err(Error::new(ErrorCode::Internal, "failed to sync file"))
+        }
+    }
+}
+
 // This block provides helpers on CharacterMetadata for accessing it in file storage.
 // The functions defined here use the fields on CharacterMetadata as it's read
 // from storage to avoid file access based on user input.
@@ -250,6 +551,7 @@ impl CharacterMetadata {
         root: &Path,
         uuid: Uuid,
     ) -> Result<CharacterMetadata, Error> {
+        let _span = trace_span!("read_metadata", uuid = uuid).entered();
         let path = CharacterMetadata::metadata_path(root, uuid);
 
         let mut file = match OpenOptions::new()
@@ -273,7 +575,16 @@ impl CharacterMetadata {
             }
         }?;
 
-        let metadata = match CharacterMetadata::parse_from_reader(&mut file) {
+        let mut bytes = vec![];
+        if let Err(err) = file.read_to_end(&mut bytes) {
+            error!( uuid = uuid, err = %err, "failed to read metadata file");
+            return Err(Error::new(
+                ErrorCode::Internal,
+                "could not read metadata",
+            ));
+        }
+
+        let metadata = match CharacterMetadata::decode(bytes.as_slice()) {
             Ok(metadata) => metadata,
             Err(err) => {
                 error!( uuid = uuid, err = %err, "failed to decode metadata file");
@@ -320,6 +631,7 @@ impl CharacterMetadata {
         open: &mut OpenOptions,
         path: &Path,
     ) -> Result<(), Error> {
+        let _span = trace_span!("write_metadata", uuid = self.uuid).entered();
         let mut file = match open.open(path) {
             Ok(file) => Ok(file),
             Err(err) => {
@@ -341,16 +653,7 @@ impl CharacterMetadata {
             }
         }?;
 
-        let bytes = match self.write_to_bytes() {
-            Ok(bytes) => bytes,
-            Err(err) => {
-                error!(uuid = self.uuid, err = %err, "failed to encode metadata");
-                return Err(Error::new(
-                    ErrorCode::Internal,
-                    "failed to encode metadata",
-                ));
-            }
-        };
+        let bytes = self.encode_to_vec();
         match file.write_all(&bytes) {
             Ok(_) => Ok(()),
             Err(err) => {
@@ -376,14 +679,15 @@ impl CharacterMetadata {
         &self,
         new_revision: Revision,
     ) -> Result<(), Error> {
-        let latest_revision = self.get_latest_revision();
+        let latest_revision = self.latest_revision();
         let is_first_revision =
-            new_revision == 0 && !self.has_latest_revision();
+            new_revision == 0 && self.latest_revision.is_none();
         if new_revision <= latest_revision && !is_first_revision {
             return Err(Error::new(
                 ErrorCode::OutOfOrder,
                 &format!("revisions need to be sent in order, last known revision is {latest_revision}"),
-            ));
+            )
+            .with_latest_revision(latest_revision));
         }
         Ok(())
     }
@@ -392,10 +696,10 @@ impl CharacterMetadata {
         &self,
         root: &Path,
         revision: Revision,
-        character: Character,
+        bytes: &[u8],
     ) -> Result<Revision, Error> {
         let revision_file = self.create_revision_file(root, revision)?;
-        self.write_revision_file(revision_file, character)?;
+        self.write_revision_file(revision_file, bytes)?;
 
         Ok(revision)
     }
@@ -405,22 +709,56 @@ impl CharacterMetadata {
         root: &Path,
         revision: Revision,
     ) -> Result<(), Error> {
-        self.set_latest_revision(revision);
+        self.latest_revision = Some(revision);
 
         self.update_at_root(root)?;
 
         Ok(())
     }
 
+    // read_revision reads a revision, upgrading it to the current
+    // SCHEMA_VERSION.
     pub fn read_revision(
         &self,
         root: &Path,
         revision: Revision,
     ) -> Result<Character, Error> {
+        let (mut character, version) =
+            self.read_stored_revision(root, revision)?;
+        if let Err(err) = schema::upgrade(&mut character, version) {
+            error!(uuid = self.uuid, revision = revision, err = %err, "failed to upgrade character revision");
+            return Err(err);
+        }
+
+        Ok(character)
+    }
+
+    // read_stored_revision reads a revision as it is stored, returning the
+    // character and its schema version.
+    fn read_stored_revision(
+        &self,
+        root: &Path,
+        revision: Revision,
+    ) -> Result<(Character, u32), Error> {
         let mut revision_file = self.open_revision_file(root, revision)?;
 
-        let character = match Character::parse_from_reader(&mut revision_file) {
-            Ok(character) => character,
+        let mut bytes = vec![];
+        let read =
+            trace_span!("read_revision_file", uuid = self.uuid, revision)
+                .in_scope(|| revision_file.read_to_end(&mut bytes));
+        if let Err(err) = read {
+            error!(uuid = self.uuid, revision = revision, err = %err, "failed to read character revision");
+            return Err(Error::new(
+                ErrorCode::Internal,
+                "could not read character",
+            ));
+        }
+
+        let decoded =
+            trace_span!("decode_revision", uuid = self.uuid, revision)
+                .in_scope(|| schema::decode(&bytes));
+        let stored = match decoded {
+            Ok(stored) => stored,
             Err(err) => {
                 error!(uuid = self.uuid, revision = revision, err = %err, "failed to decode character revision");
                 return Err(Error::new(
@@ -430,25 +768,17 @@ impl CharacterMetadata {
             }
         };
 
-        Ok(character)
+        Ok(stored)
     }
 
     fn write_revision_file(
         &self,
         mut file: File,
-        character: Character,
+        bytes: &[u8],
     ) -> Result<(), Error> {
-        let bytes = match character.write_to_bytes() {
-            Ok(bytes) => bytes,
-            Err(err) => {
-                error!(uuid = self.uuid, err = %err, "failed to encode character revision");
-                return Err(Error::new(
-                    ErrorCode::Internal,
-                    "failed to encode character revision",
-                ));
-            }
-        };
-        match file.write_all(&bytes) {
+        let _span =
+            trace_span!("write_revision_file", uuid = self.uuid).entered();
+        match file.write_all(bytes) {
             Ok(_) => Ok(()),
             Err(err) => {
                 error!(uuid = self.uuid, err = %err, "failed to write character revision");
@@ -460,6 +790,58 @@ impl CharacterMetadata {
         }
     }
 
+    // migrate rewrites the revisions written with an older schema version,
+    // returning how many were rewritten.
+    fn migrate(&mut self, root: &Path) -> Result<usize, Error> {
+        if self.latest_revision.is_none() {
+            return Ok(0);
+        }
+
+        let mut migrated = 0;
+        for (revision, path) in self.revision_files(root)? {
+            let (mut character, version) =
+                self.read_stored_revision(root, revision)?;
+            if version == SCHEMA_VERSION {
+                continue;
+            }
+            schema::upgrade(&mut character, version)?;
+            self.replace_revision_file(&path, &schema::encode(character))?;
+            migrated += 1;
+        }
+
+        if migrated > 0 {
+            sync_path(&CharacterMetadata::character_path(root, &self.uuid))?;
+            self.stored_bytes = self.revisions_size(root);
+            self.update_at_root(root)?;
+            sync_path(&CharacterMetadata::metadata_path(root, &self.uuid))?;
+        }
+        Ok(migrated)
+    }
+
+    // replace_revision_file atomically replaces the revision at path by
+    // writing bytes to a temporary file renamed over it.
+    fn replace_revision_file(
+        &self,
+        path: &Path,
+        bytes: &[u8],
+    ) -> Result<(), Error> {
+        let tmp = path.with_extension("tmp");
+        let replaced = File::create(&tmp)
+            .and_then(|mut file| {
+                file.write_all(bytes)?;
+                file.sync_all()
+            })
+            .and_then(|_| fs::rename(&tmp, path));
+        if let Err(err) = replaced {
+            error!(uuid = self.uuid, path = ?path, err = %err, "failed to replace revision file");
+            return Err(Error::new(
+                ErrorCode::Internal,
+                "failed to replace revision",
+            ));
+        }
+        Ok(())
+    }
+
     fn create_revision_file(
         &self,
         root: &Path,
@@ -523,6 +905,63 @@ impl CharacterMetadata {
         }
     }
 
+    // revisions_size sums up the size of all stored revisions.
+    fn revisions_size(&self, root: &Path) -> u64 {
+        if self.latest_revision.is_none() {
+            return 0;
+        }
+
+        match self.revision_files(root) {
+            Ok(revisions) => {
+                revisions.iter().map(|(_, path)| file_size(path)).sum()
+            }
+            Err(_) => 0,
+        }
+    }
+
+    // revision_files lists the stored revisions and their paths in order,
+    // ignoring other files in the character directory. Listing the directory
+    // avoids probing every number up to the latest revision.
+    fn revision_files(
+        &self,
+        root: &Path,
+    ) -> Result<Vec<(Revision, PathBuf)>, Error> {
+        let path = CharacterMetadata::character_path(root, &self.uuid);
+        let entries = match fs::read_dir(&path) {
+            Ok(entries) => entries,
+            Err(err) => {
+                error!(uuid = self.uuid, path = ?path, err = %err, "failed to list revisions");
+                return Err(Error::new(
+                    ErrorCode::Internal,
+                    "failed to list revisions",
+                ));
+            }
+        };
+
+        let mut revisions = vec![];
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    warn!(uuid = self.uuid, err = %err, "failed to process path");
+                    continue;
+                }
+            };
+            let name = entry.file_name();
+            let revision = name
+                .to_str()
+                .and_then(|name| Some((name, name.parse::<Revision>().ok()?)))
+                // Only the canonical name is read back as revision.
+                .filter(|(name, revision)| revision.to_string() == *name)
+                .map(|(_, revision)| revision);
+            if let Some(revision) = revision {
+                revisions.push((revision, entry.path()));
+            }
+        }
+        revisions.sort();
+        Ok(revisions)
+    }
+
     fn revision_path(root: &Path, uuid: Uuid, revision: Revision) -> PathBuf {
         CharacterMetadata::character_path(root, uuid).join(revision.to_string())
     }
@@ -549,17 +988,29 @@ impl CharacterMetadata {
 
 #[cfg(test)]
 mod test {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use prost::Message;
     use rstest::rstest;
     use tempfile::tempdir;
     use tracing::Level;
     use tracing_subscriber::FmtSubscriber;
 
     use crate::{
-        db::{errors, file::FileStore},
+        db::{
+            errors,
+            file::{FileStore, Migration, Quotas, MAX_PENDING},
+            schema::{self, SCHEMA_VERSION},
+        },
+        metrics::Metrics,
         proto::storage::CharacterMetadata,
     };
 
-    use proto_rs::character::Character;
+    use proto_rs::character::{Character, ItemType, PoolType, Stat, Stats};
 
     fn enable_logs() {
         let subscriber = FmtSubscriber::builder()
@@ -578,6 +1029,41 @@ mod test {
         assert_eq!(s.root, root_path);
     }
 
+    #[rstest]
+    fn open_file_store_is_unavailable_until_loaded() {
+        let root = tempdir().unwrap();
+        let root_path = root.path().to_owned();
+
+        let s = FileStore::open(
+            root_path,
+            Arc::new(Metrics::new().unwrap()),
+            Default::default(),
+        );
+        assert!(!s.healthy(), "should not be healthy before loading");
+        assert_eq!(
+            s.create_character("test_user".to_owned())
+                .expect_err("should not create before loading")
+                .code(),
+            errors::ErrorCode::Unavailable
+        );
+
+        s.load_from_storage().expect("should load from storage");
+        assert!(s.healthy(), "should be healthy after loading");
+        assert!(s.create_character("test_user".to_owned()).is_ok());
+    }
+
+    #[rstest]
+    fn file_store_is_unhealthy_without_root() {
+        let root = tempdir().unwrap();
+        let root_path = root.path().to_owned();
+
+        let s = FileStore::new(root_path).unwrap();
+        assert!(s.healthy());
+
+        root.close().unwrap();
+        assert!(!s.healthy(), "should not be healthy without root");
+    }
+
     #[rstest]
     fn create_character() {
         let root = tempdir().unwrap();
@@ -677,10 +1163,9 @@ mod test {
             Default::default(),
             1,
         );
-        assert_eq!(
-            res.expect_err("should return error for third write").code(),
-            errors::ErrorCode::OutOfOrder,
-        );
+        let err = res.expect_err("should return error for third write");
+        assert_eq!(err.code(), errors::ErrorCode::OutOfOrder);
+        assert_eq!(err.latest_revision(), Some(1));
 
         let res = s.write_revision(
             &uuid,
@@ -784,10 +1269,10 @@ mod test {
             metadata
                 .write_revision_file(
                     file.unwrap(),
-                    Character {
+                    &schema::encode(Character {
                         uuid: "test_uuid".to_owned(),
                         ..Default::default()
-                    }
+                    })
                 )
                 .expect_err("should fail to write to opened file")
                 .code(),
@@ -909,6 +1394,247 @@ mod test {
         }
     }
 
+    #[rstest]
+    fn flush_syncs_pending_writes() {
+        let root = tempdir().unwrap();
+        let root_path = root.path().to_owned();
+
+        let s = FileStore::new(root_path).unwrap();
+        let uuid = s.create_character("test_user".to_owned()).unwrap();
+        let res = s.write_revision(
+            &uuid,
+            "test_user".to_owned(),
+            Default::default(),
+            0,
+        );
+        assert_eq!(res.expect("should return 0 for first write"), 0);
+        assert_eq!(s.pending.lock().unwrap().len(), 2);
+
+        s.flush().expect("should flush pending writes");
+        assert!(s.pending.lock().unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn write_revision_syncs_once_max_pending_files_wait() {
+        let root = tempdir().unwrap();
+        let root_path = root.path().to_owned();
+
+        let s = FileStore::new(root_path).unwrap();
+        let uuid = s.create_character("test_user".to_owned()).unwrap();
+        // Every revision adds its file, the metadata file is only added once.
+        for revision in 0..MAX_PENDING as u64 - 2 {
+            s.write_revision(
+                &uuid,
+                "test_user".to_owned(),
+                Default::default(),
+                revision,
+            )
+            .unwrap();
+        }
+        assert_eq!(s.pending.lock().unwrap().len(), MAX_PENDING - 1);
+
+        s.write_revision(
+            &uuid,
+            "test_user".to_owned(),
+            Default::default(),
+            MAX_PENDING as u64 - 2,
+        )
+        .unwrap();
+        // Only the metadata updated after the sync is left.
+        assert_eq!(s.pending.lock().unwrap().len(), 1);
+    }
+
+    #[rstest]
+    fn write_revision_leaves_files_failing_to_sync_for_flush() {
+        let root = tempdir().unwrap();
+        let missing = root.path().join("other").join("0");
+
+        let s = FileStore::new(root.path().to_owned()).unwrap();
+        let uuid = s.create_character("test_user".to_owned()).unwrap();
+        {
+            let mut pending = s.pending.lock().unwrap();
+            pending.insert(missing.clone());
+            for index in 0..MAX_PENDING - 4 {
+                pending.insert(root.path().join(format!("{index}")));
+            }
+        }
+        for index in 0..MAX_PENDING - 4 {
+            fs::write(root.path().join(format!("{index}")), "synced").unwrap();
+        }
+
+        for revision in 0..2 {
+            s.write_revision(
+                &uuid,
+                "test_user".to_owned(),
+                Default::default(),
+                revision,
+            )
+            .expect("should write despite other files failing to sync");
+        }
+
+        let read = s
+            .read_latest_revision(&uuid, "test_user".to_owned())
+            .unwrap();
+        assert_eq!(read.revision, 1);
+        assert!(s.pending.lock().unwrap().contains(&missing));
+        assert_eq!(s.flush().unwrap_err().code(), errors::ErrorCode::Internal);
+        assert_eq!(
+            s.pending.lock().unwrap().iter().collect::<Vec<_>>(),
+            vec![&root.path().join("other"), &missing]
+        );
+    }
+
+    #[rstest]
+    fn load_counts_revisions_with_large_gaps() {
+        let root = tempdir().unwrap();
+        let character = named_character("Eona");
+        let size = schema::encode(character.clone()).len() as u64;
+
+        let s = FileStore::new(root.path().to_owned()).unwrap();
+        let uuid = s.create_character("test_user".to_owned()).unwrap();
+        for revision in [0, u64::MAX / 2] {
+            s.write_revision(
+                &uuid,
+                "test_user".to_owned(),
+                character.clone(),
+                revision,
+            )
+            .unwrap();
+        }
+        fs::write(root.path().join(&uuid).join("0.tmp"), "leftover").unwrap();
+        // Metadata without stored bytes is counted from the revisions.
+        let mut metadata =
+            CharacterMetadata::read_from_root(root.path(), &uuid).unwrap();
+        metadata.stored_bytes = 0;
+        metadata.update_at_root(root.path()).unwrap();
+
+        let s = FileStore::new(root.path().to_owned()).unwrap();
+        let characters = s.characters.read().unwrap();
+        let metadata = characters[&uuid].lock().unwrap();
+        assert_eq!(metadata.stored_bytes, 2 * size);
+        assert_eq!(
+            metadata
+                .revision_files(root.path())
+                .unwrap()
+                .iter()
+                .map(|(revision, _)| *revision)
+                .collect::<Vec<_>>(),
+            [0, u64::MAX / 2]
+        );
+    }
+
+    fn open_with_quotas(root: PathBuf, quotas: Quotas) -> FileStore {
+        let s =
+            FileStore::open(root, Arc::new(Metrics::new().unwrap()), quotas);
+        s.load_from_storage().unwrap();
+        s
+    }
+
+    fn named_character(name: &str) -> Character {
+        Character {
+            name: name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn create_character_enforces_quota() {
+        let root = tempdir().unwrap();
+        let quotas = Quotas {
+            max_characters: 1,
+            ..Default::default()
+        };
+
+        let s = open_with_quotas(root.path().to_owned(), quotas);
+        assert!(s.create_character("test_user".to_owned()).is_ok());
+        assert_eq!(
+            s.create_character("test_user".to_owned())
+                .expect_err("should reject characters over quota")
+                .code(),
+            errors::ErrorCode::ResourceExhausted
+        );
+        assert!(
+            s.create_character("other_user".to_owned()).is_ok(),
+            "should count characters per owner"
+        );
+
+        let s = open_with_quotas(root.path().to_owned(), quotas);
+        assert!(
+            s.create_character("test_user".to_owned()).is_err(),
+            "should count loaded characters"
+        );
+    }
+
+    #[rstest]
+    fn write_revision_enforces_storage_quota() {
+        let root = tempdir().unwrap();
+        let character = named_character("Eona");
+        let size = schema::encode(character.clone()).len() as u64;
+        let quotas = Quotas {
+            max_stored_bytes: size + 1,
+            ..Default::default()
+        };
+
+        let s = open_with_quotas(root.path().to_owned(), quotas);
+        let uuid = s.create_character("test_user".to_owned()).unwrap();
+        s.write_revision(&uuid, "test_user".to_owned(), character.clone(), 0)
+            .expect("should write revision within quota");
+        assert_eq!(
+            s.write_revision(
+                &uuid,
+                "test_user".to_owned(),
+                character.clone(),
+                1
+            )
+            .expect_err("should reject revision over quota")
+            .code(),
+            errors::ErrorCode::ResourceExhausted
+        );
+
+        let s = open_with_quotas(root.path().to_owned(), quotas);
+        let metadata =
+            CharacterMetadata::read_from_root(root.path(), &uuid).unwrap();
+        assert_eq!(metadata.stored_bytes, size, "should persist stored bytes");
+        assert!(
+            s.write_revision(&uuid, "test_user".to_owned(), character, 1)
+                .is_err(),
+            "should count stored bytes after loading"
+        );
+    }
+
+    #[rstest]
+    fn records_store_metrics() {
+        let root = tempdir().unwrap();
+        let root_path = root.path().to_owned();
+
+        let s = FileStore::new(root_path.clone()).unwrap();
+        let uuid = s.create_character("test_user".to_owned()).unwrap();
+        s.create_character("test_user".to_owned()).unwrap();
+        for revision in 0..2 {
+            s.write_revision(
+                &uuid,
+                "test_user".to_owned(),
+                named_character("Eona"),
+                revision,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(s.metrics.characters.get(), 2);
+        assert_eq!(s.metrics.revisions_written.get(), 2);
+        let stored_bytes = s.metrics.stored_bytes.get();
+        assert!(stored_bytes > 0, "should count written revisions");
+
+        let s = FileStore::new(root_path).unwrap();
+        assert_eq!(s.metrics.characters.get(), 2);
+        assert_eq!(s.metrics.revisions_written.get(), 0);
+        assert_eq!(
+            s.metrics.stored_bytes.get(),
+            stored_bytes,
+            "should count stored revisions on load"
+        );
+    }
+
     #[rstest]
     fn read_latest_revision_fails_on_fresh_character() {
         let root = tempdir().unwrap();
@@ -929,4 +1655,222 @@ mod test {
             "failed to read latest revision for character without revisions"
         );
     }
+
+    // copy_dir copies the files in from into to, recursing into directories.
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let entry = entry.unwrap();
+            let path = to.join(entry.file_name());
+            if entry.file_type().unwrap().is_dir() {
+                copy_dir(&entry.path(), &path);
+            } else {
+                fs::copy(entry.path(), path).unwrap();
+            }
+        }
+    }
+
+    // The golden store was written by an earlier version of the service and
+    // must stay readable, so it must never be regenerated.
+    #[rstest]
+    fn reads_golden_store() {
+        let uuid = "3bd9cc82d371480cb5f0d3a512063b63";
+        let root = tempdir().unwrap();
+        copy_dir(
+            &PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("src/db/testdata/store"),
+            root.path(),
+        );
+
+        let s = FileStore::new(root.path().to_owned()).unwrap();
+        let metadata =
+            CharacterMetadata::read_from_root(root.path(), uuid).unwrap();
+        assert_eq!(metadata.owner, "test_user");
+        assert_eq!(metadata.latest_revision, Some(1));
+
+        let latest = s
+            .read_latest_revision(uuid, "test_user".to_owned())
+            .expect("should read latest revision");
+        assert_eq!(latest.revision, 1);
+        let character = latest.character.unwrap();
+        assert_eq!(character.name, "Aria");
+        assert!(character.items.is_empty());
+
+        let first = s
+            .read_revision(uuid, "test_user".to_owned(), 0)
+            .expect("should read first revision")
+            .character
+            .unwrap();
+        assert_eq!(first.uuid, uuid);
+        assert_eq!(first.name, "Aria Ünïcødé");
+        assert_eq!(first.skills.len(), 2);
+        assert_eq!(
+            first.items[1].types().collect::<Vec<_>>(),
+            [ItemType::Armor, ItemType::Others]
+        );
+        assert_eq!(first.items[1].armor, Some(2));
+
+        assert_eq!(
+            s.write_revision(uuid, "test_user".to_owned(), first, 2),
+            Ok(2),
+            "should append to the golden character"
+        );
+    }
+
+    // write_legacy_revision stores character as a bare Character, the way
+    // revisions were written before schema versions.
+    fn write_legacy_revision(
+        root: &Path,
+        uuid: &str,
+        revision: u64,
+        character: &Character,
+    ) {
+        fs::write(
+            CharacterMetadata::revision_path(root, uuid, revision),
+            character.encode_to_vec(),
+        )
+        .unwrap();
+        let mut metadata =
+            CharacterMetadata::read_from_root(root, uuid).unwrap();
+        metadata.latest_revision = Some(revision);
+        metadata.update_at_root(root).unwrap();
+    }
+
+    #[rstest]
+    fn read_revision_upgrades_legacy_revision() {
+        let root = tempdir().unwrap();
+        let s = FileStore::new(root.path().to_owned()).unwrap();
+        let uuid = s.create_character("test_user".to_owned()).unwrap();
+        let character = Character {
+            stats: Some(Stats {
+                speed: Some(Stat {
+                    cap: 10,
+                    pool: 8,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..named_character("Eona")
+        };
+        write_legacy_revision(root.path(), &uuid, 0, &character);
+
+        let s = FileStore::new(root.path().to_owned()).unwrap();
+        let read = s
+            .read_revision(&uuid, "test_user".to_owned(), 0)
+            .expect("should read legacy revision")
+            .character
+            .unwrap();
+        assert_eq!(read.name, "Eona");
+        assert_eq!(
+            read.stats.unwrap().speed.unwrap().r#type(),
+            PoolType::Speed,
+            "should upgrade the revision"
+        );
+    }
+
+    #[rstest]
+    fn write_revision_stores_schema_version() {
+        let root = tempdir().unwrap();
+        let s = FileStore::new(root.path().to_owned()).unwrap();
+        let uuid = s.create_character("test_user".to_owned()).unwrap();
+        s.write_revision(
+            &uuid,
+            "test_user".to_owned(),
+            named_character("Eona"),
+            0,
+        )
+        .unwrap();
+
+        let bytes =
+            fs::read(CharacterMetadata::revision_path(root.path(), &uuid, 0))
+                .unwrap();
+        let (character, version) = schema::decode(&bytes).unwrap();
+        assert_eq!(character, named_character("Eona"));
+        assert_eq!(version, SCHEMA_VERSION);
+    }
+
+    #[rstest]
+    fn migrate_rewrites_golden_store() {
+        let uuid = "3bd9cc82d371480cb5f0d3a512063b63";
+        let root = tempdir().unwrap();
+        copy_dir(
+            &PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("src/db/testdata/store"),
+            root.path(),
+        );
+        let read = |revision| {
+            FileStore::new(root.path().to_owned())
+                .unwrap()
+                .read_revision(uuid, "test_user".to_owned(), revision)
+                .unwrap()
+                .character
+                .unwrap()
+        };
+        let before = [read(0), read(1)];
+
+        assert_eq!(
+            FileStore::migrate(root.path()),
+            Ok(Migration {
+                characters: 1,
+                revisions: 2
+            })
+        );
+
+        for revision in 0..2 {
+            let path =
+                CharacterMetadata::revision_path(root.path(), uuid, revision);
+            let (_, version) =
+                schema::decode(&fs::read(path).unwrap()).unwrap();
+            assert_eq!(version, SCHEMA_VERSION, "should rewrite {revision}");
+            assert_eq!(read(revision), before[revision as usize]);
+        }
+        let metadata =
+            CharacterMetadata::read_from_root(root.path(), uuid).unwrap();
+        assert_eq!(metadata.latest_revision, Some(1));
+        assert_eq!(
+            metadata.stored_bytes,
+            metadata.revisions_size(root.path()),
+            "should count the rewritten revisions"
+        );
+
+        assert_eq!(
+            FileStore::migrate(root.path()),
+            Ok(Migration::default()),
+            "should skip migrated revisions"
+        );
+    }
+
+    #[rstest]
+    fn migrate_rewrites_revisions_with_large_gaps() {
+        let root = tempdir().unwrap();
+        let s = FileStore::new(root.path().to_owned()).unwrap();
+        let uuid = s.create_character("test_user".to_owned()).unwrap();
+        for revision in [0, u64::MAX / 2] {
+            write_legacy_revision(
+                root.path(),
+                &uuid,
+                revision,
+                &named_character("Eona"),
+            );
+        }
+
+        assert_eq!(
+            FileStore::migrate(root.path()),
+            Ok(Migration {
+                characters: 1,
+                revisions: 2
+            })
+        );
+        let path =
+            CharacterMetadata::revision_path(root.path(), &uuid, u64::MAX / 2);
+        let (_, version) = schema::decode(&fs::read(path).unwrap()).unwrap();
+        assert_eq!(version, SCHEMA_VERSION);
+    }
+
+    #[rstest]
+    fn migrate_fails_without_root() {
+        let root = tempdir().unwrap();
+
+        assert!(FileStore::migrate(&root.path().join("missing")).is_err());
+    }
 }
@@ -1,5 +1,7 @@
 mod errors;
 mod file;
+mod schema;
 
-pub use errors::{Error, ErrorCode};
-pub use file::FileStore;
+pub use errors::{Error, ErrorCode, Violation};
+pub use file::{FileStore, Quotas};
+pub use schema::SCHEMA_VERSION;
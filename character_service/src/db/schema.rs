@@ -0,0 +1,168 @@
+use prost::{DecodeError, Message};
+use proto_rs::character::{Character, PoolType};
+
+use crate::{
+    db::errors::{Error, ErrorCode},
+    proto::storage::StoredRevision,
+};
+
+// Upgrade migrates a character from one schema version to the next.
+type Upgrade = fn(&mut Character);
+
+// UPGRADES upgrade a character written with schema version n to n + 1 at
+// index n. Released upgrades must never change, new ones are appended.
+const UPGRADES: &[Upgrade] = &[type_stats];
+
+// SCHEMA_VERSION revisions are written with.
+pub const SCHEMA_VERSION: u32 = UPGRADES.len() as u32;
+
+// upgrade applies all upgrades from version to SCHEMA_VERSION to character.
+pub fn upgrade(character: &mut Character, version: u32) -> Result<(), Error> {
+    let upgrades = match UPGRADES.get(version as usize..) {
+        Some(upgrades) => upgrades,
+        None => {
+            return Err(Error::new(
+                ErrorCode::Internal,
+                &format!(
+                    "schema version {version} is newer than {SCHEMA_VERSION}"
+                ),
+            ))
+        }
+    };
+
+    for upgrade in upgrades {
+        upgrade(character);
+    }
+    Ok(())
+}
+
+// encode character as a revision of the current SCHEMA_VERSION.
+pub fn encode(character: Character) -> Vec<u8> {
+    StoredRevision {
+        schema_version: SCHEMA_VERSION,
+        character: Some(character),
+    }
+    .encode_to_vec()
+}
+
+// decode a revision into its character and the schema version it was written
+// with, without upgrading it.
+pub fn decode(bytes: &[u8]) -> Result<(Character, u32), DecodeError> {
+    let stored = StoredRevision::decode(bytes)?;
+    match stored.character {
+        Some(character) => Ok((character, stored.schema_version)),
+        // Revisions without a version are a bare character.
+        None => Ok((Character::decode(bytes)?, 0)),
+    }
+}
+
+// type_stats sets the type of each stat to the pool it is stored in. Early
+// versions of the app left it unset, which reads as intellect.
+fn type_stats(character: &mut Character) {
+    let stats = match character.stats.as_mut() {
+        Some(stats) => stats,
+        None => return,
+    };
+
+    let pools = [
+        (&mut stats.intellect, PoolType::Intellect),
+        (&mut stats.speed, PoolType::Speed),
+        (&mut stats.might, PoolType::Might),
+    ];
+    for (stat, pool_type) in pools {
+        if let Some(stat) = stat {
+            stat.r#type = pool_type.into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use prost::Message;
+    use proto_rs::character::{Character, PoolType, Stat, Stats};
+    use rstest::rstest;
+
+    use super::{decode, encode, upgrade, SCHEMA_VERSION};
+    use crate::db::ErrorCode;
+
+    fn legacy_character() -> Character {
+        Character {
+            uuid: "uuid".to_owned(),
+            name: "Aria".to_owned(),
+            stats: Some(Stats {
+                intellect: Some(Stat {
+                    cap: 18,
+                    pool: 15,
+                    ..Default::default()
+                }),
+                speed: Some(Stat {
+                    cap: 10,
+                    pool: 8,
+                    ..Default::default()
+                }),
+                might: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn decodes_current_revision() {
+        let character = legacy_character();
+
+        assert_eq!(
+            decode(&encode(character.clone())).unwrap(),
+            (character, SCHEMA_VERSION)
+        );
+    }
+
+    #[rstest]
+    fn decodes_bare_character_as_version_0() {
+        let character = legacy_character();
+
+        assert_eq!(decode(&character.encode_to_vec()).unwrap(), (character, 0));
+    }
+
+    #[rstest]
+    fn decodes_empty_character() {
+        assert_eq!(
+            decode(&encode(Character::default())).unwrap(),
+            (Character::default(), SCHEMA_VERSION)
+        );
+        assert_eq!(decode(&[]).unwrap(), (Character::default(), 0));
+    }
+
+    #[rstest]
+    fn rejects_malformed_revision() {
+        assert!(decode(&[0xff]).is_err());
+    }
+
+    #[rstest]
+    fn upgrades_stat_types() {
+        let mut character = legacy_character();
+
+        upgrade(&mut character, 0).unwrap();
+
+        let stats = character.stats.unwrap();
+        assert_eq!(stats.intellect.unwrap().r#type(), PoolType::Intellect);
+        assert_eq!(stats.speed.unwrap().r#type(), PoolType::Speed);
+        assert_eq!(stats.might, None);
+    }
+
+    #[rstest]
+    fn current_version_is_unchanged() {
+        let mut character = legacy_character();
+
+        upgrade(&mut character, SCHEMA_VERSION).unwrap();
+
+        assert_eq!(character, legacy_character());
+    }
+
+    #[rstest]
+    fn rejects_newer_version() {
+        let err =
+            upgrade(&mut legacy_character(), SCHEMA_VERSION + 1).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::Internal);
+    }
+}
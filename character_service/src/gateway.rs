@@ -0,0 +1,567 @@
+use std::{error::Error, net::SocketAddr, time::Duration};
+
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Router,
+};
+use prost::Message;
+use proto_rs::{
+    character::Character,
+    characters::{CharacterCreated, RevisionWritten},
+    json::{self, MessageName},
+};
+use tokio::{runtime::Runtime, sync::oneshot, task::JoinHandle};
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+use tracing::{error, info, span, warn, Level};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::{
+    db, grpc_web, rate_limit::Rpc, telemetry, validation, CharacterService,
+};
+
+const OPENAPI: &str = include_str!("../openapi.yaml");
+
+// Gateway serves the Characters API as JSON and gRPC-Web over HTTP for
+// clients that can't speak gRPC, e.g. browsers. Calls go through the same
+// drain, rate limits, validation and store as gRPC calls.
+pub struct Gateway {
+    runtime: Runtime,
+    shutdown: oneshot::Sender<()>,
+    server: JoinHandle<Result<(), hyper::Error>>,
+}
+
+impl Gateway {
+    // start serving the gateway on address.
+    pub fn start(
+        service: CharacterService,
+        address: &str,
+        cors: CorsLayer,
+    ) -> Result<Gateway, Box<dyn Error>> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .thread_name("gateway")
+            .enable_all()
+            .build()?;
+        let _runtime = runtime.enter();
+
+        let address: SocketAddr = address.parse()?;
+        let server = axum::Server::try_bind(&address)?
+            .serve(router(service, cors).into_make_service());
+        info!("gateway listening on {}", server.local_addr());
+
+        let (shutdown, signal) = oneshot::channel();
+        let server = runtime.spawn(server.with_graceful_shutdown(async {
+            signal.await.ok();
+        }));
+
+        Ok(Gateway {
+            runtime,
+            shutdown,
+            server,
+        })
+    }
+
+    // shutdown stops accepting connections and waits for open requests.
+    pub fn shutdown(self) {
+        let _ = self.shutdown.send(());
+        match self.runtime.block_on(self.server) {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => error!(err = %err, "gateway failed"),
+            Err(err) => error!(err = %err, "gateway panicked"),
+        }
+        self.runtime.shutdown_timeout(Duration::from_secs(5));
+    }
+}
+
+// cors allows browsers to call the gateway from the comma separated origins,
+// * allows any origin.
+pub fn cors(origins: Option<&str>) -> Result<CorsLayer, Box<dyn Error>> {
+    let layer = CorsLayer::new()
+        .allow_methods([Method::GET, Method::POST, Method::PUT])
+        .allow_headers([
+            header::CONTENT_TYPE,
+            header::AUTHORIZATION,
+            HeaderName::from_static("traceparent"),
+            HeaderName::from_static("x-grpc-web"),
+            HeaderName::from_static("x-user-agent"),
+            HeaderName::from_static("grpc-timeout"),
+        ])
+        .expose_headers([
+            HeaderName::from_static("grpc-status"),
+            HeaderName::from_static("grpc-message"),
+            HeaderName::from_static("grpc-status-details-bin"),
+        ]);
+
+    let origins = match origins {
+        Some("*") => return Ok(layer.allow_origin(Any)),
+        Some(origins) => origins
+            .split(',')
+            .map(|origin| HeaderValue::from_str(origin.trim()))
+            .collect::<Result<Vec<HeaderValue>, _>>()?,
+        None => vec![],
+    };
+    Ok(layer.allow_origin(AllowOrigin::list(origins)))
+}
+
+pub fn router(service: CharacterService, cors: CorsLayer) -> Router {
+    Router::new()
+        .route("/characters", post(create))
+        .route("/characters/:uuid/revisions/latest", get(read_latest))
+        .route(
+            "/characters/:uuid/revisions/:revision",
+            get(read).put(write),
+        )
+        .route("/openapi.yaml", get(openapi))
+        .merge(grpc_web::router())
+        .layer(cors)
+        .with_state(service)
+}
+
+async fn create(
+    State(service): State<CharacterService>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let created =
+        call(service, headers, "Create", Rpc::Create, create_character).await?;
+
+    let location = format!("/characters/{}", created.uuid);
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, location)],
+        JsonMessage(created),
+    )
+        .into_response())
+}
+
+async fn write(
+    State(service): State<CharacterService>,
+    Path((uuid, revision)): Path<(String, u64)>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, ApiError> {
+    let method = "WriteCharacterRevision";
+    let written = call(service, headers, method, Rpc::Write, move |service| {
+        let character: Character =
+            serde_json::from_slice(&body).map_err(|err| {
+                db::Error::new(
+                    db::ErrorCode::InvalidArgument,
+                    &format!("invalid character: {err}"),
+                )
+            })?;
+
+        write_revision(service, uuid, character, revision)
+    })
+    .await?;
+
+    Ok(JsonMessage(written).into_response())
+}
+
+async fn read(
+    State(service): State<CharacterService>,
+    Path((uuid, revision)): Path<(String, u64)>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let method = "ReadCharacterRevision";
+    let read = call(service, headers, method, Rpc::Read, move |service| {
+        service
+            .db
+            .read_revision(&uuid, service.user.clone(), revision)
+    })
+    .await?;
+
+    Ok(JsonMessage(read).into_response())
+}
+
+async fn read_latest(
+    State(service): State<CharacterService>,
+    Path(uuid): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let method = "ReadLatestCharacterRevision";
+    let read = call(service, headers, method, Rpc::Read, move |service| {
+        service.db.read_latest_revision(&uuid, service.user.clone())
+    })
+    .await?;
+
+    Ok(JsonMessage(read).into_response())
+}
+
+// create_character creates a character owned by the user of service.
+pub fn create_character(
+    service: &CharacterService,
+) -> Result<CharacterCreated, db::Error> {
+    Ok(CharacterCreated {
+        uuid: service.db.create_character(service.user.clone())?,
+    })
+}
+
+// write_revision validates and stores a revision of a character.
+pub fn write_revision(
+    service: &CharacterService,
+    uuid: String,
+    character: Character,
+    revision: u64,
+) -> Result<RevisionWritten, db::Error> {
+    if let Err(err) = validation::validate_revision(&uuid, &character) {
+        error!(err = %err, "invalid character revision");
+        return Err(err);
+    }
+
+    let revision = service.db.write_revision(
+        &uuid,
+        service.user.clone(),
+        character,
+        revision,
+    )?;
+    Ok(RevisionWritten { uuid, revision })
+}
+
+async fn openapi() -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "application/yaml")], OPENAPI)
+}
+
+// call runs f on the blocking thread pool with the same bookkeeping as the
+// gRPC handlers.
+pub async fn call<T, F>(
+    service: CharacterService,
+    headers: HeaderMap,
+    method: &'static str,
+    rpc: Rpc,
+    f: F,
+) -> Result<T, db::Error>
+where
+    T: Send + 'static,
+    F: FnOnce(&CharacterService) -> Result<T, db::Error> + Send + 'static,
+{
+    let span =
+        span!(target: "character_service", Level::TRACE, "gateway", method);
+    span.set_parent(telemetry::remote_context(
+        headers
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_bytes())),
+    ));
+
+    let res = tokio::task::spawn_blocking(move || {
+        let _enter = span.enter();
+        let _timer = service.metrics.start_request(method);
+
+        let res = match service.drain.begin() {
+            Ok(_call) => service
+                .limiter
+                .check(&service.user, rpc)
+                .and_then(|_| f(&service)),
+            Err(status) => Err(db::Error::new(
+                db::ErrorCode::Unavailable,
+                status.message(),
+            )),
+        };
+        if let Err(err) = &res {
+            service.metrics.record_error(method, err.code());
+        }
+        res
+    })
+    .await;
+
+    match res {
+        Ok(res) => res,
+        Err(err) => {
+            warn!(err = %err, method, "gateway call failed");
+            Err(db::Error::new(db::ErrorCode::Internal, "call failed"))
+        }
+    }
+}
+
+// JsonMessage responds with the canonical JSON of a message, including
+// fields set to their default value.
+struct JsonMessage<M>(M);
+
+impl<M: Message + MessageName> IntoResponse for JsonMessage<M> {
+    fn into_response(self) -> Response {
+        match json::to_value_with_defaults(&self.0) {
+            Ok(body) => axum::Json(body).into_response(),
+            Err(err) => {
+                error!(err = %err, "failed to encode response");
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        }
+    }
+}
+
+// ApiError responds with the error message and its ErrorDetails.
+pub struct ApiError(db::Error);
+
+impl From<db::Error> for ApiError {
+    fn from(err: db::Error) -> Self {
+        ApiError(err)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match self.0.code() {
+            db::ErrorCode::NotFound => StatusCode::NOT_FOUND,
+            db::ErrorCode::Unauthorized => StatusCode::FORBIDDEN,
+            db::ErrorCode::OutOfOrder | db::ErrorCode::Exists => {
+                StatusCode::CONFLICT
+            }
+            db::ErrorCode::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+            db::ErrorCode::InvalidArgument => StatusCode::BAD_REQUEST,
+            db::ErrorCode::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+            db::ErrorCode::Internal | db::ErrorCode::Unknown => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+
+        let mut body = match json::to_value(&self.0.details()) {
+            Ok(serde_json::Value::Object(body)) => body,
+            _ => Default::default(),
+        };
+        // Out of order writes report the latest revision, even if it is 0.
+        if let Some(latest_revision) = self.0.latest_revision() {
+            body.insert(
+                "latestRevision".to_owned(),
+                serde_json::Value::String(latest_revision.to_string()),
+            );
+        }
+        body.insert(
+            "message".to_owned(),
+            serde_json::Value::String(self.0.message()),
+        );
+
+        (status, axum::Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use axum::{
+        body::Body,
+        http::{header, Method, Request, StatusCode},
+        Router,
+    };
+    use rstest::rstest;
+    use serde_json::{json, Value};
+    use tempfile::{tempdir, TempDir};
+    use tower::ServiceExt;
+
+    use super::{cors, router, OPENAPI};
+    use crate::{
+        db::FileStore, metrics::Metrics, rate_limit::RateLimiter,
+        shutdown::Drain, CharacterService,
+    };
+
+    struct Fixture {
+        router: Router,
+        _root: TempDir,
+    }
+
+    fn fixture() -> Fixture {
+        let root = tempdir().unwrap();
+        let service = CharacterService {
+            db: Arc::new(FileStore::new(root.path().to_owned()).unwrap()),
+            drain: Arc::new(Drain::new()),
+            metrics: Arc::new(Metrics::new().unwrap()),
+            limiter: Arc::new(RateLimiter::new([])),
+            user: "test_user".to_owned(),
+        };
+
+        Fixture {
+            router: router(
+                service,
+                cors(Some("https://sheet.example.com")).unwrap(),
+            ),
+            _root: root,
+        }
+    }
+
+    async fn send(
+        router: &Router,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(match body {
+                Some(body) => Body::from(body.to_string()),
+                None => Body::empty(),
+            })
+            .unwrap();
+
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    async fn create(router: &Router) -> String {
+        let (status, body) =
+            send(router, Method::POST, "/characters", None).await;
+        assert_eq!(status, StatusCode::CREATED);
+        body["uuid"].as_str().unwrap().to_owned()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn writes_and_reads_revisions() {
+        let fixture = fixture();
+        let uuid = create(&fixture.router).await;
+
+        for revision in 0..2 {
+            let (status, body) = send(
+                &fixture.router,
+                Method::PUT,
+                &format!("/characters/{uuid}/revisions/{revision}"),
+                Some(json!({
+                    "uuid": uuid,
+                    "name": format!("Aria {revision}"),
+                    "stats": {
+                        "might": {"type": "might", "pool": 10, "cap": 12},
+                    },
+                })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(
+                body,
+                json!({"uuid": uuid, "revision": revision.to_string()})
+            );
+        }
+
+        let (status, body) = send(
+            &fixture.router,
+            Method::GET,
+            &format!("/characters/{uuid}/revisions/latest"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["revision"], json!("1"));
+        assert_eq!(body["character"]["name"], json!("Aria 1"));
+        assert_eq!(
+            body["character"]["stats"]["might"],
+            json!({"type": "might", "pool": 10, "cap": 12, "edge": 0})
+        );
+
+        let (status, body) = send(
+            &fixture.router,
+            Method::GET,
+            &format!("/characters/{uuid}/revisions/0"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["character"]["name"], json!("Aria 0"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn out_of_order_write() {
+        let fixture = fixture();
+        let uuid = create(&fixture.router).await;
+        let uri = format!("/characters/{uuid}/revisions/0");
+        let character = json!({"uuid": uuid});
+
+        send(&fixture.router, Method::PUT, &uri, Some(character.clone())).await;
+        let (status, body) =
+            send(&fixture.router, Method::PUT, &uri, Some(character)).await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["reason"], json!("OUT_OF_ORDER"));
+        assert_eq!(body["latestRevision"], json!("0"));
+    }
+
+    #[rstest]
+    #[case(json!({"uuid": "other"}), "character.uuid")]
+    #[case(json!({"unknown": 1}), "unrecognized field name 'unknown'")]
+    #[case(json!({"stats": {"might": {"pool": "a"}}}), "invalid digit")]
+    #[tokio::test]
+    async fn rejects_invalid_characters(
+        #[case] character: Value,
+        #[case] message: &str,
+    ) {
+        let fixture = fixture();
+        let uuid = create(&fixture.router).await;
+
+        let (status, body) = send(
+            &fixture.router,
+            Method::PUT,
+            &format!("/characters/{uuid}/revisions/0"),
+            Some(character),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["reason"], json!("INVALID_ARGUMENT"));
+        assert!(body["message"].as_str().unwrap().contains(message));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn missing_character() {
+        let fixture = fixture();
+
+        let (status, body) = send(
+            &fixture.router,
+            Method::GET,
+            "/characters/missing/revisions/latest",
+            None,
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["reason"], json!("NOT_FOUND"));
+    }
+
+    #[rstest]
+    #[case("https://sheet.example.com", true)]
+    #[case("https://other.example.com", false)]
+    #[tokio::test]
+    async fn allows_configured_origins(
+        #[case] origin: &str,
+        #[case] allowed: bool,
+    ) {
+        let fixture = fixture();
+        let request = Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/characters")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = fixture.router.oneshot(request).await.unwrap();
+
+        assert_eq!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .is_some(),
+            allowed
+        );
+    }
+
+    #[rstest]
+    fn openapi_documents_routes() {
+        let document: serde_yaml::Value =
+            serde_yaml::from_str(OPENAPI).unwrap();
+
+        for path in [
+            "/characters",
+            "/characters/{uuid}/revisions/latest",
+            "/characters/{uuid}/revisions/{revision}",
+        ] {
+            assert!(
+                document["paths"].get(path).is_some(),
+                "missing path {path}"
+            );
+        }
+    }
+}
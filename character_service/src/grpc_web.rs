@@ -0,0 +1,523 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Router,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use grpcio::{RpcStatus, RpcStatusCode};
+use prost::Message;
+use proto_rs::characters::{
+    CreateCharacter, ReadLatestRevision, ReadRevision, WriteRevision,
+};
+use tracing::trace;
+
+use crate::{db, gateway, rate_limit::Rpc, CharacterService};
+
+// Flag of the frame carrying the trailers, data frames use 0.
+const TRAILERS_FLAG: u8 = 0x80;
+
+// router serves the Characters service over gRPC-Web, so browsers can call
+// it with clients generated from the .proto files.
+pub fn router() -> Router<CharacterService> {
+    Router::new().route("/characters.Characters/:method", post(handle))
+}
+
+async fn handle(
+    State(service): State<CharacterService>,
+    Path(method): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let encoding = match Encoding::from_headers(&headers) {
+        Some(encoding) => encoding,
+        None => return StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response(),
+    };
+
+    let res = match encoding.decode(&body) {
+        Ok(message) => dispatch(service, headers, &method, message).await,
+        Err(status) => Err(status),
+    };
+    encoding.respond(res)
+}
+
+// dispatch calls method with the encoded request message, returning the
+// encoded response message.
+async fn dispatch(
+    service: CharacterService,
+    headers: HeaderMap,
+    method: &str,
+    message: Vec<u8>,
+) -> Result<Vec<u8>, RpcStatus> {
+    match method {
+        "Create" => {
+            parse::<CreateCharacter>(&message)?;
+            let created = gateway::call(
+                service,
+                headers,
+                "Create",
+                Rpc::Create,
+                gateway::create_character,
+            )
+            .await?;
+            Ok(created.encode_to_vec())
+        }
+        "WriteCharacterRevision" => {
+            let mut req = parse::<WriteRevision>(&message)?;
+            let method = "WriteCharacterRevision";
+            let written = gateway::call(
+                service,
+                headers,
+                method,
+                Rpc::Write,
+                move |service| {
+                    let character = req.character.take().ok_or_else(|| {
+                        db::Error::new(
+                            db::ErrorCode::InvalidArgument,
+                            "missing character data",
+                        )
+                    })?;
+                    trace!(
+                        character = req.uuid,
+                        revision = req.revision,
+                        "received character revision",
+                    );
+                    gateway::write_revision(
+                        service,
+                        req.uuid,
+                        character,
+                        req.revision,
+                    )
+                },
+            )
+            .await?;
+            Ok(written.encode_to_vec())
+        }
+        "ReadCharacterRevision" => {
+            let req = parse::<ReadRevision>(&message)?;
+            let method = "ReadCharacterRevision";
+            let read = gateway::call(
+                service,
+                headers,
+                method,
+                Rpc::Read,
+                move |service| {
+                    service.db.read_revision(
+                        &req.uuid,
+                        service.user.clone(),
+                        req.revision,
+                    )
+                },
+            )
+            .await?;
+            Ok(read.encode_to_vec())
+        }
+        "ReadLatestCharacterRevision" => {
+            let req = parse::<ReadLatestRevision>(&message)?;
+            let method = "ReadLatestCharacterRevision";
+            let read = gateway::call(
+                service,
+                headers,
+                method,
+                Rpc::Read,
+                move |service| {
+                    service
+                        .db
+                        .read_latest_revision(&req.uuid, service.user.clone())
+                },
+            )
+            .await?;
+            Ok(read.encode_to_vec())
+        }
+        _ => Err(RpcStatus::with_message(
+            RpcStatusCode::UNIMPLEMENTED,
+            format!("unknown method characters.Characters/{method}"),
+        )),
+    }
+}
+
+fn parse<M: Message + Default>(message: &[u8]) -> Result<M, RpcStatus> {
+    M::decode(message).map_err(|err| {
+        RpcStatus::with_message(
+            RpcStatusCode::INVALID_ARGUMENT,
+            format!("invalid request: {err}"),
+        )
+    })
+}
+
+// Encoding of a gRPC-Web body, grpc-web-text bodies are base64 encoded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Encoding {
+    Binary,
+    Text,
+}
+
+impl Encoding {
+    fn from_headers(headers: &HeaderMap) -> Option<Encoding> {
+        let content_type = headers.get(header::CONTENT_TYPE)?.to_str().ok()?;
+        match content_type {
+            "application/grpc-web" | "application/grpc-web+proto" => {
+                Some(Encoding::Binary)
+            }
+            "application/grpc-web-text" | "application/grpc-web-text+proto" => {
+                Some(Encoding::Text)
+            }
+            _ => None,
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Encoding::Binary => "application/grpc-web+proto",
+            Encoding::Text => "application/grpc-web-text+proto",
+        }
+    }
+
+    // decode returns the message of the single data frame in body.
+    fn decode(self, body: &[u8]) -> Result<Vec<u8>, RpcStatus> {
+        let invalid = |message: &str| {
+            RpcStatus::with_message(
+                RpcStatusCode::INVALID_ARGUMENT,
+                message.to_owned(),
+            )
+        };
+
+        let body = match self {
+            Encoding::Binary => body.to_vec(),
+            Encoding::Text => STANDARD
+                .decode(body)
+                .map_err(|_| invalid("request is not valid base64"))?,
+        };
+
+        if body.len() < 5 {
+            return Err(invalid("request is missing the frame header"));
+        }
+        if body[0] != 0 {
+            return Err(RpcStatus::with_message(
+                RpcStatusCode::UNIMPLEMENTED,
+                "compressed requests are not supported".to_owned(),
+            ));
+        }
+        let length = u32::from_be_bytes([body[1], body[2], body[3], body[4]]);
+        if body.len() - 5 != length as usize {
+            return Err(invalid("request frame length doesn't match body"));
+        }
+        Ok(body[5..].to_vec())
+    }
+
+    // respond frames the response message followed by the trailers with the
+    // status of the call.
+    fn respond(self, res: Result<Vec<u8>, RpcStatus>) -> Response {
+        let mut body = vec![];
+        let status = match res {
+            Ok(message) => {
+                frame(&mut body, 0, &message);
+                RpcStatus::ok()
+            }
+            Err(status) => status,
+        };
+
+        let code: i32 = status.code().into();
+        let mut trailers = format!(
+            "grpc-status:{code}\r\ngrpc-message:{}\r\n",
+            percent_encode(status.message())
+        );
+        if !status.details().is_empty() {
+            trailers.push_str(&format!(
+                "grpc-status-details-bin:{}\r\n",
+                base64::engine::general_purpose::STANDARD_NO_PAD
+                    .encode(status.details())
+            ));
+        }
+        frame(&mut body, TRAILERS_FLAG, trailers.as_bytes());
+
+        let body = match self {
+            Encoding::Binary => body,
+            Encoding::Text => STANDARD.encode(body).into_bytes(),
+        };
+        (
+            [(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(self.content_type()),
+            )],
+            body,
+        )
+            .into_response()
+    }
+}
+
+fn frame(body: &mut Vec<u8>, flag: u8, message: &[u8]) {
+    body.push(flag);
+    body.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    body.extend_from_slice(message);
+}
+
+// percent_encode encodes a grpc-message, keeping printable ASCII except %.
+fn percent_encode(message: &str) -> String {
+    message
+        .bytes()
+        .map(|byte| match byte {
+            b' '..=b'~' if byte != b'%' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+
+    use axum::http::{header, Method, Request, StatusCode};
+    use base64::{
+        engine::general_purpose::{STANDARD, STANDARD_NO_PAD},
+        Engine,
+    };
+    use grpcio::{RpcStatus, RpcStatusCode};
+    use hyper::{client::HttpConnector, Body, Client};
+    use prost::Message;
+    use proto_rs::{
+        character::Character,
+        characters::error_details::Reason,
+        characters::{
+            CharacterCreated, CreateCharacter, ReadLatestRevision,
+            ReadRevision, RevisionRead, RevisionWritten, WriteRevision,
+        },
+        details::error_details,
+    };
+    use rstest::rstest;
+    use tempfile::{tempdir, TempDir};
+
+    use super::{percent_encode, Encoding};
+    use crate::{
+        db::FileStore, gateway, metrics::Metrics, rate_limit::RateLimiter,
+        shutdown::Drain, CharacterService,
+    };
+
+    // GrpcWebClient calls the service like a browser client generated by
+    // protoc-gen-grpc-web.
+    struct GrpcWebClient {
+        address: SocketAddr,
+        encoding: Encoding,
+        client: Client<HttpConnector>,
+        _root: TempDir,
+    }
+
+    impl GrpcWebClient {
+        // start serves the gateway on a local port.
+        fn start(encoding: Encoding) -> GrpcWebClient {
+            let root = tempdir().unwrap();
+            let service = CharacterService {
+                db: Arc::new(FileStore::new(root.path().to_owned()).unwrap()),
+                drain: Arc::new(Drain::new()),
+                metrics: Arc::new(Metrics::new().unwrap()),
+                limiter: Arc::new(RateLimiter::new([])),
+                user: "test_user".to_owned(),
+            };
+            let router = gateway::router(service, gateway::cors(None).unwrap());
+            let server = axum::Server::bind(&"127.0.0.1:0".parse().unwrap())
+                .serve(router.into_make_service());
+            let address = server.local_addr();
+            tokio::spawn(server);
+
+            GrpcWebClient {
+                address,
+                encoding,
+                client: Client::new(),
+                _root: root,
+            }
+        }
+
+        async fn call<Req: Message, Resp: Message + Default>(
+            &self,
+            method: &str,
+            req: &Req,
+        ) -> Result<Resp, RpcStatus> {
+            // Frames are written and read by hand, so they don't depend on
+            // the gateway's framing: a flag byte, the big endian length and
+            // the message.
+            let message = req.encode_to_vec();
+            let mut body = vec![0];
+            body.extend((message.len() as u32).to_be_bytes());
+            body.extend(message);
+            let (content_type, body) = match self.encoding {
+                Encoding::Binary => ("application/grpc-web+proto", body),
+                Encoding::Text => (
+                    "application/grpc-web-text",
+                    STANDARD.encode(body).into_bytes(),
+                ),
+            };
+
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri(format!(
+                    "http://{}/characters.Characters/{method}",
+                    self.address
+                ))
+                .header(header::CONTENT_TYPE, content_type)
+                .header("x-grpc-web", "1")
+                .body(Body::from(body))
+                .unwrap();
+            let response = self.client.request(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body =
+                hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let mut body = match self.encoding {
+                Encoding::Binary => body.to_vec(),
+                Encoding::Text => STANDARD.decode(body).unwrap(),
+            };
+
+            let mut message = None;
+            let mut trailers = HashMap::new();
+            while !body.is_empty() {
+                let length =
+                    u32::from_be_bytes([body[1], body[2], body[3], body[4]]);
+                let rest = body.split_off(5 + length as usize);
+                if body[0] == 0x80 {
+                    for line in std::str::from_utf8(&body[5..])
+                        .unwrap()
+                        .split_terminator("\r\n")
+                    {
+                        let (key, value) = line.split_once(':').unwrap();
+                        trailers.insert(key.to_owned(), value.to_owned());
+                    }
+                } else {
+                    message = Some(Resp::decode(&body[5..]).unwrap());
+                }
+                body = rest;
+            }
+
+            let code: i32 = trailers["grpc-status"].parse().unwrap();
+            if code == 0 {
+                return Ok(message.unwrap());
+            }
+            let details = trailers
+                .get("grpc-status-details-bin")
+                .map(|details| STANDARD_NO_PAD.decode(details).unwrap())
+                .unwrap_or_default();
+            Err(RpcStatus::with_details(
+                code,
+                trailers["grpc-message"].clone(),
+                details,
+            ))
+        }
+
+        async fn create(&self) -> String {
+            let created: CharacterCreated =
+                self.call("Create", &CreateCharacter {}).await.unwrap();
+            created.uuid
+        }
+
+        async fn write(
+            &self,
+            uuid: &str,
+            revision: u64,
+            name: &str,
+        ) -> Result<RevisionWritten, RpcStatus> {
+            let req = WriteRevision {
+                uuid: uuid.to_owned(),
+                revision,
+                character: Some(Character {
+                    uuid: uuid.to_owned(),
+                    name: name.to_owned(),
+                    ..Default::default()
+                }),
+            };
+            self.call("WriteCharacterRevision", &req).await
+        }
+    }
+
+    #[rstest]
+    #[case(Encoding::Binary)]
+    #[case(Encoding::Text)]
+    #[tokio::test]
+    async fn writes_and_reads_revisions(#[case] encoding: Encoding) {
+        let client = GrpcWebClient::start(encoding);
+        let uuid = client.create().await;
+
+        for revision in 0..2 {
+            let written = client
+                .write(&uuid, revision, &format!("Aria {revision}"))
+                .await
+                .unwrap();
+            assert_eq!(written.revision, revision);
+        }
+
+        let req = ReadLatestRevision { uuid: uuid.clone() };
+        let read: RevisionRead = client
+            .call("ReadLatestCharacterRevision", &req)
+            .await
+            .unwrap();
+        assert_eq!(read.revision, 1);
+        assert_eq!(read.character.unwrap().name, "Aria 1");
+
+        let req = ReadRevision { uuid, revision: 0 };
+        let read: RevisionRead =
+            client.call("ReadCharacterRevision", &req).await.unwrap();
+        assert_eq!(read.character.unwrap().name, "Aria 0");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn out_of_order_write_sends_details() {
+        let client = GrpcWebClient::start(Encoding::Binary);
+        let uuid = client.create().await;
+        client.write(&uuid, 0, "Aria").await.unwrap();
+
+        let status = client.write(&uuid, 0, "Aria").await.unwrap_err();
+
+        assert_eq!(status.code(), RpcStatusCode::INVALID_ARGUMENT);
+        let details = error_details(&status).unwrap().unwrap();
+        assert_eq!(details.reason(), Reason::OutOfOrder);
+        assert_eq!(details.latest_revision, 0);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn missing_character() {
+        let client = GrpcWebClient::start(Encoding::Binary);
+        let req = ReadLatestRevision {
+            uuid: "missing".to_owned(),
+        };
+
+        let status = client
+            .call::<_, RevisionRead>("ReadLatestCharacterRevision", &req)
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), RpcStatusCode::NOT_FOUND);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn unknown_method() {
+        let client = GrpcWebClient::start(Encoding::Binary);
+
+        let status = client
+            .call::<_, CharacterCreated>("Delete", &CreateCharacter {})
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), RpcStatusCode::UNIMPLEMENTED);
+    }
+
+    #[rstest]
+    #[case(&[], RpcStatusCode::INVALID_ARGUMENT)]
+    #[case(&[0, 0, 0, 0, 2, 1], RpcStatusCode::INVALID_ARGUMENT)]
+    #[case(&[1, 0, 0, 0, 0], RpcStatusCode::UNIMPLEMENTED)]
+    fn rejects_malformed_frames(
+        #[case] body: &[u8],
+        #[case] code: RpcStatusCode,
+    ) {
+        assert_eq!(Encoding::Binary.decode(body).unwrap_err().code(), code);
+    }
+
+    #[rstest]
+    #[case("missing character", "missing character")]
+    #[case("100% done", "100%25 done")]
+    #[case("line\nbreak", "line%0Abreak")]
+    fn encodes_messages(#[case] message: &str, #[case] expected: &str) {
+        assert_eq!(percent_encode(message), expected);
+    }
+}
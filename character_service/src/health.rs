@@ -0,0 +1,84 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use grpcio_health::{HealthService, ServingStatus};
+use tracing::debug;
+
+use crate::db::FileStore;
+
+// The service name reported by the health service, next to the overall
+// server status reported for "".
+pub const CHARACTERS_SERVICE: &str = "characters.Characters";
+
+// HealthReporter keeps the health service in sync with the store.
+pub struct HealthReporter {
+    health: HealthService,
+    db: Arc<FileStore>,
+    stopped: AtomicBool,
+}
+
+impl HealthReporter {
+    pub fn new(health: HealthService, db: Arc<FileStore>) -> HealthReporter {
+        HealthReporter {
+            health,
+            db,
+            stopped: AtomicBool::new(false),
+        }
+    }
+
+    // check updates the health service with the current store status.
+    pub fn check(&self) {
+        let status = serving_status(self.db.healthy());
+        debug!(status = ?status, "updating serving status");
+
+        self.health.set_serving_status("", status);
+        self.health.set_serving_status(CHARACTERS_SERVICE, status);
+    }
+
+    // watch checks the store health every interval until shutdown.
+    pub fn watch(self: Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        thread::spawn(move || {
+            while !self.stopped.load(Ordering::Acquire) {
+                self.check();
+                thread::sleep(interval);
+            }
+        })
+    }
+
+    // shutdown reports all services as not serving from now on.
+    pub fn shutdown(&self) {
+        self.stopped.store(true, Ordering::Release);
+        self.health.shutdown();
+    }
+}
+
+fn serving_status(healthy: bool) -> ServingStatus {
+    match healthy {
+        true => ServingStatus::Serving,
+        false => ServingStatus::NotServing,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use grpcio_health::ServingStatus;
+    use rstest::rstest;
+
+    use super::serving_status;
+
+    #[rstest]
+    #[case(true, ServingStatus::Serving)]
+    #[case(false, ServingStatus::NotServing)]
+    fn serving_status_follows_store_health(
+        #[case] healthy: bool,
+        #[case] expected: ServingStatus,
+    ) {
+        assert_eq!(serving_status(healthy), expected);
+    }
+}
@@ -1,51 +1,92 @@
 mod proto;
 
 mod db;
+mod gateway;
+mod grpc_web;
+mod health;
+mod metrics;
+mod rate_limit;
+mod reflection;
+mod shutdown;
+mod telemetry;
+mod tls;
+mod validation;
 
-use std::{
-    io::{self, Read},
-    sync::Arc,
-    thread,
-};
+use std::{sync::Arc, time::Duration};
 
-use futures::{channel::oneshot, executor::block_on};
+use character_service::{Options, StoreBackend};
+use clap::{Parser, Subcommand};
+use dotenv::dotenv;
+use futures::executor::block_on;
 use grpcio::{
-    ChannelBuilder, Environment, ResourceQuota, RpcStatus, RpcStatusCode,
-    ServerBuilder, ServerCredentials,
-};
-use proto_rs::{
-    characters::{
-        ReadLatestRevision, ReadRevision, RevisionRead, WriteRevision,
-    },
-    characters_grpc::{self},
+    ChannelBuilder, Environment, ResourceQuota, ServerBuilder,
+    ServerCredentials, UnarySink, UnarySinkResult,
 };
-use tracing::{error, info, span, trace, Level};
-use tracing_subscriber::FmtSubscriber;
-
-use proto_rs::{
-    characters::{CharacterCreated, CreateCharacter, RevisionWritten},
-    characters_grpc::create_characters,
+use grpcio_health::{create_health, HealthService};
+use proto_rs::characters::{
+    self, create_characters, CharacterCreated, CreateCharacter,
+    ReadLatestRevision, ReadRevision, RevisionRead, RevisionWritten,
+    WriteRevision,
 };
+use tracing::{error, info, span, trace, warn, Level};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 #[derive(Clone)]
 struct CharacterService {
     db: Arc<db::FileStore>,
+    drain: Arc<shutdown::Drain>,
+    metrics: Arc<metrics::Metrics>,
+    limiter: Arc<rate_limit::RateLimiter>,
+    user: String,
 }
 
-impl characters_grpc::Characters for CharacterService {
+impl CharacterService {
+    // fail records the error for method before failing the call.
+    fn fail<T>(
+        &self,
+        method: &str,
+        sink: UnarySink<T>,
+        err: db::Error,
+    ) -> UnarySinkResult {
+        self.metrics.record_error(method, err.code());
+        sink.fail(err.into())
+    }
+}
+
+impl characters::Characters for CharacterService {
     fn create(
         &mut self,
-        _ctx: grpcio::RpcContext,
+        ctx: grpcio::RpcContext,
         _req: CreateCharacter,
         sink: grpcio::UnarySink<CharacterCreated>,
     ) {
         let span = span!(
             target: "character_service",
             Level::TRACE,
-            "write_character_revision",
+            "create_character",
         );
+        span.set_parent(telemetry::remote_context(ctx.request_headers()));
         let _enter = span.enter();
 
+        let _timer = self.metrics.start_request("Create");
+
+        let _call = match self.drain.begin() {
+            Ok(call) => call,
+            Err(status) => {
+                self.metrics
+                    .record_error("Create", db::ErrorCode::Unavailable);
+                sink.fail(status);
+                return;
+            }
+        };
+
+        if let Err(err) =
+            self.limiter.check(&self.user, rate_limit::Rpc::Create)
+        {
+            self.fail("Create", sink, err);
+            return;
+        }
+
         // TODO: for the hosted service we need to add authn/authz so characters are
         // gated to users.
         // It doesn't serve much benefit to add right now as it would only be
@@ -57,18 +98,15 @@ impl characters_grpc::Characters for CharacterService {
 
         trace!(parent: &span, "received character creation request",);
 
-        match self.db.clone().create_character("test_user".to_owned()) {
-            Ok(uuid) => sink.success(CharacterCreated {
-                uuid,
-                ..Default::default()
-            }),
-            Err(err) => sink.fail(err.into()),
+        match self.db.clone().create_character(self.user.clone()) {
+            Ok(uuid) => sink.success(CharacterCreated { uuid }),
+            Err(err) => self.fail("Create", sink, err),
         };
     }
 
     fn write_character_revision(
         &mut self,
-        _ctx: grpcio::RpcContext,
+        ctx: grpcio::RpcContext,
         mut req: WriteRevision,
         sink: grpcio::UnarySink<RevisionWritten>,
     ) {
@@ -77,8 +115,29 @@ impl characters_grpc::Characters for CharacterService {
             Level::TRACE,
             "write_character_revision",
         );
+        span.set_parent(telemetry::remote_context(ctx.request_headers()));
         let _enter = span.enter();
 
+        let _timer = self.metrics.start_request("WriteCharacterRevision");
+
+        let _call = match self.drain.begin() {
+            Ok(call) => call,
+            Err(status) => {
+                self.metrics.record_error(
+                    "WriteCharacterRevision",
+                    db::ErrorCode::Unavailable,
+                );
+                sink.fail(status);
+                return;
+            }
+        };
+
+        if let Err(err) = self.limiter.check(&self.user, rate_limit::Rpc::Write)
+        {
+            self.fail("WriteCharacterRevision", sink, err);
+            return;
+        }
+
         // TODO: for the hosted service we need to add authn/authz so characters are
         // gated to users.
         // It doesn't serve much benefit to add right now as it would only be
@@ -92,41 +151,48 @@ impl characters_grpc::Characters for CharacterService {
             Some(character) => character,
             None => {
                 error!(parent: &span, "missing character data");
-                sink.fail(RpcStatus::with_message(
-                    RpcStatusCode::INVALID_ARGUMENT,
-                    "missing character data".to_owned(),
-                ));
+                self.fail(
+                    "WriteCharacterRevision",
+                    sink,
+                    db::Error::new(
+                        db::ErrorCode::InvalidArgument,
+                        "missing character data",
+                    ),
+                );
                 return;
             }
         };
 
-        let character_uuid = character.uuid.clone();
-
         trace!(
             parent: &span,
-            character = character_uuid,
+            character = req.uuid,
             revision = req.revision,
             "received character revision",
         );
 
+        if let Err(err) = validation::validate_revision(&req.uuid, &character) {
+            error!(parent: &span, err = %err, "invalid character revision");
+            self.fail("WriteCharacterRevision", sink, err);
+            return;
+        }
+
         match self.db.clone().write_revision(
-            &character_uuid,
-            "test_user".to_owned(),
+            &req.uuid,
+            self.user.clone(),
             character,
             req.revision,
         ) {
             Ok(revision) => sink.success(RevisionWritten {
                 revision,
-                uuid: character_uuid,
-                ..Default::default()
+                uuid: req.uuid,
             }),
-            Err(err) => sink.fail(err.into()),
+            Err(err) => self.fail("WriteCharacterRevision", sink, err),
         };
     }
 
     fn read_character_revision(
         &mut self,
-        _ctx: grpcio::RpcContext,
+        ctx: grpcio::RpcContext,
         req: ReadRevision,
         sink: grpcio::UnarySink<RevisionRead>,
     ) {
@@ -135,8 +201,29 @@ impl characters_grpc::Characters for CharacterService {
             Level::TRACE,
             "read_character_revision",
         );
+        span.set_parent(telemetry::remote_context(ctx.request_headers()));
         let _enter = span.enter();
 
+        let _timer = self.metrics.start_request("ReadCharacterRevision");
+
+        let _call = match self.drain.begin() {
+            Ok(call) => call,
+            Err(status) => {
+                self.metrics.record_error(
+                    "ReadCharacterRevision",
+                    db::ErrorCode::Unavailable,
+                );
+                sink.fail(status);
+                return;
+            }
+        };
+
+        if let Err(err) = self.limiter.check(&self.user, rate_limit::Rpc::Read)
+        {
+            self.fail("ReadCharacterRevision", sink, err);
+            return;
+        }
+
         // TODO: for the hosted service we need to add authn/authz so characters are
         // gated to users.
         // It doesn't serve much benefit to add right now as it would only be
@@ -155,27 +242,48 @@ impl characters_grpc::Characters for CharacterService {
 
         match self.db.clone().read_revision(
             &req.uuid,
-            "test_user".to_owned(),
+            self.user.clone(),
             req.revision,
         ) {
             Ok(revision) => sink.success(revision),
-            Err(err) => sink.fail(err.into()),
+            Err(err) => self.fail("ReadCharacterRevision", sink, err),
         };
     }
 
     fn read_latest_character_revision(
         &mut self,
-        _ctx: grpcio::RpcContext,
+        ctx: grpcio::RpcContext,
         req: ReadLatestRevision,
         sink: grpcio::UnarySink<RevisionRead>,
     ) {
         let span = span!(
             target: "character_service",
             Level::TRACE,
-            "read_character_revision",
+            "read_latest_character_revision",
         );
+        span.set_parent(telemetry::remote_context(ctx.request_headers()));
         let _enter = span.enter();
 
+        let _timer = self.metrics.start_request("ReadLatestCharacterRevision");
+
+        let _call = match self.drain.begin() {
+            Ok(call) => call,
+            Err(status) => {
+                self.metrics.record_error(
+                    "ReadLatestCharacterRevision",
+                    db::ErrorCode::Unavailable,
+                );
+                sink.fail(status);
+                return;
+            }
+        };
+
+        if let Err(err) = self.limiter.check(&self.user, rate_limit::Rpc::Read)
+        {
+            self.fail("ReadLatestCharacterRevision", sink, err);
+            return;
+        }
+
         // TODO: for the hosted service we need to add authn/authz so characters are
         // gated to users.
         // It doesn't serve much benefit to add right now as it would only be
@@ -194,53 +302,169 @@ impl characters_grpc::Characters for CharacterService {
         match self
             .db
             .clone()
-            .read_latest_revision(&req.uuid, "test_user".to_owned())
+            .read_latest_revision(&req.uuid, self.user.clone())
         {
             Ok(revision) => sink.success(revision),
-            Err(err) => sink.fail(err.into()),
+            Err(err) => self.fail("ReadLatestCharacterRevision", sink, err),
         };
     }
 }
 
-fn new_file_store() -> Arc<db::FileStore> {
-    Arc::new(db::FileStore::new("./testdata/".to_owned().into()).unwrap())
+// new_store opens the configured store, existing characters still need to be
+// loaded from storage.
+fn new_store(
+    options: &Options,
+    metrics: Arc<metrics::Metrics>,
+) -> Arc<db::FileStore> {
+    match options.store_backend {
+        StoreBackend::File => Arc::new(db::FileStore::open(
+            options.store_root.to_owned().into(),
+            metrics,
+            db::Quotas {
+                max_characters: options.max_characters_per_owner,
+                max_stored_bytes: options.max_stored_bytes_per_owner,
+            },
+        )),
+    }
+}
+
+fn server_credentials(options: &Options) -> ServerCredentials {
+    match tls::TlsConfig::from_options(options) {
+        Some(config) => config
+            .server_credentials()
+            .expect("failed to load TLS certificates"),
+        None => ServerCredentials::insecure(),
+    }
+}
+
+// migrate upgrades all revisions of the configured store, exiting on failure.
+fn migrate(options: &Options) {
+    let migrated = match options.store_backend {
+        StoreBackend::File => {
+            db::FileStore::migrate(options.store_root.as_ref())
+        }
+    };
+    match migrated {
+        Ok(migration) => info!(
+            characters = migration.characters,
+            revisions = migration.revisions,
+            "migrated store to schema version {}",
+            db::SCHEMA_VERSION
+        ),
+        Err(err) => {
+            error!(err = %err, "failed to migrate store");
+            std::process::exit(1);
+        }
+    }
+}
+
+#[derive(Parser)]
+struct Command {
+    #[command(flatten)]
+    options: Options,
+
+    #[command(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Rewrite all stored revisions with the latest schema version and exit.
+    /// The service must not be running on the same store.
+    Migrate,
 }
 
 fn main() {
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(Level::TRACE)
-        .finish();
-    tracing::subscriber::set_global_default(subscriber)
-        .expect("setting default subscriber failed");
+    dotenv().ok();
 
-    let db = new_file_store();
+    let Command { options, command } = Command::parse();
 
-    let service = create_characters(CharacterService { db });
+    let telemetry = telemetry::init(&options);
 
-    let env = Arc::new(Environment::new(1));
-    let addr = "127.0.0.1:8080";
+    if let Some(Commands::Migrate) = command {
+        migrate(&options);
+        if let Some(telemetry) = telemetry {
+            telemetry.shutdown();
+        }
+        return;
+    }
+
+    let metrics =
+        Arc::new(metrics::Metrics::new().expect("failed to create metrics"));
+    metrics::serve(metrics.clone(), &options.metrics_address)
+        .expect("failed to serve metrics");
+
+    let db = new_store(&options, metrics.clone());
+    let drain = Arc::new(shutdown::Drain::new());
+
+    let characters = CharacterService {
+        db: db.clone(),
+        drain: drain.clone(),
+        metrics,
+        limiter: Arc::new(rate_limit::RateLimiter::from_options(&options)),
+        user: options.anonymous_user.clone(),
+    };
+    let gateway = options.gateway_address.as_ref().map(|address| {
+        let cors = gateway::cors(options.cors_allowed_origins.as_deref())
+            .expect("invalid CORS origins");
+        gateway::Gateway::start(characters.clone(), address, cors)
+            .expect("failed to start gateway")
+    });
+    let service = create_characters(characters);
+
+    let health = HealthService::default();
+    let health_reporter =
+        Arc::new(health::HealthReporter::new(health.clone(), db.clone()));
+    health_reporter.check();
+
+    let reflection = proto_rs::reflection::create_server_reflection(
+        reflection::ReflectionService::characters(),
+    );
+
+    let env = Arc::new(Environment::new(options.threads));
+    let addr = &options.listen_address;
     let quota = ResourceQuota::new(Some("CharacterServiceQuota"))
-        .resize_memory(1024 * 1024);
+        .resize_memory(options.resource_quota);
     let ch_builder = ChannelBuilder::new(env.clone()).set_resource_quota(quota);
 
     let mut server = ServerBuilder::new(env)
         .register_service(service)
+        .register_service(create_health(health))
+        .register_service(reflection)
         .channel_args(ch_builder.build_args())
         .build()
         .unwrap();
     server
-        .add_listening_port(addr, ServerCredentials::insecure())
+        .add_listening_port(addr, server_credentials(&options))
         .unwrap();
     server.start();
     info!("listening on {addr}");
-    let (tx, rx) = oneshot::channel();
-    thread::spawn(move || {
-        info!("Press ENTER to exit...");
-        let _ = io::stdin().read(&mut [0]).unwrap();
-        tx.send(())
-    });
-    let _ = block_on(rx);
+
+    // The health service reports NOT_SERVING until all characters are loaded.
+    if let Err(err) = db.load_from_storage() {
+        error!(err = %err, "failed to load characters from storage");
+    }
+    health_reporter
+        .clone()
+        .watch(Duration::from_secs(options.health_check_interval));
+
+    shutdown::wait_for_signal();
+
+    info!("shutting down");
+    health_reporter.shutdown();
+    if !drain.drain(Duration::from_secs(options.shutdown_timeout)) {
+        warn!("timed out waiting for in-flight calls to finish");
+    }
+    if let Some(gateway) = gateway {
+        gateway.shutdown();
+    }
+    if let Err(err) = db.flush() {
+        error!(err = %err, "failed to flush pending writes");
+    }
     let _ = block_on(server.shutdown());
+    if let Some(telemetry) = telemetry {
+        telemetry.shutdown();
+    }
 }
 
 #[cfg(test)]
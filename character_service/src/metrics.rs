@@ -0,0 +1,229 @@
+use std::{
+    sync::Arc,
+    thread::{self, JoinHandle},
+    time::Instant,
+};
+
+use prometheus::{
+    exponential_buckets, Encoder, HistogramOpts, HistogramTimer, HistogramVec,
+    IntCounter, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+use tiny_http::{Header, Response, Server};
+use tracing::{error, info};
+
+use crate::db::ErrorCode;
+
+const NAMESPACE: &str = "character_service";
+
+// Metrics collects everything exposed on the metrics endpoint.
+pub struct Metrics {
+    registry: Registry,
+
+    requests: IntCounterVec,
+    errors: IntCounterVec,
+    latency: HistogramVec,
+
+    pub characters: IntGauge,
+    pub revisions_written: IntCounter,
+    pub stored_bytes: IntGauge,
+    lock_wait: HistogramVec,
+}
+
+// The locks FileStore waits on.
+#[derive(Debug, Clone, Copy)]
+pub enum Lock {
+    // The RwLock guarding all characters.
+    Characters,
+    // The Mutex guarding a single character.
+    Character,
+}
+
+impl Lock {
+    fn label(&self) -> &'static str {
+        match self {
+            Lock::Characters => "characters",
+            Lock::Character => "character",
+        }
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Result<Metrics, prometheus::Error> {
+        let registry = Registry::new();
+
+        let requests = IntCounterVec::new(
+            Opts::new("requests_total", "Number of received requests.")
+                .namespace(NAMESPACE),
+            &["method"],
+        )?;
+        let errors = IntCounterVec::new(
+            Opts::new("errors_total", "Number of failed requests.")
+                .namespace(NAMESPACE),
+            &["method", "code"],
+        )?;
+        let latency = HistogramVec::new(
+            HistogramOpts::new(
+                "request_duration_seconds",
+                "Time spent handling requests.",
+            )
+            .namespace(NAMESPACE)
+            .buckets(exponential_buckets(0.0005, 2.0, 14)?),
+            &["method"],
+        )?;
+        let characters = IntGauge::with_opts(
+            Opts::new("characters", "Number of loaded characters.")
+                .namespace(NAMESPACE),
+        )?;
+        let revisions_written = IntCounter::with_opts(
+            Opts::new(
+                "revisions_written_total",
+                "Number of written character revisions.",
+            )
+            .namespace(NAMESPACE),
+        )?;
+        let stored_bytes = IntGauge::with_opts(
+            Opts::new("stored_bytes", "Size of all stored revisions.")
+                .namespace(NAMESPACE),
+        )?;
+        let lock_wait = HistogramVec::new(
+            HistogramOpts::new(
+                "lock_wait_seconds",
+                "Time spent waiting to acquire store locks.",
+            )
+            .namespace(NAMESPACE)
+            .buckets(exponential_buckets(0.00001, 4.0, 10)?),
+            &["lock"],
+        )?;
+
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(errors.clone()))?;
+        registry.register(Box::new(latency.clone()))?;
+        registry.register(Box::new(characters.clone()))?;
+        registry.register(Box::new(revisions_written.clone()))?;
+        registry.register(Box::new(stored_bytes.clone()))?;
+        registry.register(Box::new(lock_wait.clone()))?;
+
+        Ok(Metrics {
+            registry,
+            requests,
+            errors,
+            latency,
+            characters,
+            revisions_written,
+            stored_bytes,
+            lock_wait,
+        })
+    }
+
+    // start_request counts a request for method, its latency is recorded when
+    // the returned timer is dropped.
+    pub fn start_request(&self, method: &str) -> HistogramTimer {
+        self.requests.with_label_values(&[method]).inc();
+        self.latency.with_label_values(&[method]).start_timer()
+    }
+
+    pub fn record_error(&self, method: &str, code: ErrorCode) {
+        self.errors
+            .with_label_values(&[method, &format!("{code:?}")])
+            .inc();
+    }
+
+    pub fn record_lock_wait(&self, lock: Lock, started: Instant) {
+        self.lock_wait
+            .with_label_values(&[lock.label()])
+            .observe(started.elapsed().as_secs_f64());
+    }
+
+    // render encodes all metrics in the Prometheus text format.
+    pub fn render(&self) -> Result<Vec<u8>, prometheus::Error> {
+        let mut buffer = vec![];
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+// serve the metrics on address under /metrics.
+pub fn serve(
+    metrics: Arc<Metrics>,
+    address: &str,
+) -> Result<JoinHandle<()>, Box<dyn std::error::Error + Send + Sync>> {
+    let server = Server::http(address)?;
+    info!("serving metrics on {address}");
+
+    Ok(thread::spawn(move || {
+        for request in server.incoming_requests() {
+            if request.url() != "/metrics" {
+                let _ = request.respond(Response::empty(404));
+                continue;
+            }
+
+            let response = match metrics.render() {
+                Ok(body) => Response::from_data(body).with_header(
+                    Header::from_bytes(
+                        "Content-Type",
+                        TextEncoder::new().format_type(),
+                    )
+                    .unwrap(),
+                ),
+                Err(err) => {
+                    error!(err = %err, "failed to render metrics");
+                    Response::from_data(vec![]).with_status_code(500)
+                }
+            };
+            if let Err(err) = request.respond(response) {
+                error!(err = %err, "failed to send metrics");
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Instant;
+
+    use rstest::rstest;
+
+    use super::{Lock, Metrics};
+    use crate::db::ErrorCode;
+
+    fn render(metrics: &Metrics) -> String {
+        String::from_utf8(metrics.render().expect("should render metrics"))
+            .unwrap()
+    }
+
+    #[rstest]
+    fn records_requests() {
+        let metrics = Metrics::new().unwrap();
+
+        metrics.start_request("Create").observe_duration();
+        metrics.start_request("Create").observe_duration();
+        metrics.record_error("Create", ErrorCode::Unauthorized);
+
+        let rendered = render(&metrics);
+        assert!(rendered.contains(
+            r#"character_service_requests_total{method="Create"} 2"#
+        ));
+        assert!(rendered.contains(
+            r#"character_service_errors_total{code="Unauthorized",method="Create"} 1"#
+        ));
+        assert!(rendered.contains(
+            r#"character_service_request_duration_seconds_count{method="Create"} 2"#
+        ));
+    }
+
+    #[rstest]
+    fn records_lock_wait() {
+        let metrics = Metrics::new().unwrap();
+
+        metrics.record_lock_wait(Lock::Characters, Instant::now());
+        metrics.record_lock_wait(Lock::Character, Instant::now());
+
+        let rendered = render(&metrics);
+        assert!(rendered.contains(
+            r#"character_service_lock_wait_seconds_count{lock="characters"} 1"#
+        ));
+        assert!(rendered.contains(
+            r#"character_service_lock_wait_seconds_count{lock="character"} 1"#
+        ));
+    }
+}
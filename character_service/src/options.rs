@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+use clap::{Args, ValueEnum};
+use env_arg::EnvArgs;
+use env_arg_derive::EnvArgs;
+use tracing::Level;
+
+#[derive(Clone, Debug, PartialEq, Eq, Args, EnvArgs)]
+pub struct Options {
+    /// Address the gRPC server listens on.
+    #[arg(long, env, default_value = "127.0.0.1:8080")]
+    pub listen_address: String,
+
+    /// Address the Prometheus metrics are served on under /metrics.
+    #[arg(long, env, default_value = "127.0.0.1:9090")]
+    pub metrics_address: String,
+
+    /// Address the REST/JSON gateway listens on, the gateway is disabled if
+    /// unset.
+    #[arg(long, env)]
+    pub gateway_address: Option<String>,
+
+    /// Comma separated origins browsers may call the gateway from, * allows
+    /// any origin.
+    #[arg(long, env)]
+    pub cors_allowed_origins: Option<String>,
+
+    /// Backend used to persist characters.
+    #[arg(value_enum, long, env, default_value_t = StoreBackend::File)]
+    pub store_backend: StoreBackend,
+
+    /// Root directory of the file store.
+    #[arg(long, env, default_value = "./testdata/")]
+    pub store_root: String,
+
+    /// Memory quota for the gRPC server in bytes.
+    #[arg(long, env, default_value_t = 1024 * 1024)]
+    pub resource_quota: usize,
+
+    /// Number of completion queues (and polling threads) of the gRPC server.
+    #[arg(long, env, default_value_t = 1)]
+    pub threads: usize,
+
+    /// Seconds to wait for in-flight calls to finish when shutting down.
+    #[arg(long, env, default_value_t = 30)]
+    pub shutdown_timeout: u64,
+
+    /// Seconds between store health checks reported by the health service.
+    #[arg(long, env, default_value_t = 10)]
+    pub health_check_interval: u64,
+
+    /// Most verbose level logged to stdout, exported spans aren't filtered.
+    #[arg(long, env, default_value_t = Level::TRACE)]
+    pub log_level: Level,
+
+    /// OTLP/HTTP collector endpoint spans are exported to, e.g.
+    /// http://127.0.0.1:4318. Spans are not exported if unset.
+    #[arg(long, env)]
+    pub otlp_endpoint: Option<String>,
+
+    /// PEM encoded certificate chain, enables TLS together with tls_key.
+    #[arg(long, env, requires = "tls_key")]
+    pub tls_cert: Option<String>,
+
+    /// PEM encoded private key for tls_cert.
+    #[arg(long, env, requires = "tls_cert")]
+    pub tls_key: Option<String>,
+
+    /// PEM encoded CA certificates, enables mutual TLS requiring clients to
+    /// present a certificate signed by one of them.
+    #[arg(long, env, requires = "tls_cert")]
+    pub tls_client_ca: Option<String>,
+
+    /// Character creations allowed per user and minute, 0 disables the limit.
+    #[arg(long, env, default_value_t = 10)]
+    pub create_rate_limit: u32,
+
+    /// Revision writes allowed per user and minute, 0 disables the limit.
+    #[arg(long, env, default_value_t = 120)]
+    pub write_rate_limit: u32,
+
+    /// Revision reads allowed per user and minute, 0 disables the limit.
+    #[arg(long, env, default_value_t = 600)]
+    pub read_rate_limit: u32,
+
+    /// Characters a user can own, 0 disables the quota.
+    #[arg(long, env, default_value_t = 100)]
+    pub max_characters_per_owner: usize,
+
+    /// Bytes of revisions a user can store, 0 disables the quota.
+    #[arg(long, env, default_value_t = 100 * 1024 * 1024)]
+    pub max_stored_bytes_per_owner: u64,
+
+    /// User every request is attributed to.
+    // TODO: replace with the authenticated user once authn is implemented.
+    #[arg(long, env, default_value = "test_user")]
+    pub anonymous_user: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StoreBackend {
+    File,
+}
+
+impl Display for StoreBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            StoreBackend::File => "file",
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use clap::Parser;
+    use env_arg::EnvArgs;
+    use rstest::rstest;
+
+    use super::Options;
+
+    #[derive(Parser)]
+    struct Command {
+        #[command(flatten)]
+        options: Options,
+    }
+
+    #[rstest]
+    fn env_pairs_roundtrip() {
+        let cmd = Command::parse_from([
+            "character_service",
+            "--listen-address=0.0.0.0:8080",
+            "--store-root=/data",
+            "--log-level=info",
+        ]);
+
+        let pairs = cmd.options.get_env_pairs();
+        assert_eq!(
+            pairs,
+            BTreeMap::from([
+                ("ANONYMOUS_USER".to_owned(), "test_user".to_owned()),
+                ("CREATE_RATE_LIMIT".to_owned(), "10".to_owned()),
+                ("HEALTH_CHECK_INTERVAL".to_owned(), "10".to_owned()),
+                ("LISTEN_ADDRESS".to_owned(), "0.0.0.0:8080".to_owned()),
+                ("LOG_LEVEL".to_owned(), "INFO".to_owned()),
+                ("MAX_CHARACTERS_PER_OWNER".to_owned(), "100".to_owned()),
+                (
+                    "MAX_STORED_BYTES_PER_OWNER".to_owned(),
+                    "104857600".to_owned()
+                ),
+                ("METRICS_ADDRESS".to_owned(), "127.0.0.1:9090".to_owned()),
+                ("READ_RATE_LIMIT".to_owned(), "600".to_owned()),
+                ("RESOURCE_QUOTA".to_owned(), "1048576".to_owned()),
+                ("SHUTDOWN_TIMEOUT".to_owned(), "30".to_owned()),
+                ("STORE_BACKEND".to_owned(), "file".to_owned()),
+                ("STORE_ROOT".to_owned(), "/data".to_owned()),
+                ("THREADS".to_owned(), "1".to_owned()),
+                ("WRITE_RATE_LIMIT".to_owned(), "120".to_owned()),
+            ])
+        );
+    }
+}
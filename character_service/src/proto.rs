@@ -0,0 +1,4 @@
+// storage holds the messages the file store persists, generated by build.rs.
+pub mod storage {
+    include!(concat!(env!("OUT_DIR"), "/storage.rs"));
+}
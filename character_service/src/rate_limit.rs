@@ -0,0 +1,166 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use character_service::Options;
+
+use crate::db::{Error, ErrorCode};
+
+// Rpc groups the calls sharing a rate limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rpc {
+    Create,
+    Write,
+    Read,
+}
+
+// RateLimiter keeps a token bucket per user and Rpc. Each bucket holds up to
+// a minute worth of calls and refills continuously.
+pub struct RateLimiter {
+    // Calls per minute, Rpcs without a limit are not rate limited.
+    limits: HashMap<Rpc, u32>,
+    buckets: Mutex<HashMap<(String, Rpc), Bucket>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    // new RateLimiter allowing the given calls per minute, a limit of 0
+    // disables rate limiting for the Rpc.
+    pub fn new(limits: impl IntoIterator<Item = (Rpc, u32)>) -> RateLimiter {
+        RateLimiter {
+            limits: limits
+                .into_iter()
+                .filter(|(_, limit)| *limit > 0)
+                .collect(),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn from_options(options: &Options) -> RateLimiter {
+        RateLimiter::new([
+            (Rpc::Create, options.create_rate_limit),
+            (Rpc::Write, options.write_rate_limit),
+            (Rpc::Read, options.read_rate_limit),
+        ])
+    }
+
+    // check takes a token from the user's bucket for rpc, failing with
+    // ResourceExhausted if the bucket is empty.
+    pub fn check(&self, user: &str, rpc: Rpc) -> Result<(), Error> {
+        self.check_at(user, rpc, Instant::now())
+    }
+
+    fn check_at(
+        &self,
+        user: &str,
+        rpc: Rpc,
+        now: Instant,
+    ) -> Result<(), Error> {
+        let limit = match self.limits.get(&rpc) {
+            Some(limit) => f64::from(*limit),
+            None => return Ok(()),
+        };
+
+        // unwrapping the lock() here to panic on a poisoned lock
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket =
+            buckets
+                .entry((user.to_owned(), rpc))
+                .or_insert_with(|| Bucket {
+                    tokens: limit,
+                    updated: now,
+                });
+
+        let elapsed = now.saturating_duration_since(bucket.updated);
+        bucket.tokens = (bucket.tokens
+            + limit * elapsed.as_secs_f64() / MINUTE.as_secs_f64())
+        .min(limit);
+        bucket.updated = now;
+
+        if bucket.tokens < 1.0 {
+            return Err(Error::new(
+                ErrorCode::ResourceExhausted,
+                &format!(
+                    "rate limit of {limit} {rpc:?} calls per minute exceeded"
+                ),
+            ));
+        }
+        bucket.tokens -= 1.0;
+
+        Ok(())
+    }
+}
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use rstest::rstest;
+
+    use super::{RateLimiter, Rpc};
+    use crate::db::ErrorCode;
+
+    #[rstest]
+    fn rejects_calls_over_limit() {
+        let limiter = RateLimiter::new([(Rpc::Write, 2)]);
+        let now = Instant::now();
+
+        assert!(limiter.check_at("user", Rpc::Write, now).is_ok());
+        assert!(limiter.check_at("user", Rpc::Write, now).is_ok());
+        assert_eq!(
+            limiter
+                .check_at("user", Rpc::Write, now)
+                .expect_err("should reject third call")
+                .code(),
+            ErrorCode::ResourceExhausted
+        );
+    }
+
+    #[rstest]
+    fn refills_over_time() {
+        let limiter = RateLimiter::new([(Rpc::Write, 2)]);
+        let now = Instant::now();
+
+        assert!(limiter.check_at("user", Rpc::Write, now).is_ok());
+        assert!(limiter.check_at("user", Rpc::Write, now).is_ok());
+        assert!(limiter.check_at("user", Rpc::Write, now).is_err());
+
+        let later = now + Duration::from_secs(30);
+        assert!(
+            limiter.check_at("user", Rpc::Write, later).is_ok(),
+            "should refill a token after half a minute"
+        );
+        assert!(limiter.check_at("user", Rpc::Write, later).is_err());
+    }
+
+    #[rstest]
+    fn limits_users_and_rpcs_separately() {
+        let limiter = RateLimiter::new([(Rpc::Write, 1), (Rpc::Read, 1)]);
+        let now = Instant::now();
+
+        assert!(limiter.check_at("user", Rpc::Write, now).is_ok());
+        assert!(limiter.check_at("user", Rpc::Write, now).is_err());
+        assert!(limiter.check_at("other", Rpc::Write, now).is_ok());
+        assert!(limiter.check_at("user", Rpc::Read, now).is_ok());
+    }
+
+    #[rstest]
+    #[case(Rpc::Create)]
+    #[case(Rpc::Read)]
+    fn zero_disables_limit(#[case] rpc: Rpc) {
+        let limiter = RateLimiter::new([(Rpc::Create, 0)]);
+        let now = Instant::now();
+
+        for _ in 0..100 {
+            assert!(limiter.check_at("user", rpc, now).is_ok());
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use std::sync::Arc;
+
+use futures::{FutureExt, SinkExt, StreamExt, TryFutureExt};
+use grpcio::{
+    DuplexSink, RequestStream, RpcContext, RpcStatusCode, WriteFlags,
+};
+use prost::Message;
+use prost_types::{DescriptorProto, FileDescriptorProto, FileDescriptorSet};
+use proto_rs::reflection::{
+    server_reflection_request::MessageRequest as Request,
+    server_reflection_response::MessageResponse as Response, ErrorResponse,
+    FileDescriptorResponse, ListServiceResponse, ServerReflection,
+    ServerReflectionRequest, ServerReflectionResponse, ServiceResponse,
+};
+use tracing::{error, trace};
+
+// ReflectionService implements the gRPC server reflection protocol for the
+// registered services, allowing tools like grpcurl to discover them.
+#[derive(Clone)]
+pub struct ReflectionService {
+    files: Arc<Vec<FileDescriptorProto>>,
+    services: Arc<Vec<String>>,
+}
+
+impl ReflectionService {
+    // new ReflectionService exposing the services defined in the given files.
+    // The files need to include all of their dependencies.
+    pub fn new(files: Vec<FileDescriptorProto>) -> ReflectionService {
+        let services = files
+            .iter()
+            .flat_map(|file| {
+                file.service.iter().map(|service| {
+                    qualified_name(file.package(), service.name())
+                })
+            })
+            .collect();
+
+        ReflectionService {
+            files: Arc::new(files),
+            services: Arc::new(services),
+        }
+    }
+
+    // characters returns a ReflectionService for the Characters service.
+    pub fn characters() -> ReflectionService {
+        const FILES: &[&str] = &[
+            "character.proto",
+            "characters.proto",
+            "grpc/reflection/v1alpha/reflection.proto",
+        ];
+
+        let set = FileDescriptorSet::decode(proto_rs::FILE_DESCRIPTOR_SET)
+            .expect("proto_rs should embed a valid descriptor set");
+        ReflectionService::new(
+            set.file
+                .into_iter()
+                .filter(|file| FILES.contains(&file.name()))
+                .collect(),
+        )
+    }
+
+    fn respond(
+        &self,
+        req: ServerReflectionRequest,
+    ) -> ServerReflectionResponse {
+        let message_response = match &req.message_request {
+            Some(Request::ListServices(_)) => self.list_services(),
+            Some(Request::FileByFilename(name)) => self.file_by_filename(name),
+            Some(Request::FileContainingSymbol(symbol)) => {
+                self.file_containing_symbol(symbol)
+            }
+            Some(Request::FileContainingExtension(_))
+            | Some(Request::AllExtensionNumbersOfType(_)) => error_response(
+                RpcStatusCode::UNIMPLEMENTED,
+                "extensions are not supported",
+            ),
+            None => error_response(
+                RpcStatusCode::INVALID_ARGUMENT,
+                "missing message request",
+            ),
+        };
+
+        ServerReflectionResponse {
+            valid_host: req.host.clone(),
+            original_request: Some(req),
+            message_response: Some(message_response),
+        }
+    }
+
+    fn list_services(&self) -> Response {
+        Response::ListServicesResponse(ListServiceResponse {
+            service: self
+                .services
+                .iter()
+                .map(|name| ServiceResponse { name: name.clone() })
+                .collect(),
+        })
+    }
+
+    fn file_by_filename(&self, name: &str) -> Response {
+        match self.files.iter().find(|file| file.name() == name) {
+            Some(file) => self.file_descriptor_response(file),
+            None => error_response(RpcStatusCode::NOT_FOUND, "file not found"),
+        }
+    }
+
+    fn file_containing_symbol(&self, symbol: &str) -> Response {
+        match self.files.iter().find(|file| contains_symbol(file, symbol)) {
+            Some(file) => self.file_descriptor_response(file),
+            None => {
+                error_response(RpcStatusCode::NOT_FOUND, "symbol not found")
+            }
+        }
+    }
+
+    // file_descriptor_response returns the file and all of its dependencies.
+    fn file_descriptor_response(&self, file: &FileDescriptorProto) -> Response {
+        let mut names = vec![file.name().to_owned()];
+        let mut index = 0;
+        while index < names.len() {
+            if let Some(file) =
+                self.files.iter().find(|file| file.name() == names[index])
+            {
+                for dependency in &file.dependency {
+                    if !names.contains(dependency) {
+                        names.push(dependency.clone());
+                    }
+                }
+            }
+            index += 1;
+        }
+
+        let file_descriptor_proto = names
+            .iter()
+            .filter_map(|name| {
+                self.files.iter().find(|file| file.name() == name)
+            })
+            .map(|file| file.encode_to_vec())
+            .collect();
+
+        Response::FileDescriptorResponse(FileDescriptorResponse {
+            file_descriptor_proto,
+        })
+    }
+}
+
+impl ServerReflection for ReflectionService {
+    fn server_reflection_info(
+        &mut self,
+        ctx: RpcContext,
+        stream: RequestStream<ServerReflectionRequest>,
+        mut sink: DuplexSink<ServerReflectionResponse>,
+    ) {
+        trace!("received server reflection request");
+
+        let service = self.clone();
+        let mut responses = stream.map(move |req| {
+            req.map(|req| (service.respond(req), WriteFlags::default()))
+        });
+
+        let f = async move {
+            sink.send_all(&mut responses).await?;
+            sink.close().await?;
+            Ok(())
+        }
+        .map_err(|err: grpcio::Error| {
+            error!(err = %err, "failed to serve server reflection")
+        })
+        .map(|_| ());
+
+        ctx.spawn(f)
+    }
+}
+
+fn error_response(code: RpcStatusCode, message: &str) -> Response {
+    Response::ErrorResponse(ErrorResponse {
+        error_code: code.into(),
+        error_message: message.to_owned(),
+    })
+}
+
+fn qualified_name(package: &str, name: &str) -> String {
+    match package.is_empty() {
+        true => name.to_owned(),
+        false => format!("{package}.{name}"),
+    }
+}
+
+// contains_symbol checks if the fully qualified symbol is a message, enum,
+// service or method defined in file.
+fn contains_symbol(file: &FileDescriptorProto, symbol: &str) -> bool {
+    let package = file.package();
+
+    let services = file.service.iter().any(|service| {
+        let name = qualified_name(package, service.name());
+        name == symbol
+            || service
+                .method
+                .iter()
+                .any(|method| qualified_name(&name, method.name()) == symbol)
+    });
+
+    let enums = file
+        .enum_type
+        .iter()
+        .any(|enum_type| qualified_name(package, enum_type.name()) == symbol);
+
+    services
+        || enums
+        || file
+            .message_type
+            .iter()
+            .any(|message| message_contains_symbol(package, message, symbol))
+}
+
+fn message_contains_symbol(
+    scope: &str,
+    message: &DescriptorProto,
+    symbol: &str,
+) -> bool {
+    let name = qualified_name(scope, message.name());
+    if name == symbol {
+        return true;
+    }
+
+    message
+        .enum_type
+        .iter()
+        .any(|enum_type| qualified_name(&name, enum_type.name()) == symbol)
+        || message
+            .nested_type
+            .iter()
+            .any(|nested| message_contains_symbol(&name, nested, symbol))
+}
+
+#[cfg(test)]
+mod test {
+    use grpcio::RpcStatusCode;
+    use prost::Message;
+    use prost_types::FileDescriptorProto;
+    use proto_rs::reflection::{
+        server_reflection_request::MessageRequest as Request,
+        server_reflection_response::MessageResponse as Response,
+        ServerReflectionRequest,
+    };
+    use rstest::rstest;
+
+    use super::ReflectionService;
+
+    fn respond(request: Request) -> Response {
+        ReflectionService::characters()
+            .respond(ServerReflectionRequest {
+                message_request: Some(request),
+                ..Default::default()
+            })
+            .message_response
+            .expect("should set a response")
+    }
+
+    fn file_names(response: Response) -> Vec<String> {
+        match response {
+            Response::FileDescriptorResponse(response) => response
+                .file_descriptor_proto
+                .iter()
+                .map(|bytes| {
+                    FileDescriptorProto::decode(bytes.as_slice())
+                        .expect("should decode file descriptor")
+                        .name()
+                        .to_owned()
+                })
+                .collect(),
+            response => panic!("unexpected response {response:?}"),
+        }
+    }
+
+    #[rstest]
+    fn lists_services() {
+        let services = match respond(Request::ListServices("*".to_owned())) {
+            Response::ListServicesResponse(response) => response
+                .service
+                .iter()
+                .map(|service| service.name.clone())
+                .collect::<Vec<String>>(),
+            response => panic!("unexpected response {response:?}"),
+        };
+
+        assert_eq!(
+            services,
+            vec![
+                "characters.Characters",
+                "grpc.reflection.v1alpha.ServerReflection"
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("characters.Characters")]
+    #[case("characters.Characters.WriteCharacterRevision")]
+    #[case("characters.WriteRevision")]
+    fn file_containing_symbol_includes_dependencies(#[case] symbol: &str) {
+        let response =
+            respond(Request::FileContainingSymbol(symbol.to_owned()));
+
+        assert_eq!(
+            file_names(response),
+            vec!["characters.proto", "character.proto"]
+        );
+    }
+
+    #[rstest]
+    fn file_containing_nested_symbol() {
+        let response = respond(Request::FileContainingSymbol(
+            "character.PoolType".to_owned(),
+        ));
+
+        assert_eq!(file_names(response), vec!["character.proto"]);
+    }
+
+    #[rstest]
+    fn file_by_filename() {
+        let response =
+            respond(Request::FileByFilename("character.proto".to_owned()));
+
+        assert_eq!(file_names(response), vec!["character.proto"]);
+    }
+
+    #[rstest]
+    #[case(Request::FileContainingSymbol("characters.Unknown".to_owned()), RpcStatusCode::NOT_FOUND)]
+    #[case(Request::FileByFilename("unknown.proto".to_owned()), RpcStatusCode::NOT_FOUND)]
+    #[case(Request::AllExtensionNumbersOfType("characters.WriteRevision".to_owned()), RpcStatusCode::UNIMPLEMENTED)]
+    fn returns_errors(#[case] request: Request, #[case] code: RpcStatusCode) {
+        match respond(request) {
+            Response::ErrorResponse(response) => {
+                assert_eq!(response.error_code, i32::from(code))
+            }
+            response => panic!("unexpected response {response:?}"),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    sync::{Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+use grpcio::{RpcStatus, RpcStatusCode};
+use tokio::signal::unix::{signal, SignalKind};
+use tracing::info;
+
+// Drain keeps track of in-flight calls so they can finish before the server
+// shuts down. Once draining started new calls are rejected.
+#[derive(Default)]
+pub struct Drain {
+    state: Mutex<DrainState>,
+    idle: Condvar,
+}
+
+#[derive(Default)]
+struct DrainState {
+    draining: bool,
+    in_flight: usize,
+}
+
+// Call marks a call as in-flight until it is dropped.
+pub struct Call<'a> {
+    drain: &'a Drain,
+}
+
+impl Drain {
+    pub fn new() -> Drain {
+        Default::default()
+    }
+
+    // begin registers a new in-flight call, unless the server is draining.
+    pub fn begin(&self) -> Result<Call<'_>, RpcStatus> {
+        // unwrapping the lock() here to panic on a poisoned lock
+        let mut state = self.state.lock().unwrap();
+        if state.draining {
+            return Err(RpcStatus::with_message(
+                RpcStatusCode::UNAVAILABLE,
+                "server is shutting down".to_owned(),
+            ));
+        }
+        state.in_flight += 1;
+
+        Ok(Call { drain: self })
+    }
+
+    // drain stops accepting new calls and waits for in-flight calls to finish.
+    // Returns false if calls were still in-flight after the timeout.
+    pub fn drain(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+
+        let mut state = self.state.lock().unwrap();
+        state.draining = true;
+
+        while state.in_flight > 0 {
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            state = self.idle.wait_timeout(state, deadline - now).unwrap().0;
+        }
+
+        true
+    }
+
+    fn end(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.in_flight -= 1;
+        if state.in_flight == 0 {
+            self.idle.notify_all();
+        }
+    }
+}
+
+impl Drop for Call<'_> {
+    fn drop(&mut self) {
+        self.drain.end();
+    }
+}
+
+// wait_for_signal blocks until the process receives SIGTERM or SIGINT.
+pub fn wait_for_signal() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("failed to create signal runtime");
+
+    runtime.block_on(async {
+        let mut terminate = signal(SignalKind::terminate())
+            .expect("failed to register SIGTERM handler");
+        let mut interrupt = signal(SignalKind::interrupt())
+            .expect("failed to register SIGINT handler");
+
+        tokio::select! {
+            _ = terminate.recv() => info!("received SIGTERM"),
+            _ = interrupt.recv() => info!("received SIGINT"),
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, thread, time::Duration};
+
+    use grpcio::RpcStatusCode;
+    use rstest::rstest;
+
+    use super::Drain;
+
+    #[rstest]
+    fn drain_without_calls() {
+        let drain = Drain::new();
+        assert!(drain.drain(Duration::from_millis(10)));
+    }
+
+    #[rstest]
+    fn drain_rejects_new_calls() {
+        let drain = Drain::new();
+        assert!(drain.drain(Duration::from_millis(10)));
+
+        assert_eq!(
+            drain
+                .begin()
+                .err()
+                .expect("should reject call while draining")
+                .code(),
+            RpcStatusCode::UNAVAILABLE
+        );
+    }
+
+    #[rstest]
+    fn drain_times_out_on_in_flight_calls() {
+        let drain = Drain::new();
+        let _call = drain.begin().expect("should accept call");
+
+        assert!(!drain.drain(Duration::from_millis(10)));
+    }
+
+    #[rstest]
+    fn drain_waits_for_in_flight_calls() {
+        let drain = Arc::new(Drain::new());
+
+        let (started, start) = std::sync::mpsc::channel();
+        let handle = {
+            let drain = drain.clone();
+            thread::spawn(move || {
+                let _call = drain.begin().expect("should accept call");
+                started.send(()).unwrap();
+                thread::sleep(Duration::from_millis(50));
+            })
+        };
+
+        start.recv().unwrap();
+        assert!(drain.drain(Duration::from_secs(10)));
+        handle.join().unwrap();
+    }
+}
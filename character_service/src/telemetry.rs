@@ -0,0 +1,233 @@
+use std::{collections::HashMap, error::Error, time::Duration};
+
+use character_service::Options;
+use opentelemetry::{
+    propagation::TextMapPropagator, trace::TracerProvider as _, Context,
+    KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    propagation::TraceContextPropagator,
+    runtime,
+    trace::{self as sdktrace, Tracer, TracerProvider},
+    Resource,
+};
+use tokio::runtime::Runtime;
+use tracing::{warn, Level, Subscriber};
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::{
+    filter::LevelFilter,
+    layer::{Layer, SubscriberExt},
+    registry::LookupSpan,
+};
+
+const SERVICE_NAME: &str = "character_service";
+
+// Telemetry exports spans to an OTLP collector. Spans are exported in batches
+// and flushed on shutdown.
+pub struct Telemetry {
+    provider: TracerProvider,
+    // The batch exporter and its HTTP client run on this runtime.
+    runtime: Runtime,
+}
+
+impl Telemetry {
+    // new Telemetry exporting spans over OTLP/HTTP to endpoint, e.g.
+    // http://127.0.0.1:4318.
+    pub fn new(endpoint: &str) -> Result<Telemetry, Box<dyn Error>> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("telemetry")
+            .enable_all()
+            .build()?;
+        // The batch processor spawns its export task on creation.
+        let _runtime = runtime.enter();
+
+        let exporter = opentelemetry_otlp::new_exporter()
+            .http()
+            .with_endpoint(endpoint)
+            .build_span_exporter()?;
+        let provider = TracerProvider::builder()
+            .with_batch_exporter(exporter, runtime::Tokio)
+            .with_config(sdktrace::config().with_resource(Resource::new([
+                KeyValue::new("service.name", SERVICE_NAME),
+            ])))
+            .build();
+
+        Ok(Telemetry { provider, runtime })
+    }
+
+    // layer forwards tracing spans to the exporter.
+    pub fn layer<S>(&self) -> OpenTelemetryLayer<S, Tracer>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        tracing_opentelemetry::layer()
+            .with_tracer(self.provider.tracer(SERVICE_NAME))
+    }
+
+    // shutdown exports all remaining spans.
+    pub fn shutdown(self) {
+        for result in self.provider.force_flush() {
+            if let Err(err) = result {
+                warn!(err = %err, "failed to export spans");
+            }
+        }
+        drop(self.provider);
+        self.runtime.shutdown_timeout(Duration::from_secs(5));
+    }
+}
+
+// init installs the global tracing subscriber logging to stdout and, if
+// configured, exporting spans to the OTLP collector.
+pub fn init(options: &Options) -> Option<Telemetry> {
+    let telemetry = options.otlp_endpoint.as_ref().map(|endpoint| {
+        Telemetry::new(endpoint).expect("failed to create OTLP exporter")
+    });
+
+    tracing::subscriber::set_global_default(subscriber(
+        telemetry.as_ref(),
+        options.log_level,
+    ))
+    .expect("setting default subscriber failed");
+
+    telemetry
+}
+
+// subscriber logging to stdout up to log_level and exporting all spans to
+// telemetry. The service creates its spans at trace level, so the level only
+// filters the logs.
+fn subscriber(
+    telemetry: Option<&Telemetry>,
+    log_level: Level,
+) -> impl Subscriber + Send + Sync {
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_filter(LevelFilter::from_level(log_level)),
+        )
+        .with(telemetry.map(Telemetry::layer))
+}
+
+// remote_context extracts the W3C trace context the caller propagated in the
+// request metadata.
+pub fn remote_context<'a>(
+    metadata: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+) -> Context {
+    let headers: HashMap<String, String> = metadata
+        .into_iter()
+        .filter_map(|(key, value)| {
+            Some((key.to_owned(), std::str::from_utf8(value).ok()?.to_owned()))
+        })
+        .collect();
+
+    TraceContextPropagator::new().extract(&headers)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{thread, time::Duration};
+
+    use opentelemetry::trace::TraceContextExt;
+    use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+    use prost::Message;
+    use rstest::rstest;
+    use tiny_http::{Response, Server};
+    use tracing::{trace_span, Level};
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    use super::{remote_context, subscriber, Telemetry};
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const SPAN_ID: &str = "00f067aa0ba902b7";
+
+    fn traceparent() -> String {
+        format!("00-{TRACE_ID}-{SPAN_ID}-01")
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[rstest]
+    fn remote_context_reads_traceparent() {
+        let traceparent = traceparent();
+        let context = remote_context([
+            ("traceparent", traceparent.as_bytes()),
+            ("user-agent", b"grpc-dart/2.0".as_slice()),
+        ]);
+
+        let span_context = context.span().span_context().clone();
+        assert!(span_context.is_remote());
+        assert_eq!(span_context.trace_id().to_string(), TRACE_ID);
+        assert_eq!(span_context.span_id().to_string(), SPAN_ID);
+    }
+
+    #[rstest]
+    fn remote_context_without_traceparent() {
+        let context =
+            remote_context([("user-agent", b"grpc-dart/2.0".as_slice())]);
+
+        assert!(!context.span().span_context().is_valid());
+    }
+
+    #[rstest]
+    #[case::default_level(Level::TRACE)]
+    #[case::info_level(Level::INFO)]
+    fn exports_spans_to_collector(#[case] log_level: Level) {
+        // The collector stand-in accepts a single export request.
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", server.server_addr());
+        let collector = thread::spawn(move || {
+            let mut request = server
+                .recv_timeout(Duration::from_secs(10))
+                .unwrap()
+                .expect("should receive export request");
+            assert_eq!(request.url(), "/v1/traces");
+
+            let mut body = vec![];
+            request.as_reader().read_to_end(&mut body).unwrap();
+            request.respond(Response::empty(200)).unwrap();
+
+            ExportTraceServiceRequest::decode(body.as_slice())
+                .expect("should decode export request")
+        });
+
+        let telemetry = Telemetry::new(&endpoint).unwrap();
+        let subscriber = subscriber(Some(&telemetry), log_level);
+        tracing::subscriber::with_default(subscriber, || {
+            let traceparent = traceparent();
+            let span = trace_span!("write_character_revision");
+            span.set_parent(remote_context([(
+                "traceparent",
+                traceparent.as_bytes(),
+            )]));
+            let _enter = span.enter();
+
+            trace_span!("lock_characters").in_scope(|| {});
+        });
+        telemetry.shutdown();
+
+        let spans: Vec<_> = collector
+            .join()
+            .unwrap()
+            .resource_spans
+            .into_iter()
+            .flat_map(|spans| spans.scope_spans)
+            .flat_map(|spans| spans.spans)
+            .collect();
+        let rpc = spans
+            .iter()
+            .find(|span| span.name == "write_character_revision")
+            .expect("should export rpc span");
+        let lock = spans
+            .iter()
+            .find(|span| span.name == "lock_characters")
+            .expect("should export store span");
+
+        assert_eq!(hex(&rpc.trace_id), TRACE_ID);
+        assert_eq!(hex(&rpc.parent_span_id), SPAN_ID);
+        assert_eq!(lock.trace_id, rpc.trace_id);
+        assert_eq!(lock.parent_span_id, rpc.span_id);
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    error::Error,
+    fs,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use character_service::Options;
+use grpcio::{
+    CertificateRequestType, ServerCredentials, ServerCredentialsBuilder,
+    ServerCredentialsFetcher,
+};
+use rustls_pemfile::Item;
+use tracing::info;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TlsConfig {
+    cert: PathBuf,
+    key: PathBuf,
+    client_ca: Option<PathBuf>,
+}
+
+// Certificates holds the PEM encoded files a TlsConfig points to.
+#[derive(Clone, Debug, PartialEq)]
+struct Certificates {
+    cert: Vec<u8>,
+    key: Vec<u8>,
+    client_ca: Option<Vec<u8>>,
+}
+
+impl TlsConfig {
+    pub fn new(
+        cert: PathBuf,
+        key: PathBuf,
+        client_ca: Option<PathBuf>,
+    ) -> TlsConfig {
+        TlsConfig {
+            cert,
+            key,
+            client_ca,
+        }
+    }
+
+    // from_options returns the TlsConfig if TLS is enabled.
+    pub fn from_options(options: &Options) -> Option<TlsConfig> {
+        match (&options.tls_cert, &options.tls_key) {
+            (Some(cert), Some(key)) => Some(TlsConfig::new(
+                cert.into(),
+                key.into(),
+                options.tls_client_ca.as_ref().map(PathBuf::from),
+            )),
+            _ => None,
+        }
+    }
+
+    // server_credentials creates credentials that reload the certificates
+    // whenever the files change.
+    pub fn server_credentials(
+        self,
+    ) -> Result<ServerCredentials, Box<dyn Error>> {
+        // The fetcher can't report errors during startup, so make sure the
+        // files are valid before handing them to gRPC.
+        self.load()?;
+
+        let request_type = self.certificate_request_type();
+        Ok(ServerCredentials::with_fetcher(
+            Box::new(CertificateFetcher::new(self)),
+            request_type,
+        ))
+    }
+
+    fn certificate_request_type(&self) -> CertificateRequestType {
+        match self.client_ca {
+            Some(_) => {
+                CertificateRequestType::RequestAndRequireClientCertificateAndVerify
+            }
+            None => CertificateRequestType::DontRequestClientCertificate,
+        }
+    }
+
+    fn load(&self) -> Result<Certificates, Box<dyn Error>> {
+        let cert = read_pem(&self.cert)?;
+        if !contains_certificate(&cert)? {
+            return Err(invalid_pem(&self.cert, "no certificate found"));
+        }
+
+        let key = read_pem(&self.key)?;
+        if !contains_private_key(&key)? {
+            return Err(invalid_pem(&self.key, "no private key found"));
+        }
+
+        let client_ca = match &self.client_ca {
+            Some(path) => {
+                let client_ca = read_pem(path)?;
+                if !contains_certificate(&client_ca)? {
+                    return Err(invalid_pem(path, "no certificate found"));
+                }
+                Some(client_ca)
+            }
+            None => None,
+        };
+
+        Ok(Certificates {
+            cert,
+            key,
+            client_ca,
+        })
+    }
+}
+
+impl Certificates {
+    fn credentials_builder(&self) -> ServerCredentialsBuilder {
+        let builder = ServerCredentialsBuilder::new()
+            .add_cert(self.cert.clone(), self.key.clone());
+
+        match &self.client_ca {
+            Some(client_ca) => builder.root_cert(
+                client_ca.clone(),
+                CertificateRequestType::RequestAndRequireClientCertificateAndVerify,
+            ),
+            None => builder,
+        }
+    }
+}
+
+// CertificateFetcher is asked for credentials by gRPC whenever a new
+// connection is accepted. It rereads the certificate files and only returns
+// new credentials if their content changed.
+pub struct CertificateFetcher {
+    config: TlsConfig,
+    loaded: Mutex<Option<Certificates>>,
+}
+
+impl CertificateFetcher {
+    pub fn new(config: TlsConfig) -> CertificateFetcher {
+        CertificateFetcher {
+            config,
+            loaded: Mutex::new(None),
+        }
+    }
+}
+
+impl ServerCredentialsFetcher for CertificateFetcher {
+    fn fetch(
+        &self,
+    ) -> Result<Option<ServerCredentialsBuilder>, Box<dyn Error>> {
+        let certificates = self.config.load()?;
+
+        // unwrapping the lock() here to panic on a poisoned lock
+        let mut loaded = self.loaded.lock().unwrap();
+        if loaded.as_ref() == Some(&certificates) {
+            return Ok(None);
+        }
+
+        info!(cert = ?self.config.cert, "loading TLS certificates");
+        let builder = certificates.credentials_builder();
+        *loaded = Some(certificates);
+
+        Ok(Some(builder))
+    }
+}
+
+fn read_pem(path: &Path) -> Result<Vec<u8>, Box<dyn Error>> {
+    match fs::read(path) {
+        Ok(content) => Ok(content),
+        Err(err) => {
+            Err(format!("failed to read {}: {err}", path.display()).into())
+        }
+    }
+}
+
+fn contains_certificate(pem: &[u8]) -> io::Result<bool> {
+    Ok(!rustls_pemfile::certs(&mut BufReader::new(pem))?.is_empty())
+}
+
+fn contains_private_key(pem: &[u8]) -> io::Result<bool> {
+    Ok(rustls_pemfile::read_all(&mut BufReader::new(pem))?
+        .iter()
+        .any(|item| {
+            matches!(item, Item::RSAKey(_) | Item::PKCS8Key(_) | Item::ECKey(_))
+        }))
+}
+
+fn invalid_pem(path: &Path, reason: &str) -> Box<dyn Error> {
+    format!("invalid PEM file {}: {reason}", path.display()).into()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use grpcio::{CertificateRequestType, ServerCredentialsFetcher};
+    use rcgen::{BasicConstraints, Certificate, CertificateParams, IsCa};
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::{CertificateFetcher, TlsConfig};
+
+    fn generate_ca() -> Certificate {
+        let mut params = CertificateParams::new(vec![]);
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        Certificate::from_params(params).unwrap()
+    }
+
+    // write_certificate writes a certificate for localhost signed by ca.
+    fn write_certificate(ca: &Certificate, cert: &Path, key: &Path) {
+        let certificate =
+            rcgen::generate_simple_self_signed(vec!["localhost".to_owned()])
+                .unwrap();
+        fs::write(cert, certificate.serialize_pem_with_signer(ca).unwrap())
+            .unwrap();
+        fs::write(key, certificate.serialize_private_key_pem()).unwrap();
+    }
+
+    #[rstest]
+    fn fetch_loads_certificates_once() {
+        let dir = tempdir().unwrap();
+        let (cert, key) =
+            (dir.path().join("tls.crt"), dir.path().join("tls.key"));
+        write_certificate(&generate_ca(), &cert, &key);
+
+        let fetcher = CertificateFetcher::new(TlsConfig::new(cert, key, None));
+
+        assert!(
+            fetcher.fetch().expect("should load certificates").is_some(),
+            "should return credentials on first fetch"
+        );
+        assert!(
+            fetcher.fetch().expect("should load certificates").is_none(),
+            "should not return credentials for unchanged files"
+        );
+    }
+
+    #[rstest]
+    fn fetch_reloads_changed_certificates() {
+        let dir = tempdir().unwrap();
+        let (cert, key) =
+            (dir.path().join("tls.crt"), dir.path().join("tls.key"));
+        let ca = generate_ca();
+        write_certificate(&ca, &cert, &key);
+
+        let fetcher = CertificateFetcher::new(TlsConfig::new(
+            cert.clone(),
+            key.clone(),
+            None,
+        ));
+        assert!(fetcher.fetch().unwrap().is_some());
+
+        write_certificate(&ca, &cert, &key);
+        assert!(
+            fetcher
+                .fetch()
+                .expect("should reload certificates")
+                .is_some(),
+            "should return credentials for changed files"
+        );
+        assert!(fetcher.fetch().unwrap().is_none());
+    }
+
+    #[rstest]
+    fn fetch_keeps_certificates_on_invalid_files() {
+        let dir = tempdir().unwrap();
+        let (cert, key) =
+            (dir.path().join("tls.crt"), dir.path().join("tls.key"));
+        write_certificate(&generate_ca(), &cert, &key);
+
+        let fetcher =
+            CertificateFetcher::new(TlsConfig::new(cert, key.clone(), None));
+        assert!(fetcher.fetch().unwrap().is_some());
+
+        fs::write(&key, "not a key").unwrap();
+        assert!(fetcher.fetch().is_err(), "should fail to load invalid key");
+    }
+
+    #[rstest]
+    fn load_requires_certificate_files() {
+        let dir = tempdir().unwrap();
+
+        let config = TlsConfig::new(
+            dir.path().join("tls.crt"),
+            dir.path().join("tls.key"),
+            None,
+        );
+        assert!(config.load().is_err(), "should fail on missing files");
+    }
+
+    #[rstest]
+    fn load_validates_client_ca() {
+        let dir = tempdir().unwrap();
+        let (cert, key) =
+            (dir.path().join("tls.crt"), dir.path().join("tls.key"));
+        let client_ca = dir.path().join("ca.crt");
+        let ca = generate_ca();
+        write_certificate(&ca, &cert, &key);
+
+        fs::write(&client_ca, ca.serialize_private_key_pem()).unwrap();
+        let config = TlsConfig::new(cert, key, Some(client_ca.clone()));
+        assert!(
+            config.load().is_err(),
+            "should fail on client CA without certificate"
+        );
+
+        fs::write(&client_ca, ca.serialize_pem().unwrap()).unwrap();
+        let certificates = config
+            .load()
+            .expect("should load certificates with client CA");
+        assert!(certificates.client_ca.is_some());
+    }
+
+    #[rstest]
+    #[case(None, CertificateRequestType::DontRequestClientCertificate)]
+    #[case(
+        Some("ca.crt"),
+        CertificateRequestType::RequestAndRequireClientCertificateAndVerify
+    )]
+    fn client_ca_requires_client_certificates(
+        #[case] client_ca: Option<&str>,
+        #[case] expected: CertificateRequestType,
+    ) {
+        let config = TlsConfig::new(
+            "tls.crt".into(),
+            "tls.key".into(),
+            client_ca.map(Into::into),
+        );
+        assert_eq!(config.certificate_request_type() as u32, expected as u32);
+    }
+}
@@ -0,0 +1,448 @@
+use std::collections::HashSet;
+
+use proto_rs::character::{Character, Item, ItemPath, Stat};
+
+use crate::db::{Error, ErrorCode, Violation};
+
+// Maximum length in bytes of names and other single line text fields.
+const MAX_NAME_LENGTH: usize = 256;
+// Maximum length in bytes of descriptions and note texts.
+const MAX_TEXT_LENGTH: usize = 64 * 1024;
+
+// validate_revision checks that character can be stored as a revision of the
+// character with uuid, failing with InvalidArgument listing all violations.
+pub fn validate_revision(
+    uuid: &str,
+    character: &Character,
+) -> Result<(), Error> {
+    let violations = violations(uuid, character);
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    let message = violations
+        .iter()
+        .map(Violation::to_string)
+        .collect::<Vec<String>>()
+        .join("; ");
+    Err(Error::new(
+        ErrorCode::InvalidArgument,
+        &format!("invalid character: {message}"),
+    )
+    .with_violations(violations))
+}
+
+fn violations(uuid: &str, character: &Character) -> Vec<Violation> {
+    let mut v = Validator::default();
+
+    if character.uuid != uuid {
+        v.violation(
+            "character.uuid",
+            format!("does not match the written character {uuid:?}"),
+        );
+    }
+
+    v.check_name("character.name", &character.name);
+    v.check_name("character.descriptor", &character.descriptor);
+    v.check_name("character.type", &character.r#type);
+    v.check_name("character.focus", &character.focus);
+
+    if let Some(stats) = character.stats.as_ref() {
+        v.check_stat("character.stats.might", stats.might.as_ref());
+        v.check_stat("character.stats.speed", stats.speed.as_ref());
+        v.check_stat("character.stats.intellect", stats.intellect.as_ref());
+    }
+
+    v.check_uuids(
+        "character.skills",
+        character.skills.iter().map(|skill| skill.uuid.as_str()),
+    );
+    for (i, skill) in character.skills.iter().enumerate() {
+        let field = format!("character.skills[{i}]");
+        v.check_name(&format!("{field}.name"), &skill.name);
+        v.check_text(&format!("{field}.description"), &skill.description);
+    }
+
+    v.check_uuids(
+        "character.abilities",
+        character
+            .abilities
+            .iter()
+            .map(|ability| ability.uuid.as_str()),
+    );
+    for (i, ability) in character.abilities.iter().enumerate() {
+        let field = format!("character.abilities[{i}]");
+        v.check_name(&format!("{field}.name"), &ability.name);
+        v.check_name(&format!("{field}.cost"), &ability.cost);
+        v.check_text(&format!("{field}.description"), &ability.description);
+        v.check_text(
+            &format!("{field}.short_description"),
+            &ability.short_description,
+        );
+    }
+
+    v.check_uuids(
+        "character.cyphers",
+        character.cyphers.iter().map(|cypher| cypher.uuid.as_str()),
+    );
+    for (i, cypher) in character.cyphers.iter().enumerate() {
+        let field = format!("character.cyphers[{i}]");
+        v.check_name(&format!("{field}.name"), &cypher.name);
+        v.check_name(&format!("{field}.level"), &cypher.level);
+        v.check_name(&format!("{field}.depletion"), &cypher.depletion);
+        v.check_name(&format!("{field}.internal"), &cypher.internal);
+        v.check_name(&format!("{field}.wearable"), &cypher.wearable);
+        v.check_name(&format!("{field}.usable"), &cypher.usable);
+        v.check_text(
+            &format!("{field}.short_description"),
+            &cypher.short_description,
+        );
+        v.check_text(&format!("{field}.effect"), &cypher.effect);
+    }
+
+    v.check_uuids(
+        "character.artifacts",
+        character
+            .artifacts
+            .iter()
+            .map(|artifact| artifact.uuid.as_str()),
+    );
+    for (i, artifact) in character.artifacts.iter().enumerate() {
+        let field = format!("character.artifacts[{i}]");
+        v.check_name(&format!("{field}.name"), &artifact.name);
+        v.check_name(&format!("{field}.level"), &artifact.level);
+        v.check_name(&format!("{field}.depletion"), &artifact.depletion);
+        v.check_name(&format!("{field}.form"), &artifact.form);
+        v.check_text(
+            &format!("{field}.short_description"),
+            &artifact.short_description,
+        );
+        v.check_text(&format!("{field}.effect"), &artifact.effect);
+    }
+
+    v.check_uuids(
+        "character.inventories",
+        character
+            .inventories
+            .iter()
+            .map(|inventory| inventory.uuid.as_str()),
+    );
+    for (i, inventory) in character.inventories.iter().enumerate() {
+        v.check_name(
+            &format!("character.inventories[{i}].name"),
+            &inventory.name,
+        );
+    }
+
+    v.check_items(character);
+
+    v.check_uuids(
+        "character.notes",
+        character.notes.iter().map(|note| note.uuid.as_str()),
+    );
+    for (i, note) in character.notes.iter().enumerate() {
+        let field = format!("character.notes[{i}]");
+        v.check_name(&format!("{field}.title"), &note.title);
+        v.check_text(
+            &format!("{field}.shortDescription"),
+            &note.short_description,
+        );
+        v.check_text(&format!("{field}.text"), &note.text);
+    }
+
+    v.violations
+}
+
+#[derive(Default)]
+struct Validator {
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    fn violation(&mut self, field: &str, description: String) {
+        self.violations.push(Violation {
+            field: field.to_owned(),
+            description,
+        });
+    }
+
+    fn check_length(&mut self, field: &str, value: &str, max: usize) {
+        if value.len() > max {
+            self.violation(
+                field,
+                format!(
+                    "is {} bytes long, at most {max} are allowed",
+                    value.len()
+                ),
+            );
+        }
+    }
+
+    fn check_name(&mut self, field: &str, value: &str) {
+        self.check_length(field, value, MAX_NAME_LENGTH)
+    }
+
+    fn check_text(&mut self, field: &str, value: &str) {
+        self.check_length(field, value, MAX_TEXT_LENGTH)
+    }
+
+    fn check_stat(&mut self, field: &str, stat: Option<&Stat>) {
+        let stat = match stat {
+            Some(stat) => stat,
+            None => return,
+        };
+
+        if stat.pool < 0 {
+            self.violation(&format!("{field}.pool"), "is negative".to_owned());
+        }
+        if stat.cap < 0 {
+            self.violation(&format!("{field}.cap"), "is negative".to_owned());
+        }
+        if stat.edge < 0 {
+            self.violation(&format!("{field}.edge"), "is negative".to_owned());
+        }
+        if stat.pool > stat.cap {
+            self.violation(
+                &format!("{field}.pool"),
+                format!("exceeds the cap of {}", stat.cap),
+            );
+        }
+    }
+
+    // check_uuids reports missing and duplicate uuids of the entities in the
+    // repeated field.
+    fn check_uuids<'a>(
+        &mut self,
+        field: &str,
+        uuids: impl Iterator<Item = &'a str>,
+    ) {
+        self.check_unique(field, "uuid", uuids)
+    }
+
+    fn check_unique<'a>(
+        &mut self,
+        field: &str,
+        key: &str,
+        uuids: impl Iterator<Item = &'a str>,
+    ) {
+        let mut seen = HashSet::new();
+        for (i, uuid) in uuids.enumerate() {
+            let field = format!("{field}[{i}].{key}");
+            if uuid.is_empty() {
+                self.violation(&field, "is required".to_owned());
+            } else if !seen.insert(uuid) {
+                self.violation(&field, format!("duplicates uuid {uuid:?}"));
+            }
+        }
+    }
+
+    // check_items validates the items and that their paths point to existing
+    // inventories and parent items.
+    fn check_items(&mut self, character: &Character) {
+        let inventories: HashSet<&str> = character
+            .inventories
+            .iter()
+            .map(|inventory| inventory.uuid.as_str())
+            .collect();
+        let items: HashSet<&str> =
+            character.items.iter().map(item_self).collect();
+
+        self.check_unique(
+            "character.items",
+            "path.self",
+            character.items.iter().map(item_self),
+        );
+
+        for (i, item) in character.items.iter().enumerate() {
+            let field = format!("character.items[{i}]");
+            self.check_item_path(&field, item, &inventories, &items);
+            self.check_name(&format!("{field}.name"), &item.name);
+            self.check_text(&format!("{field}.description"), &item.description);
+            self.check_text(
+                &format!("{field}.short_description"),
+                &item.short_description,
+            );
+        }
+    }
+
+    fn check_item_path(
+        &mut self,
+        field: &str,
+        item: &Item,
+        inventories: &HashSet<&str>,
+        items: &HashSet<&str>,
+    ) {
+        let default = ItemPath::default();
+        let path = item.path.as_ref().unwrap_or(&default);
+
+        if !inventories.contains(path.inventory.as_str()) {
+            self.violation(
+                &format!("{field}.path.inventory"),
+                format!("references missing inventory {:?}", path.inventory),
+            );
+        }
+
+        let parent = path.parent.as_str();
+        if !parent.is_empty() && !items.contains(parent) {
+            self.violation(
+                &format!("{field}.path.parent"),
+                format!("references missing item {parent:?}"),
+            );
+        }
+    }
+}
+
+// item_self is the uuid of item, empty if it has no path.
+fn item_self(item: &Item) -> &str {
+    item.path.as_ref().map_or("", |path| path.self_.as_str())
+}
+
+#[cfg(test)]
+mod test {
+    use proto_rs::character::{
+        Character, Inventory, Item, ItemPath, Note, Skill, Stat, Stats,
+    };
+    use rstest::rstest;
+
+    use super::{validate_revision, violations, MAX_NAME_LENGTH};
+    use crate::db::ErrorCode;
+
+    const UUID: &str = "2a4b6c8d";
+
+    fn character() -> Character {
+        Character {
+            uuid: UUID.to_owned(),
+            name: "Eona".to_owned(),
+            stats: Some(Stats {
+                might: Some(Stat {
+                    cap: 12,
+                    pool: 10,
+                    edge: 1,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            skills: vec![skill("s1"), skill("s2")],
+            inventories: vec![Inventory {
+                uuid: "i1".to_owned(),
+                name: "Backpack".to_owned(),
+                ..Default::default()
+            }],
+            items: vec![item("rope", "i1", ""), item("knot", "i1", "rope")],
+            notes: vec![Note {
+                uuid: "n1".to_owned(),
+                text: "Met the Aeon Priests.".to_owned(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn skill(uuid: &str) -> Skill {
+        Skill {
+            uuid: uuid.to_owned(),
+            name: "Climbing".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn item(uuid: &str, inventory: &str, parent: &str) -> Item {
+        Item {
+            path: Some(ItemPath {
+                inventory: inventory.to_owned(),
+                parent: parent.to_owned(),
+                self_: uuid.to_owned(),
+            }),
+            name: "Rope".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn fields(character: &Character) -> Vec<String> {
+        violations(UUID, character)
+            .into_iter()
+            .map(|violation| violation.field)
+            .collect()
+    }
+
+    #[rstest]
+    fn accepts_valid_character() {
+        assert_eq!(validate_revision(UUID, &character()), Ok(()));
+    }
+
+    #[rstest]
+    fn rejects_mismatched_uuid() {
+        let err = validate_revision("other", &character())
+            .expect_err("should reject mismatched uuid");
+
+        assert_eq!(err.code(), ErrorCode::InvalidArgument);
+        assert!(err.message().contains("character.uuid"));
+        assert_eq!(err.violations().len(), 1);
+        assert_eq!(err.violations()[0].field, "character.uuid");
+    }
+
+    #[rstest]
+    #[case(-1, 12, vec!["character.stats.might.pool"])]
+    #[case(13, 12, vec!["character.stats.might.pool"])]
+    #[case(-1, -2, vec!["character.stats.might.pool", "character.stats.might.cap", "character.stats.might.pool"])]
+    fn rejects_invalid_pools(
+        #[case] pool: i32,
+        #[case] cap: i32,
+        #[case] expected: Vec<&str>,
+    ) {
+        let mut character = character();
+        let might = character
+            .stats
+            .as_mut()
+            .and_then(|stats| stats.might.as_mut())
+            .unwrap();
+        might.pool = pool;
+        might.cap = cap;
+
+        assert_eq!(fields(&character), expected);
+    }
+
+    #[rstest]
+    fn rejects_duplicate_uuids() {
+        let mut character = character();
+        character.skills.push(skill("s1"));
+        character.skills.push(skill(""));
+        character.items.push(item("rope", "i1", ""));
+
+        assert_eq!(
+            fields(&character),
+            vec![
+                "character.skills[2].uuid",
+                "character.skills[3].uuid",
+                "character.items[2].path.self",
+            ]
+        );
+    }
+
+    #[rstest]
+    fn rejects_items_in_missing_inventories() {
+        let mut character = character();
+        character.items.push(item("lamp", "i2", ""));
+        character.items.push(item("wick", "i1", "candle"));
+
+        assert_eq!(
+            fields(&character),
+            vec![
+                "character.items[2].path.inventory",
+                "character.items[3].path.parent",
+            ]
+        );
+    }
+
+    #[rstest]
+    fn rejects_oversized_text() {
+        let mut character = character();
+        character.name = "a".repeat(MAX_NAME_LENGTH + 1);
+        character.notes[0].text = "a".repeat(1024 * 1024);
+
+        assert_eq!(
+            fields(&character),
+            vec!["character.name", "character.notes[0].text"]
+        );
+    }
+}
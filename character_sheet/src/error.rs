@@ -0,0 +1,39 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ErrorCode {
+    // InvalidActor errors are Foundry VTT actors that can't be imported, e.g.
+    // because they aren't player characters.
+    InvalidActor,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Error {
+    code: ErrorCode,
+    message: String,
+}
+
+impl Error {
+    pub fn new(code: ErrorCode, message: &str) -> Error {
+        Error {
+            code,
+            message: message.to_owned(),
+        }
+    }
+
+    pub fn message(&self) -> String {
+        self.message.to_owned()
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
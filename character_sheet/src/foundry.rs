@@ -0,0 +1,988 @@
+// Conversion between characters and actors of the Cypher System for Foundry
+// VTT (https://foundryvtt.com/packages/cyphersystem), exported from and
+// imported into Foundry as JSON.
+
+use std::fmt::Display;
+
+use cypher_rules::{stat, DamageTrack, Dice};
+use proto_rs::character::{
+    Ability, Advancements, Artifact, Character, Cypher, Inventory, Item,
+    ItemPath, ItemType, PoolType, Progress, Recovery, Skill, SkillLevel, Stat,
+    Stats,
+};
+use serde_json::{json, Value};
+
+use crate::error::{Error, ErrorCode};
+
+// FLAGS is the scope of the Foundry flags keeping the uuids of the app, so
+// they survive a round trip through Foundry.
+const FLAGS: &str = "cypher_sheet";
+
+// EQUIPMENT is the inventory holding the equipment of imported actors.
+const EQUIPMENT: &str = "equipment";
+
+// Unmapped is a field which couldn't be converted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unmapped {
+    // field in the converted character or actor, e.g. "notes" or "items[2]".
+    pub field: String,
+    pub reason: String,
+}
+
+impl Display for Unmapped {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+// Conversion is a converted value and the fields lost on the way.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion<T> {
+    pub value: T,
+    pub unmapped: Vec<Unmapped>,
+}
+
+#[derive(Default)]
+struct Report {
+    unmapped: Vec<Unmapped>,
+}
+
+impl Report {
+    fn add(&mut self, field: &str, reason: &str) {
+        self.unmapped.push(Unmapped {
+            field: field.to_owned(),
+            reason: reason.to_owned(),
+        });
+    }
+
+    // check adds field if lost is set.
+    fn check(&mut self, lost: bool, field: &str, reason: &str) {
+        if lost {
+            self.add(field, reason);
+        }
+    }
+}
+
+// to_foundry converts character to a Foundry player character actor.
+pub fn to_foundry(character: &Character) -> Conversion<Value> {
+    let mut report = Report::default();
+    let progress = character.progress.clone().unwrap_or_default();
+    let recovery = character.recovery.clone().unwrap_or_default();
+    let advancements = progress.advancements.clone().unwrap_or_default();
+
+    report.check(
+        progress.total_xp != 0,
+        "progress.total_xp",
+        "Foundry only tracks unspent XP",
+    );
+    report.check(
+        character.color.is_some(),
+        "color",
+        "Foundry actors have no color",
+    );
+    report.check(
+        character.money != 0.0,
+        "money",
+        "Foundry actors have no money",
+    );
+    report.check(
+        !character.notes.is_empty(),
+        "notes",
+        "Foundry actors have no notes",
+    );
+    report.check(
+        character.inventories.len() > 1,
+        "inventories",
+        "Foundry keeps all equipment in a single list",
+    );
+
+    let mut items = vec![];
+    for skill in &character.skills {
+        items.push(export_skill(skill));
+    }
+    for (index, ability) in character.abilities.iter().enumerate() {
+        items.push(export_ability(ability, index, &mut report));
+    }
+    for (index, cypher) in character.cyphers.iter().enumerate() {
+        items.push(export_cypher(cypher, index, &mut report));
+    }
+    for (index, artifact) in character.artifacts.iter().enumerate() {
+        items.push(export_artifact(artifact, index, &mut report));
+    }
+    for (index, item) in character.items.iter().enumerate() {
+        items.push(export_item(item, index, &mut report));
+    }
+    // sort keeps the order of the items in Foundry.
+    for (sort, item) in items.iter_mut().enumerate() {
+        item["sort"] = json!(sort);
+    }
+
+    let actor = json!({
+        "name": character.name,
+        "type": "pc",
+        "system": {
+            "basic": {
+                "descriptor": character.descriptor,
+                "type": character.r#type,
+                "focus": character.focus,
+                "tier": progress.tier,
+                "effort": progress.max_effort,
+                "xp": progress.free_xp,
+            },
+            "pools": {
+                "might": export_pool(&stat(character, PoolType::Might)),
+                "speed": export_pool(&stat(character, PoolType::Speed)),
+                "intellect": export_pool(&stat(character, PoolType::Intellect)),
+            },
+            "combat": {
+                "recoveries": {
+                    "roll": format!(
+                        "1d6+{}",
+                        progress.tier.saturating_add(recovery.bonus)
+                    ),
+                    "oneAction": recovery.one_action,
+                    "tenMinutes": recovery.ten_minutes,
+                    "oneHour": recovery.one_hour,
+                    "tenHours": recovery.ten_hours,
+                },
+                "damageTrack": {
+                    "state": match DamageTrack::of(character) {
+                        DamageTrack::Hale => "Hale",
+                        DamageTrack::Impaired => "Impaired",
+                        DamageTrack::Debilitated => "Debilitated",
+                        DamageTrack::Dead => "Dead",
+                    },
+                },
+            },
+            "advancement": {
+                "stats": advancements.increase_capabilities,
+                "edge": advancements.move_toward_perfection,
+                "effort": advancements.extra_effort,
+                "skill": advancements.skill_training,
+                "other": advancements.other,
+            },
+            "settings": {
+                "equipment": {
+                    "cypherLimit": character.cypher_limit,
+                },
+            },
+        },
+        "items": items,
+        "flags": flags(&character.uuid),
+    });
+
+    Conversion {
+        value: actor,
+        unmapped: report.unmapped,
+    }
+}
+
+fn flags(uuid: &str) -> Value {
+    json!({ FLAGS: { "uuid": uuid } })
+}
+
+fn export_pool(stat: &Stat) -> Value {
+    json!({ "value": stat.pool, "max": stat.cap, "edge": stat.edge })
+}
+
+// description of an item, Foundry has a single one so the short description
+// is only kept if there is nothing else.
+fn description(
+    description: &str,
+    short_description: &str,
+    field: &str,
+    report: &mut Report,
+) -> String {
+    report.check(
+        !description.is_empty() && !short_description.is_empty(),
+        &format!("{field}.short_description"),
+        "Foundry items have a single description",
+    );
+    match description.is_empty() {
+        false => description.to_owned(),
+        true => short_description.to_owned(),
+    }
+}
+
+fn export_skill(skill: &Skill) -> Value {
+    json!({
+        "name": skill.name,
+        "type": "skill",
+        "system": {
+            "description": skill.description,
+            "basic": {
+                "rating": match skill.level() {
+                    SkillLevel::Specialized => "Specialized",
+                    SkillLevel::Trained => "Trained",
+                    SkillLevel::Inability => "Inability",
+                },
+            },
+            "settings": {
+                "rollButton": { "pool": pool_name(skill.r#type()) },
+            },
+        },
+        "flags": flags(&skill.uuid),
+    })
+}
+
+fn export_ability(
+    ability: &Ability,
+    index: usize,
+    report: &mut Report,
+) -> Value {
+    let field = format!("abilities[{index}]");
+    report.check(
+        ability.enabler,
+        &format!("{field}.enabler"),
+        "Foundry doesn't tell enablers apart from actions",
+    );
+    json!({
+        "name": ability.name,
+        "type": "ability",
+        "system": {
+            "description": description(
+                &ability.description,
+                &ability.short_description,
+                &field,
+                report,
+            ),
+            "basic": {
+                "cost": ability.cost,
+                "pool": pool_name(ability.r#type()),
+            },
+        },
+        "flags": flags(&ability.uuid),
+    })
+}
+
+fn export_cypher(cypher: &Cypher, index: usize, report: &mut Report) -> Value {
+    let field = format!("cyphers[{index}]");
+    for (lost, name) in [
+        (&cypher.depletion, "depletion"),
+        (&cypher.internal, "internal"),
+        (&cypher.wearable, "wearable"),
+        (&cypher.usable, "usable"),
+    ] {
+        report.check(
+            !lost.is_empty(),
+            &format!("{field}.{name}"),
+            "Foundry cyphers only have a level and a description",
+        );
+    }
+    json!({
+        "name": cypher.name,
+        "type": "cypher",
+        "system": {
+            "description": description(
+                &cypher.effect,
+                &cypher.short_description,
+                &field,
+                report,
+            ),
+            "basic": {
+                "level": cypher.level,
+                "identified": true,
+            },
+            "archived": !cypher.active,
+        },
+        "flags": flags(&cypher.uuid),
+    })
+}
+
+fn export_artifact(
+    artifact: &Artifact,
+    index: usize,
+    report: &mut Report,
+) -> Value {
+    let field = format!("artifacts[{index}]");
+    report.check(
+        !artifact.form.is_empty(),
+        &format!("{field}.form"),
+        "Foundry artifacts have no form",
+    );
+    json!({
+        "name": artifact.name,
+        "type": "artifact",
+        "system": {
+            "description": description(
+                &artifact.effect,
+                &artifact.short_description,
+                &field,
+                report,
+            ),
+            "basic": {
+                "level": artifact.level,
+                "depletion": artifact.depletion,
+                "identified": true,
+            },
+            "archived": !artifact.active,
+        },
+        "flags": flags(&artifact.uuid),
+    })
+}
+
+fn export_item(item: &Item, index: usize, report: &mut Report) -> Value {
+    let field = format!("items[{index}]");
+    let path = item.path.clone().unwrap_or_default();
+    let item_type = match item.types().next() {
+        Some(ItemType::Armor) => "armor",
+        Some(ItemType::Ammo) => "ammo",
+        Some(ItemType::Material) => "material",
+        Some(ItemType::Oddity) => "oddity",
+        _ => "equipment",
+    };
+
+    report.check(
+        item.types.len() > 1
+            || (item_type == "equipment" && !item.types.is_empty()),
+        &format!("{field}.types"),
+        "Foundry items have a single type",
+    );
+    report.check(
+        item.sub_item_type.is_some(),
+        &format!("{field}.sub_item_type"),
+        "Foundry items have a single type",
+    );
+    report.check(
+        item.armor.is_some() && item_type != "armor",
+        &format!("{field}.armor"),
+        "only Foundry armor has an armor rating",
+    );
+    report.check(
+        item.value != 0.0,
+        &format!("{field}.value"),
+        "Foundry items have no value",
+    );
+    report.check(
+        !path.parent.is_empty(),
+        &format!("{field}.path.parent"),
+        "Foundry items can't be nested",
+    );
+
+    let basic = match item_type {
+        "armor" => json!({ "rating": item.armor.unwrap_or_default() }),
+        _ => json!({ "quantity": item.amount }),
+    };
+    json!({
+        "name": item.name,
+        "type": item_type,
+        "system": {
+            "description": description(
+                &item.description,
+                &item.short_description,
+                &field,
+                report,
+            ),
+            "basic": basic,
+        },
+        "flags": flags(&path.self_),
+    })
+}
+
+// from_foundry converts a Foundry player character actor to a character.
+// Descriptions are kept as they are, including any HTML.
+pub fn from_foundry(actor: &Value) -> Result<Conversion<Character>, Error> {
+    if !actor.is_object() {
+        return Err(Error::new(
+            ErrorCode::InvalidActor,
+            "actor needs to be a JSON object",
+        ));
+    }
+    let actor_type = text(actor, "/type");
+    if actor_type != "pc" {
+        return Err(Error::new(
+            ErrorCode::InvalidActor,
+            &format!("{actor_type:?} isn't a player character (pc) actor"),
+        ));
+    }
+
+    let mut report = Report::default();
+    let tier = int(actor, "/system/basic/tier");
+    let mut character = Character {
+        uuid: uuid(actor, ""),
+        name: text(actor, "/name"),
+        descriptor: text(actor, "/system/basic/descriptor"),
+        r#type: text(actor, "/system/basic/type"),
+        focus: text(actor, "/system/basic/focus"),
+        progress: Some(Progress {
+            tier,
+            free_xp: int(actor, "/system/basic/xp"),
+            max_effort: int(actor, "/system/basic/effort"),
+            advancements: Some(Advancements {
+                increase_capabilities: flag(actor, "/system/advancement/stats"),
+                move_toward_perfection: flag(actor, "/system/advancement/edge"),
+                extra_effort: flag(actor, "/system/advancement/effort"),
+                skill_training: flag(actor, "/system/advancement/skill"),
+                other: flag(actor, "/system/advancement/other"),
+            }),
+            ..Default::default()
+        }),
+        stats: Some(Stats {
+            might: Some(import_pool(actor, PoolType::Might)),
+            speed: Some(import_pool(actor, PoolType::Speed)),
+            intellect: Some(import_pool(actor, PoolType::Intellect)),
+        }),
+        recovery: Some(import_recovery(actor, tier, &mut report)),
+        cypher_limit: int(actor, "/system/settings/equipment/cypherLimit"),
+        ..Default::default()
+    };
+
+    if character.uuid.is_empty() {
+        report.add("_id", "actor has no id, the character needs a new uuid");
+    }
+    import_damage(actor, &mut report).set(&mut character);
+
+    let items = actor
+        .pointer("/items")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    for (index, item) in items.iter().enumerate() {
+        import_item(&mut character, item, index, &mut report);
+    }
+    if !character.items.is_empty() {
+        character.inventories.push(Inventory {
+            uuid: EQUIPMENT.to_owned(),
+            name: "Equipment".to_owned(),
+            order: 0,
+        });
+    }
+
+    Ok(Conversion {
+        value: character,
+        unmapped: report.unmapped,
+    })
+}
+
+fn import_pool(actor: &Value, pool: PoolType) -> Stat {
+    let path = format!("/system/pools/{}", pool_name(pool).to_lowercase());
+    Stat {
+        r#type: pool.into(),
+        pool: int(actor, &format!("{path}/value")),
+        cap: int(actor, &format!("{path}/max")),
+        edge: int(actor, &format!("{path}/edge")),
+    }
+}
+
+// import_recovery takes the recovery bonus from the recovery roll, which
+// includes the tier.
+fn import_recovery(actor: &Value, tier: i32, report: &mut Report) -> Recovery {
+    let path = "/system/combat/recoveries";
+    let roll = text(actor, &format!("{path}/roll"));
+    let bonus = match roll.parse::<Dice>() {
+        _ if roll.is_empty() => 0,
+        Ok(dice) if dice.count() == 1 && dice.sides() == 6 => {
+            dice.modifier().saturating_sub(tier).max(0)
+        }
+        _ => {
+            report.add(
+                "system.combat.recoveries.roll",
+                &format!("{roll} isn't a recovery roll like 1d6+3"),
+            );
+            0
+        }
+    };
+    Recovery {
+        bonus,
+        one_action: flag(actor, &format!("{path}/oneAction")),
+        ten_minutes: flag(actor, &format!("{path}/tenMinutes")),
+        one_hour: flag(actor, &format!("{path}/oneHour")),
+        ten_hours: flag(actor, &format!("{path}/tenHours")),
+    }
+}
+
+// import_damage reads the damage track, setting it empties the pools of dead
+// characters.
+fn import_damage(actor: &Value, report: &mut Report) -> DamageTrack {
+    let state = text(actor, "/system/combat/damageTrack/state");
+    match state.as_str() {
+        "" | "Hale" => DamageTrack::Hale,
+        "Impaired" => DamageTrack::Impaired,
+        "Debilitated" => DamageTrack::Debilitated,
+        "Dead" => DamageTrack::Dead,
+        state => {
+            report.add(
+                "system.combat.damageTrack.state",
+                &format!("unknown damage track state {state}"),
+            );
+            DamageTrack::Hale
+        }
+    }
+}
+
+fn import_item(
+    character: &mut Character,
+    item: &Value,
+    index: usize,
+    report: &mut Report,
+) {
+    let field = format!("items[{index}]");
+    let uuid = uuid(item, &format!("foundry-item-{index}"));
+    let name = text(item, "/name");
+    let description = text(item, "/system/description");
+    let active = !flag(item, "/system/archived");
+
+    match text(item, "/type").as_str() {
+        "skill" => {
+            let level = match text(item, "/system/basic/rating").as_str() {
+                "Specialized" => SkillLevel::Specialized,
+                "Trained" => SkillLevel::Trained,
+                "Inability" => SkillLevel::Inability,
+                rating => {
+                    report.add(
+                        &field,
+                        &format!("{rating} skill {name:?} isn't tracked"),
+                    );
+                    return;
+                }
+            };
+            character.skills.push(Skill {
+                uuid,
+                name,
+                description,
+                r#type: import_pool_type(
+                    item,
+                    "/system/settings/rollButton/pool",
+                    &field,
+                    report,
+                )
+                .into(),
+                level: level.into(),
+            });
+        }
+        "ability" => character.abilities.push(Ability {
+            uuid,
+            name,
+            cost: text(item, "/system/basic/cost"),
+            r#type: import_pool_type(
+                item,
+                "/system/basic/pool",
+                &field,
+                report,
+            )
+            .into(),
+            description,
+            ..Default::default()
+        }),
+        "cypher" => character.cyphers.push(Cypher {
+            uuid,
+            name,
+            level: text(item, "/system/basic/level"),
+            effect: description,
+            active,
+            ..Default::default()
+        }),
+        "artifact" => character.artifacts.push(Artifact {
+            uuid,
+            name,
+            level: text(item, "/system/basic/level"),
+            depletion: text(item, "/system/basic/depletion"),
+            effect: description,
+            active,
+            ..Default::default()
+        }),
+        item_type
+        @ ("equipment" | "armor" | "ammo" | "material" | "oddity") => {
+            let (types, armor) = match item_type {
+                "armor" => (
+                    vec![ItemType::Armor],
+                    Some(int(item, "/system/basic/rating")),
+                ),
+                "ammo" => (vec![ItemType::Ammo], None),
+                "material" => (vec![ItemType::Material], None),
+                "oddity" => (vec![ItemType::Oddity], None),
+                _ => (vec![], None),
+            };
+            character.items.push(Item {
+                path: Some(ItemPath {
+                    inventory: EQUIPMENT.to_owned(),
+                    parent: String::new(),
+                    self_: uuid,
+                }),
+                name,
+                description,
+                types: types.into_iter().map(i32::from).collect(),
+                amount: number(item, "/system/basic/quantity"),
+                armor,
+                ..Default::default()
+            });
+        }
+        item_type => report.add(
+            &field,
+            &format!("{item_type} {name:?} has no counterpart in the app"),
+        ),
+    }
+}
+
+fn import_pool_type(
+    item: &Value,
+    pointer: &str,
+    field: &str,
+    report: &mut Report,
+) -> PoolType {
+    match text(item, pointer).to_lowercase().as_str() {
+        "might" => PoolType::Might,
+        "speed" => PoolType::Speed,
+        "intellect" => PoolType::Intellect,
+        "" | "pool" => PoolType::default(),
+        pool => {
+            report.add(field, &format!("unknown pool {pool}"));
+            PoolType::default()
+        }
+    }
+}
+
+// uuid of the app kept in the flags of value, falling back to the Foundry id
+// and then to fallback.
+fn uuid(value: &Value, fallback: &str) -> String {
+    [format!("/flags/{FLAGS}/uuid"), "/_id".to_owned()]
+        .iter()
+        .map(|pointer| text(value, pointer))
+        .find(|uuid| !uuid.is_empty())
+        .unwrap_or_else(|| fallback.to_owned())
+}
+
+fn text(value: &Value, pointer: &str) -> String {
+    match value.pointer(pointer) {
+        Some(Value::String(text)) => text.to_owned(),
+        Some(Value::Number(number)) => number.to_string(),
+        _ => String::new(),
+    }
+}
+
+// number at pointer, Foundry stores some numbers as strings.
+fn number(value: &Value, pointer: &str) -> f64 {
+    match value.pointer(pointer) {
+        Some(Value::Number(number)) => number.as_f64().unwrap_or_default(),
+        Some(Value::String(text)) => text.trim().parse().unwrap_or_default(),
+        _ => 0.0,
+    }
+}
+
+fn int(value: &Value, pointer: &str) -> i32 {
+    number(value, pointer) as i32
+}
+
+fn flag(value: &Value, pointer: &str) -> bool {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+fn pool_name(pool: PoolType) -> &'static str {
+    match pool {
+        PoolType::Intellect => "Intellect",
+        PoolType::Speed => "Speed",
+        PoolType::Might => "Might",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use cypher_rules::{stat, DamageTrack};
+    use proto_rs::character::{
+        Ability, Advancements, Artifact, Character, Cypher, Damage, Inventory,
+        Item, ItemPath, ItemType, PoolType, Progress, Recovery, Skill,
+        SkillLevel, Stat, Stats,
+    };
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    use super::{from_foundry, import_recovery, to_foundry, Report};
+    use crate::{
+        error::ErrorCode,
+        testing::{character, check_golden_json},
+    };
+
+    fn actor() -> Value {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata/foundry_actor.json");
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    fn fields<T>(conversion: &super::Conversion<T>) -> Vec<&str> {
+        conversion
+            .unmapped
+            .iter()
+            .map(|unmapped| unmapped.field.as_str())
+            .collect()
+    }
+
+    fn item(id: &str, name: &str, types: Vec<ItemType>, amount: f64) -> Item {
+        Item {
+            path: Some(ItemPath {
+                inventory: "equipment".to_owned(),
+                parent: String::new(),
+                self_: id.to_owned(),
+            }),
+            name: name.to_owned(),
+            types: types.into_iter().map(i32::from).collect(),
+            amount,
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn exports_golden_actor() {
+        let actor = to_foundry(&character()).value;
+
+        check_golden_json("foundry_export.json", &actor);
+    }
+
+    #[rstest]
+    fn reports_fields_lost_on_export() {
+        let conversion = to_foundry(&character());
+
+        assert_eq!(
+            fields(&conversion),
+            vec![
+                "progress.total_xp",
+                "money",
+                "notes",
+                "abilities[0].short_description",
+                "abilities[1].enabler",
+                "items[2].path.parent",
+            ]
+        );
+        assert_eq!(
+            conversion.unmapped[1].to_string(),
+            "money: Foundry actors have no money"
+        );
+    }
+
+    #[rstest]
+    fn imports_fixture_actor() {
+        let conversion = from_foundry(&actor()).unwrap();
+
+        assert_eq!(
+            conversion.value,
+            Character {
+                uuid: "kAelZ3vUq8rT1mXc".to_owned(),
+                name: "Kael".to_owned(),
+                descriptor: "Strong".to_owned(),
+                r#type: "Glaive".to_owned(),
+                focus: "Bears a Halo of Fire".to_owned(),
+                progress: Some(Progress {
+                    tier: 2,
+                    free_xp: 3,
+                    max_effort: 1,
+                    advancements: Some(Advancements {
+                        increase_capabilities: true,
+                        move_toward_perfection: true,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                stats: Some(Stats {
+                    might: Some(Stat {
+                        r#type: PoolType::Might.into(),
+                        cap: 16,
+                        pool: 14,
+                        edge: 1,
+                    }),
+                    speed: Some(Stat {
+                        r#type: PoolType::Speed.into(),
+                        cap: 12,
+                        pool: 10,
+                        edge: 1,
+                    }),
+                    intellect: Some(Stat {
+                        r#type: PoolType::Intellect.into(),
+                        cap: 8,
+                        pool: 7,
+                        edge: 0,
+                    }),
+                }),
+                recovery: Some(Recovery {
+                    bonus: 1,
+                    one_action: true,
+                    ten_minutes: true,
+                    ..Default::default()
+                }),
+                damage: Some(Damage {
+                    impaired: true,
+                    debilitated: false,
+                }),
+                cypher_limit: 2,
+                skills: vec![
+                    Skill {
+                        uuid: "sKiLl0000000001a".to_owned(),
+                        name: "Climbing".to_owned(),
+                        description: "<p>Scaling walls and cliffs.</p>"
+                            .to_owned(),
+                        r#type: PoolType::Might.into(),
+                        level: SkillLevel::Trained.into(),
+                    },
+                    Skill {
+                        uuid: "sKiLl0000000002b".to_owned(),
+                        name: "Perception".to_owned(),
+                        description: String::new(),
+                        r#type: PoolType::Intellect.into(),
+                        level: SkillLevel::Specialized.into(),
+                    },
+                ],
+                abilities: vec![Ability {
+                    uuid: "ability-1".to_owned(),
+                    name: "Fleet of Foot".to_owned(),
+                    cost: "2".to_owned(),
+                    r#type: PoolType::Speed.into(),
+                    description:
+                        "<p>Move a short distance and take an action.</p>"
+                            .to_owned(),
+                    ..Default::default()
+                }],
+                cyphers: vec![Cypher {
+                    uuid: "cYpHeR000000001a".to_owned(),
+                    name: "Detonation (Flame)".to_owned(),
+                    level: "1d6+2".to_owned(),
+                    effect: "Explodes in an immediate radius.".to_owned(),
+                    active: true,
+                    ..Default::default()
+                }],
+                artifacts: vec![Artifact {
+                    uuid: "aRtIfAcT0000001a".to_owned(),
+                    name: "Ring of Blinking".to_owned(),
+                    level: "6".to_owned(),
+                    effect: "Teleports the wearer a short distance.".to_owned(),
+                    depletion: "1 in 1d10".to_owned(),
+                    active: false,
+                    ..Default::default()
+                }],
+                inventories: vec![Inventory {
+                    uuid: "equipment".to_owned(),
+                    name: "Equipment".to_owned(),
+                    order: 0,
+                }],
+                items: vec![
+                    Item {
+                        armor: Some(2),
+                        ..item(
+                            "aRmOr00000000001",
+                            "Medium Armor",
+                            vec![ItemType::Armor],
+                            0.0,
+                        )
+                    },
+                    Item {
+                        description: "50 feet of sturdy rope.".to_owned(),
+                        ..item("eQuIpMeNt000001a", "Rope", vec![], 1.0)
+                    },
+                    item(
+                        "aMmO000000000001",
+                        "Arrows",
+                        vec![ItemType::Ammo],
+                        20.0,
+                    ),
+                ],
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            fields(&conversion),
+            vec!["items[2]", "items[9]", "items[10]"]
+        );
+        assert_eq!(
+            conversion.unmapped[0].to_string(),
+            "items[2]: Practiced skill \"Swimming\" isn't tracked"
+        );
+    }
+
+    #[rstest]
+    fn round_trips_imported_actor() {
+        let imported = from_foundry(&actor()).unwrap().value;
+
+        let exported = to_foundry(&imported);
+        let conversion = from_foundry(&exported.value).unwrap();
+
+        assert_eq!(fields(&exported), Vec::<&str>::new());
+        assert_eq!(fields(&conversion), Vec::<&str>::new());
+        assert_eq!(conversion.value, imported);
+    }
+
+    #[rstest]
+    #[case(json!([]))]
+    #[case(json!({ "name": "Kael" }))]
+    #[case(json!({ "name": "Goblin", "type": "npc" }))]
+    fn rejects_invalid_actors(#[case] actor: Value) {
+        assert_eq!(
+            from_foundry(&actor).unwrap_err().code(),
+            ErrorCode::InvalidActor
+        );
+    }
+
+    #[rstest]
+    #[case("Hale", DamageTrack::Hale, false)]
+    #[case("Impaired", DamageTrack::Impaired, false)]
+    #[case("Debilitated", DamageTrack::Debilitated, false)]
+    #[case("Dead", DamageTrack::Dead, false)]
+    #[case("Sleepy", DamageTrack::Hale, true)]
+    fn imports_damage_track(
+        #[case] state: &str,
+        #[case] expected: DamageTrack,
+        #[case] reported: bool,
+    ) {
+        let mut actor = actor();
+        actor["system"]["combat"]["damageTrack"]["state"] = json!(state);
+
+        let conversion = from_foundry(&actor).unwrap();
+
+        assert_eq!(DamageTrack::of(&conversion.value), expected);
+        assert_eq!(
+            fields(&conversion).contains(&"system.combat.damageTrack.state"),
+            reported
+        );
+        let might = stat(&conversion.value, PoolType::Might).pool;
+        assert_eq!(might == 0, expected == DamageTrack::Dead);
+    }
+
+    #[rstest]
+    fn reports_actors_without_id() {
+        let mut actor = actor();
+        actor.as_object_mut().unwrap().remove("_id");
+
+        let conversion = from_foundry(&actor).unwrap();
+
+        assert_eq!(conversion.value.uuid, "");
+        assert_eq!(fields(&conversion)[0], "_id");
+    }
+
+    #[rstest]
+    #[case("1d6+5", 3, false)]
+    #[case("1d6+2", 0, false)]
+    #[case("1d6", 0, false)]
+    #[case("", 0, false)]
+    #[case("2d6+2", 0, true)]
+    #[case("lots", 0, true)]
+    fn imports_recovery_bonus(
+        #[case] roll: &str,
+        #[case] bonus: i32,
+        #[case] reported: bool,
+    ) {
+        let actor = json!({ "system": { "combat": { "recoveries": {
+            "roll": roll,
+        } } } });
+        let mut report = Report::default();
+
+        assert_eq!(import_recovery(&actor, 2, &mut report).bonus, bonus);
+        assert_eq!(!report.unmapped.is_empty(), reported);
+    }
+
+    #[rstest]
+    fn saturates_extreme_recovery_rolls() {
+        let mut actor = actor();
+        actor["system"]["basic"]["tier"] = json!(i64::MIN);
+        let mut character = from_foundry(&actor).unwrap().value;
+        assert_eq!(character.recovery.as_ref().unwrap().bonus, i32::MAX);
+
+        character.progress.as_mut().unwrap().tier = i32::MAX;
+        let exported = to_foundry(&character).value;
+
+        assert_eq!(
+            exported["system"]["combat"]["recoveries"]["roll"],
+            json!(format!("1d6+{}", i32::MAX))
+        );
+    }
+}
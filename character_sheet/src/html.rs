@@ -0,0 +1,142 @@
+use proto_rs::character::Character;
+
+use crate::sheet::{Block, Entry, Sheet};
+
+const STYLE: &str = "\
+body { font-family: sans-serif; max-width: 50em; margin: 2em auto; }
+table { border-collapse: collapse; margin-bottom: 1em; }
+th, td { border: 1px solid #999; padding: 0.25em 0.5em; text-align: left; }
+p.summary { font-style: italic; }";
+
+// to_html renders character as a standalone HTML document.
+pub fn to_html(character: &Character) -> String {
+    render(&Sheet::new(character))
+}
+
+fn render(sheet: &Sheet) -> String {
+    let title = escape(&sheet.title);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n\
+         <meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>\n{STYLE}\n</style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+    );
+    if !sheet.summary.is_empty() {
+        html +=
+            &format!("<p class=\"summary\">{}</p>\n", escape(&sheet.summary));
+    }
+    for section in &sheet.sections {
+        html += &format!("<section>\n<h2>{}</h2>\n", escape(&section.title));
+        for block in &section.blocks {
+            html += &self::block(block);
+        }
+        html += "</section>\n";
+    }
+    html + "</body>\n</html>\n"
+}
+
+fn block(block: &Block) -> String {
+    match block {
+        Block::Heading(title) => format!("<h3>{}</h3>\n", escape(title)),
+        Block::Table { header, rows } => {
+            let mut html = format!(
+                "<table>\n<thead>\n{}</thead>\n<tbody>\n",
+                row("th", header)
+            );
+            for cells in rows {
+                html += &row("td", cells);
+            }
+            html + "</tbody>\n</table>\n"
+        }
+        Block::List(entries) => list(entries),
+    }
+}
+
+fn row(tag: &str, cells: &[String]) -> String {
+    let cells: String = cells
+        .iter()
+        .map(|cell| format!("<{tag}>{}</{tag}>", escape(cell)))
+        .collect();
+    format!("<tr>{cells}</tr>\n")
+}
+
+fn list(entries: &[Entry]) -> String {
+    let mut html = "<ul>\n".to_owned();
+    for entry in entries {
+        html += "<li>";
+        html += &match (entry.title.is_empty(), entry.detail.is_empty()) {
+            (false, false) => format!(
+                "<strong>{}</strong>: {}",
+                escape(&entry.title),
+                escape(&entry.detail)
+            ),
+            (false, true) => {
+                format!("<strong>{}</strong>", escape(&entry.title))
+            }
+            (true, _) => escape(&entry.detail),
+        };
+        for paragraph in entry.body.split("\n\n") {
+            if !paragraph.trim().is_empty() {
+                html += &format!(
+                    "<p>{}</p>",
+                    escape(paragraph.trim()).replace('\n', "<br>")
+                );
+            }
+        }
+        if !entry.children.is_empty() {
+            html += "\n";
+            html += &list(&entry.children);
+        }
+        html += "</li>\n";
+    }
+    html + "</ul>\n"
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use proto_rs::character::Character;
+    use rstest::rstest;
+
+    use super::{escape, to_html};
+    use crate::testing::{character, check_golden};
+
+    #[rstest]
+    fn renders_golden_sheet() {
+        check_golden("sheet.html", &to_html(&character()));
+    }
+
+    #[rstest]
+    #[case("plain text", "plain text")]
+    #[case("<script>", "&lt;script&gt;")]
+    #[case("Tom & \"Jerry's\"", "Tom &amp; &quot;Jerry&#39;s&quot;")]
+    fn escapes_text(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(escape(text), expected);
+    }
+
+    #[rstest]
+    fn escapes_character_name() {
+        let character = Character {
+            name: "<b>Aria</b>".to_owned(),
+            ..Default::default()
+        };
+
+        let html = to_html(&character);
+
+        assert!(html.contains("<title>&lt;b&gt;Aria&lt;/b&gt;</title>"));
+        assert!(!html.contains("<b>"));
+    }
+}
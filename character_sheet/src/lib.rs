@@ -0,0 +1,17 @@
+// Printable character sheets rendered from the app's characters.
+
+mod error;
+mod foundry;
+mod html;
+mod markdown;
+mod pdf;
+mod sheet;
+#[cfg(test)]
+mod testing;
+
+pub use error::{Error, ErrorCode};
+pub use foundry::{from_foundry, to_foundry, Conversion, Unmapped};
+pub use html::to_html;
+pub use markdown::to_markdown;
+pub use pdf::to_pdf;
+pub use sheet::{Block, Entry, Section, Sheet};
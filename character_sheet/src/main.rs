@@ -0,0 +1,147 @@
+use std::{error::Error, fs, io::Write, path::PathBuf};
+
+use character_sheet::{
+    from_foundry, to_foundry, to_html, to_markdown, to_pdf, Unmapped,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use prost::Message;
+use proto_rs::character::Character;
+
+#[derive(Parser)]
+#[command(about = "Export characters of the cypher_sheet app")]
+struct Options {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Render a character as a printable sheet.
+    Export {
+        /// Character to export, protobuf encoded or JSON if the file name
+        /// ends in .json.
+        input: PathBuf,
+
+        #[arg(value_enum, short, long, default_value_t = Format::Markdown)]
+        format: Format,
+
+        /// File to write the sheet to, stdout if not set.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Convert between characters and Foundry VTT Cypher System actors.
+    Foundry {
+        #[command(subcommand)]
+        command: FoundryCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum FoundryCommands {
+    /// Convert a Foundry actor JSON file to a character.
+    Import {
+        /// Actor exported from Foundry.
+        input: PathBuf,
+
+        /// File to write the character to, protobuf encoded or JSON if the
+        /// file name ends in .json. JSON on stdout if not set.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Convert a character to a Foundry actor JSON file.
+    Export {
+        /// Character to convert, protobuf encoded or JSON if the file name
+        /// ends in .json.
+        input: PathBuf,
+
+        /// File to write the actor to, stdout if not set.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Markdown,
+    Html,
+    Pdf,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let options = Options::parse();
+
+    match options.command {
+        Commands::Export {
+            input,
+            format,
+            output,
+        } => {
+            let character = read_character(&input)?;
+            let sheet = match format {
+                Format::Markdown => to_markdown(&character).into_bytes(),
+                Format::Html => to_html(&character).into_bytes(),
+                Format::Pdf => to_pdf(&character),
+            };
+            write_output(output, &sheet)
+        }
+        Commands::Foundry {
+            command: FoundryCommands::Import { input, output },
+        } => {
+            let json = fs::read_to_string(&input)
+                .map_err(|err| format!("reading {}: {err}", input.display()))?;
+            let actor = serde_json::from_str(&json)
+                .map_err(|err| format!("parsing {}: {err}", input.display()))?;
+            let conversion = from_foundry(&actor)?;
+            report(&conversion.unmapped);
+            let character = &conversion.value;
+            let bytes = match &output {
+                Some(path)
+                    if path.extension().is_none_or(|ext| ext != "json") =>
+                {
+                    character.encode_to_vec()
+                }
+                _ => proto_rs::json::to_string(character)?.into_bytes(),
+            };
+            write_output(output, &bytes)
+        }
+        Commands::Foundry {
+            command: FoundryCommands::Export { input, output },
+        } => {
+            let conversion = to_foundry(&read_character(&input)?);
+            report(&conversion.unmapped);
+            let json = serde_json::to_string_pretty(&conversion.value)?;
+            write_output(output, json.as_bytes())
+        }
+    }
+}
+
+// report prints the fields lost in a conversion to stderr.
+fn report(unmapped: &[Unmapped]) {
+    for unmapped in unmapped {
+        eprintln!("not converted: {unmapped}");
+    }
+}
+
+// read_character decodes the character in path, as JSON if path ends in .json
+// and protobuf encoded otherwise.
+fn read_character(path: &PathBuf) -> Result<Character, Box<dyn Error>> {
+    let bytes = fs::read(path)
+        .map_err(|err| format!("reading {}: {err}", path.display()))?;
+    let character = match path.extension().is_some_and(|ext| ext == "json") {
+        true => proto_rs::json::from_str(std::str::from_utf8(&bytes)?)?,
+        false => Character::decode(bytes.as_slice())?,
+    };
+    Ok(character)
+}
+
+fn write_output(
+    output: Option<PathBuf>,
+    bytes: &[u8],
+) -> Result<(), Box<dyn Error>> {
+    match output {
+        Some(path) => fs::write(&path, bytes)
+            .map_err(|err| format!("writing {}: {err}", path.display()))?,
+        None => std::io::stdout().write_all(bytes)?,
+    }
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use proto_rs::character::Character;
+
+use crate::sheet::{Block, Entry, Sheet};
+
+// to_markdown renders character as a Markdown document.
+pub fn to_markdown(character: &Character) -> String {
+    render(&Sheet::new(character))
+}
+
+fn render(sheet: &Sheet) -> String {
+    let mut blocks = vec![format!("# {}", inline(&sheet.title))];
+    if !sheet.summary.is_empty() {
+        blocks.push(inline(&sheet.summary));
+    }
+    for section in &sheet.sections {
+        blocks.push(format!("## {}", inline(&section.title)));
+        blocks.extend(section.blocks.iter().map(block));
+    }
+    blocks.join("\n\n") + "\n"
+}
+
+fn block(block: &Block) -> String {
+    match block {
+        Block::Heading(title) => format!("### {}", inline(title)),
+        Block::Table { header, rows } => {
+            let mut lines =
+                vec![row(header), row(&vec!["---".to_owned(); header.len()])];
+            lines.extend(rows.iter().map(|cells| row(cells)));
+            lines.join("\n")
+        }
+        Block::List(entries) => {
+            let mut lines = vec![];
+            for entry in entries {
+                list_entry(entry, 0, &mut lines);
+            }
+            while lines.last().is_some_and(String::is_empty) {
+                lines.pop();
+            }
+            lines.join("\n")
+        }
+    }
+}
+
+fn row(cells: &[String]) -> String {
+    let cells: Vec<String> = cells.iter().map(|cell| inline(cell)).collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+fn list_entry(entry: &Entry, depth: usize, lines: &mut Vec<String>) {
+    let indent = "  ".repeat(depth);
+    let title = match (entry.title.is_empty(), entry.detail.is_empty()) {
+        (false, false) => {
+            format!("**{}**: {}", inline(&entry.title), inline(&entry.detail))
+        }
+        (false, true) => format!("**{}**", inline(&entry.title)),
+        (true, _) => inline(&entry.detail),
+    };
+    lines.push(format!("{indent}- {title}"));
+
+    if !entry.body.is_empty() {
+        lines.push(String::new());
+        lines.extend(entry.body.lines().map(|line| match line.is_empty() {
+            false => format!("{indent}  {}", block_line(line)),
+            true => String::new(),
+        }));
+        lines.push(String::new());
+    }
+    for child in &entry.children {
+        list_entry(child, depth + 1, lines);
+    }
+}
+
+// inline escapes text for use on a single line, e.g. in a heading or table.
+fn inline(text: &str) -> String {
+    escape(&text.split_whitespace().collect::<Vec<&str>>().join(" "))
+}
+
+// block_line escapes a line of a text block, so it can't start a heading,
+// list, quote or code block. Leading whitespace is dropped for the same reason.
+fn block_line(line: &str) -> String {
+    let line = line.trim_start();
+    let escaped = escape(line);
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    match line.chars().next() {
+        Some('#' | '-' | '+' | '=' | '~') => format!("\\{escaped}"),
+        // Digits are never escaped, so they end at the same index.
+        _ if digits > 0
+            && matches!(line[digits..].chars().next(), Some('.' | ')')) =>
+        {
+            format!("{}\\{}", &escaped[..digits], &escaped[digits..])
+        }
+        _ => escaped,
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '|' | '&'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use proto_rs::character::{Character, Note};
+    use rstest::rstest;
+
+    use super::{block_line, inline, to_markdown};
+    use crate::testing::{character, check_golden};
+
+    #[rstest]
+    fn renders_golden_sheet() {
+        check_golden("sheet.md", &to_markdown(&character()));
+    }
+
+    #[rstest]
+    #[case("plain text", "plain text")]
+    #[case("a | b", "a \\| b")]
+    #[case("*bold* and _em_", "\\*bold\\* and \\_em\\_")]
+    #[case("two\nlines", "two lines")]
+    #[case("Tom &amp; Jerry", "Tom \\&amp; Jerry")]
+    fn escapes_inline_text(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(inline(text), expected);
+    }
+
+    #[rstest]
+    #[case("# Qi", "\\# Qi")]
+    #[case("- market", "\\- market")]
+    #[case("+ market", "\\+ market")]
+    #[case("12. market", "12\\. market")]
+    #[case("1) market", "1\\) market")]
+    #[case("    code", "code")]
+    #[case("~~~", "\\~~~")]
+    #[case("2 markets", "2 markets")]
+    fn escapes_block_markers(#[case] line: &str, #[case] expected: &str) {
+        assert_eq!(block_line(line), expected);
+    }
+
+    #[rstest]
+    fn indents_note_text_below_entry() {
+        let character = Character {
+            name: "Aria".to_owned(),
+            notes: vec![Note {
+                title: "Qi".to_owned(),
+                text: "A city\n\nin the Steadfast.".to_owned(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(to_markdown(&character)
+            .contains("- **Qi**\n\n  A city\n\n  in the Steadfast.\n"));
+    }
+}
@@ -0,0 +1,186 @@
+// Metrics and encoding of the standard Helvetica fonts every PDF reader
+// provides, so no font needs to be embedded.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    // name of the font resource in the page resources.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+
+    pub fn base_font(&self) -> &'static str {
+        match self {
+            Font::Regular => "Helvetica",
+            Font::Bold => "Helvetica-Bold",
+        }
+    }
+}
+
+// Widths of the printable ASCII characters from ' ' to '~' in thousandths of
+// the font size, taken from the Adobe font metrics.
+const REGULAR_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278,
+    278, 556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584,
+    584, 556, 1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556,
+    833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278,
+    278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222,
+    500, 222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500,
+    500, 334, 260, 334, 584,
+];
+
+const BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278,
+    278, 556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584,
+    584, 611, 975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611,
+    833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333,
+    278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278,
+    556, 278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556,
+    500, 389, 280, 389, 584,
+];
+
+// encode c in WinAnsiEncoding, characters it can't encode become '?'.
+pub fn encode(c: char) -> u8 {
+    match c {
+        ' '..='~' | '\u{a0}'..='\u{ff}' => c as u8,
+        '–' => 0x96,
+        '—' => 0x97,
+        '•' => 0x95,
+        '‘' => 0x91,
+        '’' => 0x92,
+        '“' => 0x93,
+        '”' => 0x94,
+        '…' => 0x85,
+        _ => b'?',
+    }
+}
+
+// width of c in thousandths of the font size. Characters beyond ASCII use an
+// average width.
+fn width(font: Font, c: char) -> u16 {
+    let widths = match font {
+        Font::Regular => &REGULAR_WIDTHS,
+        Font::Bold => &BOLD_WIDTHS,
+    };
+    match encode(c) {
+        byte @ b' '..=b'~' => widths[usize::from(byte - b' ')],
+        0x95 => 350,
+        0x97 | 0x85 => 1000,
+        _ => 556,
+    }
+}
+
+// text_width of text set in font at size points.
+pub fn text_width(font: Font, size: f32, text: &str) -> f32 {
+    let width: u32 = text.chars().map(|c| u32::from(width(font, c))).sum();
+    width as f32 * size / 1000.0
+}
+
+// wrap text into lines of at most max_width points, the first line having
+// only first_width points. Words longer than a line are broken up.
+pub fn wrap(
+    font: Font,
+    size: f32,
+    text: &str,
+    first_width: f32,
+    max_width: f32,
+) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+    let limit = |lines: &Vec<String>| match lines.is_empty() {
+        true => first_width,
+        false => max_width,
+    };
+
+    for word in text.split_whitespace() {
+        let candidate = match line.is_empty() {
+            true => word.to_owned(),
+            false => format!("{line} {word}"),
+        };
+        if text_width(font, size, &candidate) <= limit(&lines) {
+            line = candidate;
+            continue;
+        }
+        // Move word to the next line, it's only broken up if it doesn't fit
+        // there either. A word missing only the shorter first line leaves it
+        // empty.
+        if !line.is_empty() || lines.is_empty() {
+            lines.push(std::mem::take(&mut line));
+            if text_width(font, size, word) <= limit(&lines) {
+                line = word.to_owned();
+                continue;
+            }
+            if lines == [""] {
+                lines.clear();
+            }
+        }
+        for c in word.chars() {
+            line.push(c);
+            if text_width(font, size, &line) > limit(&lines)
+                && line.chars().count() > 1
+            {
+                line.pop();
+                lines.push(std::mem::replace(&mut line, c.to_string()));
+            }
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::{encode, text_width, wrap, Font};
+
+    #[rstest]
+    #[case('a', b'a')]
+    #[case('ü', 0xfc)]
+    #[case('×', 0xd7)]
+    #[case('–', 0x96)]
+    #[case('漢', b'?')]
+    fn encodes_win_ansi(#[case] c: char, #[case] expected: u8) {
+        assert_eq!(encode(c), expected);
+    }
+
+    #[rstest]
+    fn measures_text() {
+        assert_eq!(text_width(Font::Regular, 10.0, "Hi"), 9.44);
+        assert_eq!(text_width(Font::Bold, 10.0, "Hi"), 10.0);
+    }
+
+    #[rstest]
+    #[case::fits("a few words", 100.0, &["a few words"])]
+    #[case::wraps("a few words", 30.0, &["a few", "words"])]
+    #[case::breaks_long_words("aaaaaaaaaa", 20.0, &["aaa", "aaa", "aaa", "a"])]
+    #[case::empty("", 20.0, &[""])]
+    fn wraps_text(
+        #[case] text: &str,
+        #[case] width: f32,
+        #[case] expected: &[&str],
+    ) {
+        assert_eq!(wrap(Font::Regular, 10.0, text, width, width), expected);
+    }
+
+    #[rstest]
+    fn wraps_shorter_first_line() {
+        assert_eq!(
+            wrap(Font::Regular, 10.0, "a few words", 15.0, 100.0),
+            vec!["a", "few words"]
+        );
+        assert_eq!(
+            wrap(Font::Regular, 10.0, "words", 15.0, 100.0),
+            vec!["", "words"]
+        );
+    }
+}
@@ -0,0 +1,527 @@
+// PDF character sheets laid out like the Cypher System sheets: the stat pools,
+// damage track, recovery and advancements as boxes on top, followed by the
+// remaining sections of the Sheet flowing over as many pages as they need.
+
+mod font;
+mod writer;
+
+use cypher_rules::{stat, POOLS};
+use proto_rs::character::{Character, PoolType};
+
+use self::{
+    font::{text_width, wrap, Font},
+    writer::{write, Page, PAGE_WIDTH},
+};
+use crate::sheet::{Block, Entry, Section, Sheet, CHARACTER, STATS};
+
+const MARGIN: f32 = 40.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+// TOP and BOTTOM of the content of a page, the footer goes below BOTTOM.
+const TOP: f32 = 802.0;
+const BOTTOM: f32 = 56.0;
+const FOOTER: f32 = 30.0;
+const TEXT_SIZE: f32 = 10.0;
+const TABLE_SIZE: f32 = 9.5;
+// COLUMN_GAP between the boxes of the header.
+const COLUMN_GAP: f32 = 12.0;
+const INDENT: f32 = 14.0;
+
+// to_pdf renders character as a PDF document, the same character always
+// gives the same bytes.
+pub fn to_pdf(character: &Character) -> Vec<u8> {
+    let sheet = Sheet::new(character);
+    let mut layout = Layout::new();
+
+    layout.header(character, &sheet);
+    for section in &sheet.sections {
+        if section.title != CHARACTER && section.title != STATS {
+            layout.section(section);
+        }
+    }
+    layout.finish(&sheet.title)
+}
+
+// Layout places content from the top of a page down, starting a new page
+// once it reaches the bottom.
+struct Layout {
+    pages: Vec<Page>,
+    // y of the top of the next content.
+    y: f32,
+}
+
+fn leading(size: f32) -> f32 {
+    size * 1.4
+}
+
+impl Layout {
+    fn new() -> Layout {
+        Layout {
+            pages: vec![Page::default()],
+            y: TOP,
+        }
+    }
+
+    fn page(&mut self) -> &mut Page {
+        self.pages.last_mut().expect("layout has a page")
+    }
+
+    // space starts a new page unless height fits on the current one.
+    fn space(&mut self, height: f32) {
+        if self.y - height < BOTTOM && self.y < TOP {
+            self.pages.push(Page::default());
+            self.y = TOP;
+        }
+    }
+
+    // line places a single line of text at x.
+    fn line(&mut self, x: f32, font: Font, size: f32, text: &str) {
+        self.space(leading(size));
+        let baseline = self.y - size;
+        self.page().text(x, baseline, font, size, text);
+        self.y -= leading(size);
+    }
+
+    // paragraph places text wrapped to width, breaking pages between lines.
+    fn paragraph(&mut self, x: f32, width: f32, font: Font, text: &str) {
+        for line in wrap(font, TEXT_SIZE, text, width, width) {
+            self.line(x, font, TEXT_SIZE, &line);
+        }
+    }
+
+    fn header(&mut self, character: &Character, sheet: &Sheet) {
+        self.line(MARGIN, Font::Bold, 22.0, &sheet.title);
+        if !sheet.summary.is_empty() {
+            self.line(MARGIN, Font::Regular, 12.0, &sheet.summary);
+        }
+        let progress = character.progress.clone().unwrap_or_default();
+        let facts = [
+            format!("Tier {}", progress.tier),
+            format!("Effort {}", progress.max_effort),
+            format!("XP {} ({} total)", progress.free_xp, progress.total_xp),
+            format!("Cypher limit {}", character.cypher_limit),
+            format!("Money {}", character.money),
+        ];
+        self.line(MARGIN, Font::Regular, TEXT_SIZE, &facts.join("    "));
+        self.y -= 8.0;
+        self.pools(character);
+        self.y -= 16.0;
+        self.checklists(character);
+        self.y -= 8.0;
+    }
+
+    // pools draws a box for each stat pool with its cap and edge.
+    fn pools(&mut self, character: &Character) {
+        let width = (CONTENT_WIDTH - 2.0 * COLUMN_GAP) / 3.0;
+        let height = 64.0;
+        let top = self.y;
+        for (column, pool) in POOLS.into_iter().enumerate() {
+            let stat = stat(character, pool);
+            let x = MARGIN + column as f32 * (width + COLUMN_GAP);
+            let page = self.page();
+            page.rect(x, top - height, width, height, false);
+            page.text(x + 8.0, top - 16.0, Font::Bold, 11.0, pool_name(pool));
+            page.text(
+                x + 8.0,
+                top - 50.0,
+                Font::Bold,
+                26.0,
+                &stat.pool.to_string(),
+            );
+            let details = x + width / 2.0;
+            page.text(
+                details,
+                top - 34.0,
+                Font::Regular,
+                TEXT_SIZE,
+                &format!("Cap {}", stat.cap),
+            );
+            page.text(
+                details,
+                top - 50.0,
+                Font::Regular,
+                TEXT_SIZE,
+                &format!("Edge {}", stat.edge),
+            );
+        }
+        self.y -= height;
+    }
+
+    // checklists draws the damage track, recovery rolls and advancements as
+    // checkboxes next to each other.
+    fn checklists(&mut self, character: &Character) {
+        let damage = character.damage.clone().unwrap_or_default();
+        let recovery = character.recovery.clone().unwrap_or_default();
+        let advancements = character
+            .progress
+            .clone()
+            .unwrap_or_default()
+            .advancements
+            .unwrap_or_default();
+        let tier = character.progress.as_ref().map_or(0, |p| p.tier);
+
+        let lists = [
+            (
+                "Damage Track".to_owned(),
+                vec![
+                    ("Impaired", damage.impaired),
+                    ("Debilitated", damage.debilitated),
+                ],
+            ),
+            (
+                format!(
+                    "Recovery 1d6 + {}",
+                    tier.saturating_add(recovery.bonus)
+                ),
+                vec![
+                    ("One action", recovery.one_action),
+                    ("Ten minutes", recovery.ten_minutes),
+                    ("One hour", recovery.one_hour),
+                    ("Ten hours", recovery.ten_hours),
+                ],
+            ),
+            (
+                "Advancements".to_owned(),
+                vec![
+                    (
+                        "Increase capabilities",
+                        advancements.increase_capabilities,
+                    ),
+                    (
+                        "Move toward perfection",
+                        advancements.move_toward_perfection,
+                    ),
+                    ("Extra effort", advancements.extra_effort),
+                    ("Skill training", advancements.skill_training),
+                    ("Other", advancements.other),
+                ],
+            ),
+        ];
+
+        let width = (CONTENT_WIDTH - 2.0 * COLUMN_GAP) / 3.0;
+        let row = leading(TEXT_SIZE);
+        let top = self.y;
+        let mut bottom = top;
+        for (column, (title, boxes)) in lists.iter().enumerate() {
+            let x = MARGIN + column as f32 * (width + COLUMN_GAP);
+            let page = self.page();
+            page.text(x, top - 11.0, Font::Bold, 11.0, title);
+            let mut y = top - 11.0 - row;
+            for (label, checked) in boxes {
+                page.rect(x, y - 1.0, 8.0, 8.0, *checked);
+                page.text(x + 13.0, y, Font::Regular, TEXT_SIZE, label);
+                y -= row;
+            }
+            bottom = bottom.min(y);
+        }
+        self.y = bottom;
+    }
+
+    fn section(&mut self, section: &Section) {
+        // Keep the title together with the first lines of the section.
+        self.space(leading(14.0) + 3.0 * leading(TEXT_SIZE));
+        self.y -= 8.0;
+        self.line(MARGIN, Font::Bold, 14.0, &section.title);
+        let rule = self.y + 3.0;
+        self.page()
+            .line(MARGIN, rule, MARGIN + CONTENT_WIDTH, rule, 0.75);
+        self.y -= 4.0;
+
+        for block in &section.blocks {
+            match block {
+                Block::Heading(title) => {
+                    self.space(leading(11.0) + leading(TEXT_SIZE));
+                    self.y -= 4.0;
+                    self.line(MARGIN, Font::Bold, 11.0, title);
+                }
+                Block::Table { header, rows } => self.table(header, rows),
+                Block::List(entries) => {
+                    for entry in entries {
+                        self.entry(entry, 0.0);
+                    }
+                }
+            }
+        }
+    }
+
+    // table places rows of wrapped cells, the first column being narrow and
+    // the last one taking the remaining width.
+    fn table(&mut self, header: &[String], rows: &[Vec<String>]) {
+        let widths = column_widths(header.len());
+        self.row(header, &widths, Font::Bold);
+        let rule = self.y + 2.0;
+        self.page()
+            .line(MARGIN, rule, MARGIN + CONTENT_WIDTH, rule, 0.5);
+        for row in rows {
+            self.row(row, &widths, Font::Regular);
+        }
+        self.y -= 6.0;
+    }
+
+    fn row(&mut self, cells: &[String], widths: &[f32], font: Font) {
+        let padding = 4.0;
+        let cells: Vec<Vec<String>> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| {
+                let width = width - 2.0 * padding;
+                wrap(font, TABLE_SIZE, cell, width, width)
+            })
+            .collect();
+        let lines = cells.iter().map(Vec::len).max().unwrap_or(1);
+
+        // A row taller than a page goes line by line across all the
+        // columns, so its page breaks fall between lines.
+        self.space(lines as f32 * leading(TABLE_SIZE));
+        for index in 0..lines {
+            self.space(leading(TABLE_SIZE));
+            let baseline = self.y - TABLE_SIZE;
+            let mut x = MARGIN;
+            for (cell, width) in cells.iter().zip(widths) {
+                if let Some(line) = cell.get(index) {
+                    self.page().text(
+                        x + padding,
+                        baseline,
+                        font,
+                        TABLE_SIZE,
+                        line,
+                    );
+                }
+                x += width;
+            }
+            self.y -= leading(TABLE_SIZE);
+        }
+    }
+
+    // entry places a bulleted list entry with its title in bold, followed by
+    // its body and children.
+    fn entry(&mut self, entry: &Entry, indent: f32) {
+        let x = MARGIN + indent + 10.0;
+        let width = CONTENT_WIDTH - indent - 10.0;
+        let title = match entry.title.is_empty() || entry.detail.is_empty() {
+            true => entry.title.to_owned(),
+            false => format!("{}:", entry.title),
+        };
+
+        let mut lines: Vec<(String, String)> = vec![];
+        let title_lines = wrap(Font::Bold, TEXT_SIZE, &title, width, width);
+        let last = title_lines.last().cloned().unwrap_or_default();
+        lines.extend(
+            title_lines[..title_lines.len() - 1]
+                .iter()
+                .map(|line| (line.to_owned(), String::new())),
+        );
+        let offset = match last.is_empty() {
+            true => 0.0,
+            false => text_width(Font::Bold, TEXT_SIZE, &last) + 3.0,
+        };
+        let detail = wrap(
+            Font::Regular,
+            TEXT_SIZE,
+            &entry.detail,
+            width - offset,
+            width,
+        );
+        lines.push((last, detail[0].to_owned()));
+        lines.extend(
+            detail[1..]
+                .iter()
+                .map(|line| (String::new(), line.to_owned())),
+        );
+
+        for (index, (bold, regular)) in lines.iter().enumerate() {
+            self.space(leading(TEXT_SIZE));
+            let baseline = self.y - TEXT_SIZE;
+            let page = self.page();
+            if index == 0 {
+                page.text(x - 10.0, baseline, Font::Regular, TEXT_SIZE, "•");
+            }
+            page.text(x, baseline, Font::Bold, TEXT_SIZE, bold);
+            let offset = match bold.is_empty() {
+                true => 0.0,
+                false => offset,
+            };
+            page.text(x + offset, baseline, Font::Regular, TEXT_SIZE, regular);
+            self.y -= leading(TEXT_SIZE);
+        }
+
+        if !entry.body.is_empty() {
+            self.y -= 2.0;
+            for line in entry.body.lines() {
+                match line.trim().is_empty() {
+                    true => self.y -= leading(TEXT_SIZE) / 2.0,
+                    false => self.paragraph(x, width, Font::Regular, line),
+                }
+            }
+            self.y -= 2.0;
+        }
+        for child in &entry.children {
+            self.entry(child, indent + INDENT);
+        }
+    }
+
+    // finish adds the page numbers and writes the document.
+    fn finish(mut self, title: &str) -> Vec<u8> {
+        let count = self.pages.len();
+        for (index, page) in self.pages.iter_mut().enumerate() {
+            let footer = format!("{title} – page {} of {count}", index + 1);
+            let width = text_width(Font::Regular, 8.0, &footer);
+            page.text(
+                (PAGE_WIDTH - width) / 2.0,
+                FOOTER,
+                Font::Regular,
+                8.0,
+                &footer,
+            );
+        }
+        write(title, &self.pages)
+    }
+}
+
+fn column_widths(columns: usize) -> Vec<f32> {
+    match columns {
+        0 => vec![],
+        1 => vec![CONTENT_WIDTH],
+        columns => {
+            let first = CONTENT_WIDTH * 0.28;
+            let middle = CONTENT_WIDTH * 0.16;
+            let last = CONTENT_WIDTH - first - middle * (columns - 2) as f32;
+            let mut widths = vec![first];
+            widths.extend(vec![middle; columns - 2]);
+            widths.push(last);
+            widths
+        }
+    }
+}
+
+fn pool_name(pool: PoolType) -> &'static str {
+    match pool {
+        PoolType::Intellect => "Intellect",
+        PoolType::Speed => "Speed",
+        PoolType::Might => "Might",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proto_rs::character::{Ability, Character, Note, Progress, Recovery};
+    use rstest::rstest;
+
+    use super::{column_widths, to_pdf, CONTENT_WIDTH};
+    use crate::testing::{character, check_golden};
+
+    fn page_count(pdf: &[u8]) -> usize {
+        let pdf = String::from_utf8(pdf.to_vec()).expect("pdf should be ascii");
+        pdf.matches("/Type /Page ").count()
+    }
+
+    #[rstest]
+    fn renders_golden_sheet() {
+        let pdf = String::from_utf8(to_pdf(&character()))
+            .expect("pdf should be ascii");
+
+        check_golden("sheet.pdf", &pdf);
+    }
+
+    #[rstest]
+    fn renders_deterministically() {
+        assert_eq!(to_pdf(&character()), to_pdf(&character()));
+    }
+
+    #[rstest]
+    fn fits_short_sheet_on_a_page() {
+        let character = Character {
+            name: "Aria".to_owned(),
+            ..Default::default()
+        };
+
+        assert_eq!(page_count(&to_pdf(&character)), 1);
+    }
+
+    #[rstest]
+    fn saturates_recovery_roll() {
+        let character = Character {
+            name: "Aria".to_owned(),
+            progress: Some(Progress {
+                tier: i32::MAX,
+                ..Default::default()
+            }),
+            recovery: Some(Recovery {
+                bonus: i32::MAX,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let pdf = String::from_utf8(to_pdf(&character)).unwrap();
+
+        assert!(pdf.contains(&format!("(Recovery 1d6 + {})", i32::MAX)));
+    }
+
+    #[rstest]
+    fn paginates_long_notes() {
+        let text = vec!["A long day in the Steadfast."; 200].join("\n");
+        let character = Character {
+            name: "Aria".to_owned(),
+            notes: vec![Note {
+                title: "Journal".to_owned(),
+                text,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let pdf = to_pdf(&character);
+
+        let pages = page_count(&pdf);
+        assert!(pages > 1, "{pages} pages");
+        let pdf = String::from_utf8(pdf).unwrap();
+        let footer = format!("(Aria \\226 page {pages} of {pages})");
+        assert!(pdf.contains(&footer), "{footer}");
+    }
+
+    #[rstest]
+    fn splits_rows_longer_than_a_page() {
+        let name = (0..1000)
+            .map(|word| format!("word{word}"))
+            .collect::<Vec<String>>()
+            .join(" ");
+        let character = Character {
+            name: "Aria".to_owned(),
+            abilities: vec![Ability {
+                name,
+                enabler: true,
+                description: "Move a short distance.".to_owned(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let pdf = String::from_utf8(to_pdf(&character)).unwrap();
+
+        let pages: Vec<&str> =
+            pdf.split("stream\n").skip(1).step_by(2).collect();
+        assert!(pages.len() > 2, "{} pages", pages.len());
+        let page = |text: &str| {
+            pages
+                .iter()
+                .position(|page| page.contains(text))
+                .unwrap_or_else(|| panic!("{text} not found"))
+        };
+        assert_eq!(page("(Enabler)"), page("(word0 "));
+        assert_eq!(page("(Move a short distance.)"), page("(word0 "));
+        assert!(page(" word999)") > page("(word0 "));
+    }
+
+    #[rstest]
+    #[case(1, vec![CONTENT_WIDTH])]
+    #[case(3, vec![144.2, 82.4, 288.4])]
+    fn splits_columns(#[case] columns: usize, #[case] expected: Vec<f32>) {
+        let widths = column_widths(columns);
+
+        assert_eq!(widths.len(), expected.len());
+        for (width, expected) in widths.iter().zip(expected) {
+            assert!((width - expected).abs() < 0.01, "{width} != {expected}");
+        }
+        assert!((widths.iter().sum::<f32>() - CONTENT_WIDTH).abs() < 0.01);
+    }
+}
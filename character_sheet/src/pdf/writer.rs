@@ -0,0 +1,217 @@
+// Minimal PDF 1.4 writer for pages of text, lines and rectangles. Everything
+// written depends only on the pages, there are no timestamps or random
+// document IDs, so the same pages always give the same bytes.
+
+use super::font::{encode, Font};
+
+// A4 page size in points.
+pub const PAGE_WIDTH: f32 = 595.0;
+pub const PAGE_HEIGHT: f32 = 842.0;
+
+const FONTS: [Font; 2] = [Font::Regular, Font::Bold];
+
+// Page holds the content stream of a single page. Coordinates are in points
+// from the bottom left corner.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Page {
+    content: String,
+}
+
+impl Page {
+    pub fn text(&mut self, x: f32, y: f32, font: Font, size: f32, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        self.content += &format!(
+            "BT /{} {} Tf {} {} Td {} Tj ET\n",
+            font.name(),
+            number(size),
+            number(x),
+            number(y),
+            string(text)
+        );
+    }
+
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, width: f32) {
+        self.content += &format!(
+            "{} w {} {} m {} {} l S\n",
+            number(width),
+            number(x1),
+            number(y1),
+            number(x2),
+            number(y2)
+        );
+    }
+
+    // rect strokes a rectangle, filling it too if fill is set.
+    pub fn rect(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        fill: bool,
+    ) {
+        self.content += &format!(
+            "0.75 w {} {} {} {} re {}\n",
+            number(x),
+            number(y),
+            number(width),
+            number(height),
+            match fill {
+                true => "B",
+                false => "S",
+            }
+        );
+    }
+}
+
+// write the pages as a PDF document with the given title.
+pub fn write(title: &str, pages: &[Page]) -> Vec<u8> {
+    // Objects are numbered from 1: the catalog, the page tree, the info
+    // dictionary, the fonts and then each page followed by its content.
+    let first_page = 4 + FONTS.len();
+    let page_ids: Vec<usize> =
+        (0..pages.len()).map(|page| first_page + 2 * page).collect();
+
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_owned(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids
+                .iter()
+                .map(|id| format!("{id} 0 R"))
+                .collect::<Vec<String>>()
+                .join(" "),
+            pages.len()
+        ),
+        format!("<< /Title {} /Producer (character_sheet) >>", string(title)),
+    ];
+    objects.extend(FONTS.iter().map(|font| {
+        format!(
+            "<< /Type /Font /Subtype /Type1 /BaseFont /{} \
+             /Encoding /WinAnsiEncoding >>",
+            font.base_font()
+        )
+    }));
+    let fonts = FONTS
+        .iter()
+        .enumerate()
+        .map(|(index, font)| format!("/{} {} 0 R", font.name(), 4 + index))
+        .collect::<Vec<String>>()
+        .join(" ");
+    for (page, id) in pages.iter().zip(&page_ids) {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << {fonts} >> >> /Contents {} 0 R >>",
+            number(PAGE_WIDTH),
+            number(PAGE_HEIGHT),
+            id + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            page.content.len(),
+            page.content
+        ));
+    }
+
+    let mut pdf = "%PDF-1.4\n".to_owned();
+    let mut offsets = vec![];
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf += &format!("{} 0 obj\n{object}\nendobj\n", index + 1);
+    }
+    let xref = pdf.len();
+    pdf += &format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        pdf += &format!("{offset:010} 00000 n \n");
+    }
+    pdf += &format!(
+        "trailer\n<< /Size {} /Root 1 0 R /Info 3 0 R >>\nstartxref\n{xref}\n\
+         %%EOF\n",
+        objects.len() + 1
+    );
+    pdf.into_bytes()
+}
+
+// number formats n with at most two decimals, e.g. 12, 12.5 or 12.25.
+fn number(n: f32) -> String {
+    let formatted = format!("{n:.2}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" => "0".to_owned(),
+        trimmed => trimmed.to_owned(),
+    }
+}
+
+// string encodes text as a PDF string literal, escaping everything outside of
+// printable ASCII so the document stays ASCII only.
+fn string(text: &str) -> String {
+    let mut literal = "(".to_owned();
+    for byte in text.chars().map(encode) {
+        match byte {
+            b'(' | b')' | b'\\' => {
+                literal.push('\\');
+                literal.push(char::from(byte));
+            }
+            b' '..=b'~' => literal.push(char::from(byte)),
+            byte => literal += &format!("\\{byte:03o}"),
+        }
+    }
+    literal + ")"
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::{number, string, write, Page};
+    use crate::pdf::font::Font;
+
+    #[rstest]
+    #[case(12.0, "12")]
+    #[case(12.5, "12.5")]
+    #[case(12.254, "12.25")]
+    #[case(-0.001, "0")]
+    fn formats_numbers(#[case] n: f32, #[case] expected: &str) {
+        assert_eq!(number(n), expected);
+    }
+
+    #[rstest]
+    #[case("Aria", "(Aria)")]
+    #[case("(a) \\ b", "(\\(a\\) \\\\ b)")]
+    #[case("Ünï", "(\\334n\\357)")]
+    fn encodes_strings(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(string(text), expected);
+    }
+
+    #[rstest]
+    fn writes_valid_cross_reference_table() {
+        let mut page = Page::default();
+        page.text(10.0, 20.0, Font::Bold, 12.0, "Hello");
+        let pdf = String::from_utf8(write("Test", &[page.clone(), page]))
+            .expect("pdf should be ascii");
+
+        let startxref: usize = pdf
+            .lines()
+            .skip_while(|line| *line != "startxref")
+            .nth(1)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(pdf[startxref..].starts_with("xref\n0 10\n"));
+
+        let offsets: Vec<usize> = pdf[startxref..]
+            .lines()
+            .skip(3)
+            .take(9)
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+        for (index, offset) in offsets.into_iter().enumerate() {
+            let object = format!("{} 0 obj\n", index + 1);
+            assert!(pdf[offset..].starts_with(&object), "{object}");
+        }
+        assert!(pdf.contains("/Count 2"));
+        assert!(pdf.ends_with("%%EOF\n"));
+    }
+}
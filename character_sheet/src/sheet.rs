@@ -0,0 +1,601 @@
+use std::collections::{HashMap, HashSet};
+
+use cypher_rules::{stat, DamageTrack, Depletion, Level, POOLS};
+use proto_rs::character::{
+    Character, Inventory, Item, NoteType, PoolType, SkillLevel,
+};
+
+// Sheet is a Character laid out for printing, rendered by the format modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sheet {
+    pub title: String,
+    // summary of the character, e.g. "Clever Nano who Talks to Machines".
+    pub summary: String,
+    pub sections: Vec<Section>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub title: String,
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Heading(String),
+    Table {
+        header: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+    List(Vec<Entry>),
+}
+
+// Entry of a list, rendered as "title: detail" followed by the body and the
+// nested children.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Entry {
+    pub title: String,
+    pub detail: String,
+    pub body: String,
+    pub children: Vec<Entry>,
+}
+
+impl Entry {
+    fn new(title: &str, detail: &str) -> Entry {
+        Entry {
+            title: title.to_owned(),
+            detail: detail.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+// CHARACTER and STATS are the titles of the sections formats may lay out on
+// their own.
+pub(crate) const CHARACTER: &str = "Character";
+pub(crate) const STATS: &str = "Stats";
+
+const SKILL_LEVELS: [SkillLevel; 3] = [
+    SkillLevel::Specialized,
+    SkillLevel::Trained,
+    SkillLevel::Inability,
+];
+
+const NOTE_TYPES: [NoteType; 5] = [
+    NoteType::Quest,
+    NoteType::Character,
+    NoteType::Location,
+    NoteType::Item,
+    NoteType::Misc,
+];
+
+impl Sheet {
+    // new lays out character, sections without content are left out.
+    pub fn new(character: &Character) -> Sheet {
+        let sections = [
+            Section::new(CHARACTER, vec![Block::List(facts(character))]),
+            Section::new(STATS, stats(character)),
+            Section::new("Skills", skills(character)),
+            Section::new("Abilities", abilities(character)),
+            Section::new("Cyphers", cyphers(character)),
+            Section::new("Artifacts", artifacts(character)),
+            Section::new("Inventory", inventory(character)),
+            Section::new("Notes", notes(character)),
+        ];
+
+        Sheet {
+            title: match character.name.is_empty() {
+                false => character.name.to_owned(),
+                true => "Unnamed Character".to_owned(),
+            },
+            summary: summary(character),
+            sections: sections
+                .into_iter()
+                .filter(|section| !section.blocks.is_empty())
+                .collect(),
+        }
+    }
+}
+
+impl Section {
+    fn new(title: &str, blocks: Vec<Block>) -> Section {
+        Section {
+            title: title.to_owned(),
+            blocks,
+        }
+    }
+}
+
+fn summary(character: &Character) -> String {
+    let mut summary: Vec<&str> = [&character.descriptor, &character.r#type]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .map(String::as_str)
+        .collect();
+    if !character.focus.is_empty() {
+        summary.extend(["who", character.focus.as_str()]);
+    }
+    summary.join(" ")
+}
+
+fn facts(character: &Character) -> Vec<Entry> {
+    let progress = character.progress.clone().unwrap_or_default();
+    let recovery = character.recovery.clone().unwrap_or_default();
+    let used: Vec<&str> = [
+        (recovery.one_action, "one action"),
+        (recovery.ten_minutes, "ten minutes"),
+        (recovery.one_hour, "one hour"),
+        (recovery.ten_hours, "ten hours"),
+    ]
+    .into_iter()
+    .filter(|(used, _)| *used)
+    .map(|(_, slot)| slot)
+    .collect();
+
+    vec![
+        Entry::new("Tier", &progress.tier.to_string()),
+        Entry::new("Effort", &progress.max_effort.to_string()),
+        Entry::new(
+            "XP",
+            &format!("{} ({} total)", progress.free_xp, progress.total_xp),
+        ),
+        Entry::new(
+            "Damage track",
+            match DamageTrack::of(character) {
+                DamageTrack::Hale => "hale",
+                DamageTrack::Impaired => "impaired",
+                DamageTrack::Debilitated => "debilitated",
+                DamageTrack::Dead => "dead",
+            },
+        ),
+        Entry::new("Recovery bonus", &format!("+{}", recovery.bonus)),
+        Entry::new(
+            "Recoveries used",
+            &match used.is_empty() {
+                false => used.join(", "),
+                true => "none".to_owned(),
+            },
+        ),
+        Entry::new("Money", &character.money.to_string()),
+    ]
+}
+
+fn stats(character: &Character) -> Vec<Block> {
+    if character.stats.is_none() {
+        return vec![];
+    }
+    let rows = POOLS
+        .into_iter()
+        .map(|pool| {
+            let stat = stat(character, pool);
+            vec![
+                pool_name(pool).to_owned(),
+                stat.pool.to_string(),
+                stat.cap.to_string(),
+                stat.edge.to_string(),
+            ]
+        })
+        .collect();
+    vec![table(&["Stat", "Pool", "Cap", "Edge"], rows)]
+}
+
+fn skills(character: &Character) -> Vec<Block> {
+    let mut blocks = vec![];
+    for level in SKILL_LEVELS {
+        let entries: Vec<Entry> = character
+            .skills
+            .iter()
+            .filter(|skill| skill.level() == level)
+            .map(|skill| {
+                Entry::new(
+                    &format!("{} ({})", skill.name, pool_name(skill.r#type())),
+                    &skill.description,
+                )
+            })
+            .collect();
+        if !entries.is_empty() {
+            blocks.push(Block::Heading(skill_level_name(level).to_owned()));
+            blocks.push(Block::List(entries));
+        }
+    }
+    blocks
+}
+
+fn abilities(character: &Character) -> Vec<Block> {
+    if character.abilities.is_empty() {
+        return vec![];
+    }
+    let rows = character
+        .abilities
+        .iter()
+        .map(|ability| {
+            let cost = match (ability.enabler, ability.cost.is_empty()) {
+                (true, _) => "Enabler".to_owned(),
+                (false, true) => String::new(),
+                (false, false) => {
+                    format!("{} {}", ability.cost, pool_name(ability.r#type()))
+                }
+            };
+            vec![
+                ability.name.to_owned(),
+                cost,
+                first_non_empty(
+                    &ability.short_description,
+                    &ability.description,
+                )
+                .to_owned(),
+            ]
+        })
+        .collect();
+    vec![table(&["Ability", "Cost", "Description"], rows)]
+}
+
+fn cyphers(character: &Character) -> Vec<Block> {
+    if character.cyphers.is_empty() {
+        return vec![];
+    }
+    let rows = character
+        .cyphers
+        .iter()
+        .map(|cypher| {
+            vec![
+                cypher.name.to_owned(),
+                level(&cypher.level),
+                first_non_empty(&cypher.effect, &cypher.short_description)
+                    .to_owned(),
+            ]
+        })
+        .collect();
+    vec![table(&["Cypher", "Level", "Effect"], rows)]
+}
+
+fn artifacts(character: &Character) -> Vec<Block> {
+    if character.artifacts.is_empty() {
+        return vec![];
+    }
+    let rows = character
+        .artifacts
+        .iter()
+        .map(|artifact| {
+            vec![
+                match artifact.active {
+                    true => artifact.name.to_owned(),
+                    false => format!("{} (depleted)", artifact.name),
+                },
+                level(&artifact.level),
+                artifact
+                    .depletion
+                    .parse::<Depletion>()
+                    .map_or(artifact.depletion.to_owned(), |depletion| {
+                        depletion.to_string()
+                    }),
+                first_non_empty(&artifact.effect, &artifact.short_description)
+                    .to_owned(),
+            ]
+        })
+        .collect();
+    vec![table(&["Artifact", "Level", "Depletion", "Effect"], rows)]
+}
+
+// inventory lists the items of each inventory as a tree, nesting items below
+// the item their ItemPath.parent points to. Items of unknown inventories are
+// listed last.
+fn inventory(character: &Character) -> Vec<Block> {
+    let mut inventories: Vec<&Inventory> =
+        character.inventories.iter().collect();
+    inventories.sort_by_key(|inventory| inventory.order);
+
+    let path = |item: &Item| item.path.clone().unwrap_or_default();
+    let known: HashSet<&str> = inventories
+        .iter()
+        .map(|inventory| inventory.uuid.as_str())
+        .collect();
+
+    let mut blocks = vec![];
+    let mut list = |title: &str, items: Vec<&Item>| {
+        if !items.is_empty() {
+            blocks.push(Block::Heading(title.to_owned()));
+            blocks.push(Block::List(item_tree(&items)));
+        }
+    };
+    for inventory in inventories {
+        list(
+            &inventory.name,
+            character
+                .items
+                .iter()
+                .filter(|item| path(item).inventory == inventory.uuid)
+                .collect(),
+        );
+    }
+    list(
+        "Other items",
+        character
+            .items
+            .iter()
+            .filter(|item| !known.contains(path(item).inventory.as_str()))
+            .collect(),
+    );
+    blocks
+}
+
+// item_tree nests items below their parents. Items with parents outside of
+// items, or in a cycle, are listed at the top.
+fn item_tree(items: &[&Item]) -> Vec<Entry> {
+    let id = |item: &Item| item.path.clone().unwrap_or_default().self_;
+    let parent = |item: &Item| item.path.clone().unwrap_or_default().parent;
+    let ids: HashSet<String> = items.iter().map(|item| id(item)).collect();
+
+    let mut children: HashMap<String, Vec<usize>> = HashMap::new();
+    let mut roots = vec![];
+    for (index, item) in items.iter().enumerate() {
+        let parent = parent(item);
+        match !parent.is_empty() && ids.contains(&parent) {
+            true => children.entry(parent).or_default().push(index),
+            false => roots.push(index),
+        }
+    }
+
+    let mut visited = HashSet::new();
+    let mut entries: Vec<Entry> = roots
+        .into_iter()
+        .filter_map(|index| item_entry(items, index, &children, &mut visited))
+        .collect();
+    for index in 0..items.len() {
+        if let Some(entry) = item_entry(items, index, &children, &mut visited) {
+            entries.push(entry);
+        }
+    }
+    entries
+}
+
+fn item_entry(
+    items: &[&Item],
+    index: usize,
+    children: &HashMap<String, Vec<usize>>,
+    visited: &mut HashSet<usize>,
+) -> Option<Entry> {
+    if !visited.insert(index) {
+        return None;
+    }
+    let item = items[index];
+    let id = item.path.clone().unwrap_or_default().self_;
+    let title = match item.amount > 1.0 {
+        true => format!("{} ×{}", item.name, item.amount),
+        false => item.name.to_owned(),
+    };
+
+    Some(Entry {
+        children: children
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter_map(|child| item_entry(items, *child, children, visited))
+            .collect(),
+        ..Entry::new(
+            &title,
+            first_non_empty(&item.short_description, &item.description),
+        )
+    })
+}
+
+fn notes(character: &Character) -> Vec<Block> {
+    let mut blocks = vec![];
+    for note_type in NOTE_TYPES {
+        let entries: Vec<Entry> = character
+            .notes
+            .iter()
+            .filter(|note| note.r#type() == note_type)
+            .map(|note| Entry {
+                body: note.text.to_owned(),
+                ..Entry::new(&note.title, &note.short_description)
+            })
+            .collect();
+        if !entries.is_empty() {
+            blocks.push(Block::Heading(note_type_name(note_type).to_owned()));
+            blocks.push(Block::List(entries));
+        }
+    }
+    blocks
+}
+
+fn table(header: &[&str], rows: Vec<Vec<String>>) -> Block {
+    Block::Table {
+        header: header.iter().map(|title| title.to_string()).collect(),
+        rows,
+    }
+}
+
+// level normalizes cypher and artifact levels, levels that can't be parsed
+// are kept as written.
+fn level(level: &str) -> String {
+    match level.parse::<Level>() {
+        Ok(Level::Unknown) => String::new(),
+        Ok(level) => level.to_string(),
+        Err(_) => level.to_owned(),
+    }
+}
+
+fn first_non_empty<'a>(first: &'a str, second: &'a str) -> &'a str {
+    match first.is_empty() {
+        false => first,
+        true => second,
+    }
+}
+
+fn pool_name(pool: PoolType) -> &'static str {
+    match pool {
+        PoolType::Intellect => "Intellect",
+        PoolType::Speed => "Speed",
+        PoolType::Might => "Might",
+    }
+}
+
+fn skill_level_name(level: SkillLevel) -> &'static str {
+    match level {
+        SkillLevel::Specialized => "Specialized",
+        SkillLevel::Trained => "Trained",
+        SkillLevel::Inability => "Inability",
+    }
+}
+
+fn note_type_name(note_type: NoteType) -> &'static str {
+    match note_type {
+        NoteType::Misc => "Misc",
+        NoteType::Location => "Locations",
+        NoteType::Character => "Characters",
+        NoteType::Item => "Items",
+        NoteType::Quest => "Quests",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proto_rs::character::{
+        Character, Inventory, Item, ItemPath, Note, NoteType,
+    };
+    use rstest::rstest;
+
+    use super::{item_tree, Block, Entry, Sheet};
+
+    fn item(inventory: &str, parent: &str, id: &str) -> Item {
+        Item {
+            path: Some(ItemPath {
+                inventory: inventory.to_owned(),
+                parent: parent.to_owned(),
+                self_: id.to_owned(),
+            }),
+            name: id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn titles(entries: &[Entry]) -> Vec<String> {
+        entries
+            .iter()
+            .map(|entry| match entry.children.is_empty() {
+                true => entry.title.to_owned(),
+                false => format!(
+                    "{}[{}]",
+                    entry.title,
+                    titles(&entry.children).join(",")
+                ),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn nests_items_below_parents() {
+        let items = [
+            item("bag", "", "bag"),
+            item("bag", "pouch", "coin"),
+            item("bag", "bag", "pouch"),
+            item("bag", "", "rope"),
+        ];
+        let items: Vec<&Item> = items.iter().collect();
+
+        assert_eq!(
+            titles(&item_tree(&items)),
+            vec!["bag[pouch[coin]]", "rope"]
+        );
+    }
+
+    #[rstest]
+    fn lists_orphans_and_cycles_at_the_top() {
+        let items = [
+            item("bag", "missing", "orphan"),
+            item("bag", "b", "a"),
+            item("bag", "a", "b"),
+        ];
+        let items: Vec<&Item> = items.iter().collect();
+
+        assert_eq!(titles(&item_tree(&items)), vec!["orphan", "a[b]"]);
+    }
+
+    #[rstest]
+    fn lists_items_of_unknown_inventories_last() {
+        let character = Character {
+            inventories: vec![
+                Inventory {
+                    uuid: "chest".to_owned(),
+                    name: "Chest".to_owned(),
+                    order: 2,
+                },
+                Inventory {
+                    uuid: "bag".to_owned(),
+                    name: "Bag".to_owned(),
+                    order: 1,
+                },
+            ],
+            items: vec![
+                item("lost", "", "key"),
+                item("chest", "", "gold"),
+                item("bag", "", "rope"),
+            ],
+            ..Default::default()
+        };
+
+        let inventory = Sheet::new(&character)
+            .sections
+            .into_iter()
+            .find(|section| section.title == "Inventory")
+            .unwrap();
+
+        let headings: Vec<&Block> =
+            inventory.blocks.iter().step_by(2).collect();
+        assert_eq!(
+            headings,
+            vec![
+                &Block::Heading("Bag".to_owned()),
+                &Block::Heading("Chest".to_owned()),
+                &Block::Heading("Other items".to_owned()),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn groups_notes_by_type() {
+        let note = |title: &str, note_type: NoteType| Note {
+            title: title.to_owned(),
+            r#type: note_type.into(),
+            ..Default::default()
+        };
+        let character = Character {
+            notes: vec![
+                note("Qi", NoteType::Location),
+                note("Find the key", NoteType::Quest),
+                note("Ada", NoteType::Location),
+            ],
+            ..Default::default()
+        };
+
+        let notes = Sheet::new(&character)
+            .sections
+            .into_iter()
+            .find(|section| section.title == "Notes")
+            .unwrap();
+
+        assert_eq!(
+            notes.blocks,
+            vec![
+                Block::Heading("Quests".to_owned()),
+                Block::List(vec![Entry::new("Find the key", "")]),
+                Block::Heading("Locations".to_owned()),
+                Block::List(vec![Entry::new("Qi", ""), Entry::new("Ada", "")]),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn leaves_out_empty_sections() {
+        let sheet = Sheet::new(&Character::default());
+
+        assert_eq!(sheet.title, "Unnamed Character");
+        let sections: Vec<&str> = sheet
+            .sections
+            .iter()
+            .map(|section| section.title.as_str())
+            .collect();
+        assert_eq!(sections, vec!["Character"]);
+    }
+}
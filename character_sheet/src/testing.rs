@@ -0,0 +1,208 @@
+// Fixtures shared by the tests of the format modules.
+
+use std::{fs, path::PathBuf};
+
+use proto_rs::character::{
+    Ability, Advancements, Artifact, Character, Cypher, Damage, Inventory,
+    Item, ItemPath, ItemType, Note, NoteType, PoolType, Progress, Recovery,
+    Skill, SkillLevel, Stat, Stats,
+};
+use serde_json::Value;
+
+fn stat(pool_type: PoolType, cap: i32, pool: i32, edge: i32) -> Stat {
+    Stat {
+        r#type: pool_type.into(),
+        cap,
+        pool,
+        edge,
+    }
+}
+
+fn item(parent: &str, id: &str, name: &str, amount: f64) -> Item {
+    Item {
+        path: Some(ItemPath {
+            inventory: "inventory-1".to_owned(),
+            parent: parent.to_owned(),
+            self_: id.to_owned(),
+        }),
+        name: name.to_owned(),
+        amount,
+        ..Default::default()
+    }
+}
+
+// character with every section of the sheet filled.
+pub fn character() -> Character {
+    Character {
+        uuid: "0c1b6a32-3f0e-4a8e-9d2c-6a1f1f0b7e55".to_owned(),
+        name: "Aria".to_owned(),
+        descriptor: "Clever".to_owned(),
+        r#type: "Nano".to_owned(),
+        focus: "Talks to Machines".to_owned(),
+        progress: Some(Progress {
+            tier: 3,
+            free_xp: 2,
+            total_xp: 21,
+            max_effort: 2,
+            advancements: Some(Advancements {
+                increase_capabilities: true,
+                ..Default::default()
+            }),
+        }),
+        stats: Some(Stats {
+            intellect: Some(stat(PoolType::Intellect, 18, 15, 1)),
+            speed: Some(stat(PoolType::Speed, 10, 8, 0)),
+            might: Some(stat(PoolType::Might, 9, 9, 0)),
+        }),
+        recovery: Some(Recovery {
+            bonus: 3,
+            one_action: true,
+            ..Default::default()
+        }),
+        damage: Some(Damage::default()),
+        skills: vec![
+            Skill {
+                uuid: "skill-1".to_owned(),
+                name: "Climbing".to_owned(),
+                description: "Up walls".to_owned(),
+                r#type: PoolType::Might.into(),
+                level: SkillLevel::Trained.into(),
+            },
+            Skill {
+                uuid: "skill-2".to_owned(),
+                name: "Lore".to_owned(),
+                r#type: PoolType::Intellect.into(),
+                level: SkillLevel::Specialized.into(),
+                ..Default::default()
+            },
+            Skill {
+                uuid: "skill-3".to_owned(),
+                name: "Swimming".to_owned(),
+                r#type: PoolType::Might.into(),
+                level: SkillLevel::Inability.into(),
+                ..Default::default()
+            },
+        ],
+        abilities: vec![
+            Ability {
+                uuid: "ability-1".to_owned(),
+                name: "Onslaught".to_owned(),
+                cost: "1+".to_owned(),
+                r#type: PoolType::Intellect.into(),
+                description: "Attack with your mind.".to_owned(),
+                short_description: "Mental attack".to_owned(),
+                ..Default::default()
+            },
+            Ability {
+                uuid: "ability-2".to_owned(),
+                name: "Ward".to_owned(),
+                enabler: true,
+                description: "+1 Armor".to_owned(),
+                ..Default::default()
+            },
+        ],
+        cypher_limit: 3,
+        cyphers: vec![Cypher {
+            uuid: "cypher-1".to_owned(),
+            name: "Detonation".to_owned(),
+            level: "d6+2".to_owned(),
+            effect: "Inflicts 3 damage | explodes".to_owned(),
+            active: true,
+            ..Default::default()
+        }],
+        artifacts: vec![
+            Artifact {
+                uuid: "artifact-1".to_owned(),
+                name: "Lightning Gloves".to_owned(),
+                level: "5".to_owned(),
+                effect: "Inflicts 4 damage".to_owned(),
+                depletion: "1 in d20".to_owned(),
+                active: true,
+                ..Default::default()
+            },
+            Artifact {
+                uuid: "artifact-2".to_owned(),
+                name: "Mind Lock".to_owned(),
+                depletion: "automatic".to_owned(),
+                ..Default::default()
+            },
+        ],
+        money: 12.75,
+        inventories: vec![Inventory {
+            uuid: "inventory-1".to_owned(),
+            name: "Backpack".to_owned(),
+            order: 1,
+        }],
+        items: vec![
+            Item {
+                short_description: "50 ft".to_owned(),
+                ..item("", "item-1", "Rope", 1.0)
+            },
+            item("", "item-2", "Pouch", 1.0),
+            Item {
+                types: vec![ItemType::Ammo.into()],
+                ..item("item-2", "item-3", "Sling Stones", 12.0)
+            },
+        ],
+        notes: vec![
+            Note {
+                uuid: "note-1".to_owned(),
+                title: "Qi".to_owned(),
+                r#type: NoteType::Location.into(),
+                short_description: "City".to_owned(),
+                text: "A city\nin the Steadfast.\n\nHas a market.".to_owned(),
+            },
+            Note {
+                uuid: "note-3".to_owned(),
+                title: "Rumors".to_owned(),
+                r#type: NoteType::Misc.into(),
+                text: "# Not a heading\n- not a list\n1. not a list\n    \
+                    not code\nTom &amp; Jerry"
+                    .to_owned(),
+                ..Default::default()
+            },
+            Note {
+                uuid: "note-2".to_owned(),
+                title: "Find the <key>".to_owned(),
+                r#type: NoteType::Quest.into(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+// check_golden compares rendered to the file name in testdata/. Set
+// UPDATE_GOLDEN to write rendered to the file instead.
+pub fn check_golden(name: &str, rendered: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join(name);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, rendered)
+            .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+    }
+    let golden = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+    assert_eq!(rendered, golden, "{} is outdated", path.display());
+}
+
+// check_golden_json compares value to the JSON file name in testdata/,
+// ignoring the order of the keys, which depends on the features serde_json
+// is built with.
+pub fn check_golden_json(name: &str, value: &Value) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join(name);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        let json = serde_json::to_string_pretty(value).unwrap() + "\n";
+        fs::write(&path, json)
+            .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+    }
+    let golden: Value = serde_json::from_str(
+        &fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("{}: {err}", path.display())),
+    )
+    .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+    assert_eq!(value, &golden, "{} is outdated", path.display());
+}
@@ -0,0 +1,625 @@
+use std::fmt::Display;
+
+use proto_rs::character::{
+    Advancements, Character, PoolType, Progress, Skill, SkillLevel,
+};
+
+use crate::{
+    error::{Error, ErrorCode},
+    pools::{points, stat_mut},
+};
+
+// ADVANCEMENT_COST in XP.
+pub const ADVANCEMENT_COST: i32 = 4;
+
+// CAPABILITY_POINTS added to the pools by increasing capabilities.
+pub const CAPABILITY_POINTS: u32 = 4;
+
+// MAX_TIER a character can reach.
+pub const MAX_TIER: i32 = 6;
+
+// Advancement a character can buy once per tier. Other options replace
+// skill training.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Advancement {
+    // IncreaseCapabilities adds CAPABILITY_POINTS to the pools.
+    IncreaseCapabilities {
+        might: u32,
+        speed: u32,
+        intellect: u32,
+    },
+    // MoveTowardPerfection adds 1 to the edge of a stat.
+    MoveTowardPerfection(PoolType),
+    // ExtraEffort adds 1 to the maximum effort.
+    ExtraEffort,
+    // SkillTraining trains the skill, specializes in it if it is trained
+    // already or removes an inability.
+    SkillTraining(Skill),
+    // Other options described by the text, e.g. reducing the cost of armor.
+    Other(String),
+}
+
+// Change made to a character by advancing, listed in the order applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    SpentXp(i32),
+    IncreasedPool {
+        pool: PoolType,
+        points: u32,
+    },
+    IncreasedEdge(PoolType),
+    IncreasedMaxEffort(i32),
+    TrainedSkill {
+        name: String,
+        level: Option<SkillLevel>,
+    },
+    Other(String),
+    ReachedTier(i32),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::SpentXp(xp) => write!(f, "spent {xp} XP"),
+            Change::IncreasedPool { pool, points } => {
+                write!(f, "increased {} by {points}", pool_name(*pool))
+            }
+            Change::IncreasedEdge(pool) => {
+                write!(f, "increased {} edge by 1", pool_name(*pool))
+            }
+            Change::IncreasedMaxEffort(effort) => {
+                write!(f, "increased maximum effort to {effort}")
+            }
+            Change::TrainedSkill {
+                name,
+                level: Some(level),
+            } => write!(f, "{} in {name}", level_name(*level)),
+            Change::TrainedSkill { name, level: None } => {
+                write!(f, "overcame inability in {name}")
+            }
+            Change::Other(text) => write!(f, "advanced: {text}"),
+            Change::ReachedTier(tier) => write!(f, "reached tier {tier}"),
+        }
+    }
+}
+
+fn pool_name(pool: PoolType) -> &'static str {
+    match pool {
+        PoolType::Intellect => "Intellect",
+        PoolType::Speed => "Speed",
+        PoolType::Might => "Might",
+    }
+}
+
+fn level_name(level: SkillLevel) -> &'static str {
+    match level {
+        SkillLevel::Specialized => "specialized",
+        SkillLevel::Trained => "trained",
+        SkillLevel::Inability => "inability",
+    }
+}
+
+// Advanced is the character after buying an advancement and what changed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Advanced {
+    pub character: Character,
+    pub changes: Vec<Change>,
+}
+
+// award_xp adds xp to the free and total XP of character.
+pub fn award_xp(character: &Character, xp: u32) -> Character {
+    let mut character = character.clone();
+    let progress = character.progress.get_or_insert_with(Progress::default);
+    progress.free_xp = progress.free_xp.saturating_add(points(xp));
+    progress.total_xp = progress.total_xp.saturating_add(points(xp));
+    character
+}
+
+// advance buys advancement for ADVANCEMENT_COST XP. Once all four
+// advancements of a tier are bought the character reaches the next tier and
+// can buy them again.
+pub fn advance(
+    character: &Character,
+    advancement: Advancement,
+) -> Result<Advanced, Error> {
+    let mut character = character.clone();
+    let progress = character.progress.get_or_insert_with(Progress::default);
+    let advancements = progress
+        .advancements
+        .get_or_insert_with(Advancements::default);
+
+    let bought = match advancement {
+        Advancement::IncreaseCapabilities { .. } => {
+            &mut advancements.increase_capabilities
+        }
+        Advancement::MoveTowardPerfection(_) => {
+            &mut advancements.move_toward_perfection
+        }
+        Advancement::ExtraEffort => &mut advancements.extra_effort,
+        Advancement::SkillTraining(_) | Advancement::Other(_) => {
+            if advancements.other {
+                &mut advancements.other
+            } else {
+                &mut advancements.skill_training
+            }
+        }
+    };
+    if *bought {
+        return Err(Error::new(
+            ErrorCode::AlreadyAdvanced,
+            "advancement was already bought in this tier",
+        ));
+    }
+    if progress.free_xp < ADVANCEMENT_COST {
+        return Err(Error::new(
+            ErrorCode::NotEnoughXp,
+            &format!(
+                "advancing costs {ADVANCEMENT_COST} XP, {} are left",
+                progress.free_xp
+            ),
+        ));
+    }
+    *bought = true;
+    if let Advancement::Other(_) = advancement {
+        advancements.other = true;
+    }
+    progress.free_xp -= ADVANCEMENT_COST;
+
+    let mut changes = vec![Change::SpentXp(ADVANCEMENT_COST)];
+    apply(&mut character, advancement, &mut changes)?;
+    if let Some(tier) = tier_up(&mut character) {
+        changes.push(Change::ReachedTier(tier));
+    }
+
+    Ok(Advanced { character, changes })
+}
+
+fn apply(
+    character: &mut Character,
+    advancement: Advancement,
+    changes: &mut Vec<Change>,
+) -> Result<(), Error> {
+    match advancement {
+        Advancement::IncreaseCapabilities {
+            might,
+            speed,
+            intellect,
+        } => {
+            let added = [
+                (PoolType::Might, might),
+                (PoolType::Speed, speed),
+                (PoolType::Intellect, intellect),
+            ];
+            let total: u64 = added.iter().map(|(_, p)| u64::from(*p)).sum();
+            if total != u64::from(CAPABILITY_POINTS) {
+                return Err(Error::new(
+                    ErrorCode::InvalidAdvancement,
+                    &format!(
+                        "increasing capabilities adds {CAPABILITY_POINTS} \
+                         points, not {total}"
+                    ),
+                ));
+            }
+            for (pool, added) in added.into_iter().filter(|(_, p)| *p > 0) {
+                let stat = stat_mut(character, pool);
+                stat.cap = stat.cap.saturating_add(points(added));
+                stat.pool = stat.pool.saturating_add(points(added));
+                changes.push(Change::IncreasedPool {
+                    pool,
+                    points: added,
+                });
+            }
+        }
+        Advancement::MoveTowardPerfection(pool) => {
+            let stat = stat_mut(character, pool);
+            stat.edge = stat.edge.saturating_add(1);
+            changes.push(Change::IncreasedEdge(pool));
+        }
+        Advancement::ExtraEffort => {
+            let progress =
+                character.progress.get_or_insert_with(Progress::default);
+            progress.max_effort = progress.max_effort.saturating_add(1);
+            changes.push(Change::IncreasedMaxEffort(progress.max_effort));
+        }
+        Advancement::SkillTraining(skill) => {
+            let name = skill.name.clone();
+            let level = train(character, skill)?;
+            changes.push(Change::TrainedSkill { name, level });
+        }
+        Advancement::Other(text) => changes.push(Change::Other(text)),
+    }
+    Ok(())
+}
+
+// train the skill with the uuid of skill, adding it if the character doesn't
+// have it. Returns the new level, None if an inability was removed.
+fn train(
+    character: &mut Character,
+    skill: Skill,
+) -> Result<Option<SkillLevel>, Error> {
+    let existing = character
+        .skills
+        .iter()
+        .position(|existing| existing.uuid == skill.uuid);
+    let index = match existing {
+        Some(index) => index,
+        None => {
+            character.skills.push(Skill {
+                level: SkillLevel::Trained.into(),
+                ..skill
+            });
+            return Ok(Some(SkillLevel::Trained));
+        }
+    };
+
+    let existing = &mut character.skills[index];
+    match existing.level() {
+        SkillLevel::Inability => {
+            character.skills.remove(index);
+            Ok(None)
+        }
+        SkillLevel::Trained => {
+            existing.set_level(SkillLevel::Specialized);
+            Ok(Some(SkillLevel::Specialized))
+        }
+        SkillLevel::Specialized => Err(Error::new(
+            ErrorCode::InvalidAdvancement,
+            &format!("already specialized in {}", existing.name),
+        )),
+    }
+}
+
+// tier_up moves the character to the next tier once all advancements of the
+// current one are bought, returning the new tier.
+fn tier_up(character: &mut Character) -> Option<i32> {
+    let progress = character.progress.as_mut()?;
+    let advancements = progress.advancements.as_ref()?;
+    let complete = advancements.increase_capabilities
+        && advancements.move_toward_perfection
+        && advancements.extra_effort
+        && advancements.skill_training;
+    if !complete || progress.tier >= MAX_TIER {
+        return None;
+    }
+
+    progress.tier += 1;
+    progress.advancements = Some(Advancements::default());
+    Some(progress.tier)
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use proto_rs::character::{
+        Advancements, Character, PoolType, Progress, Skill, SkillLevel, Stat,
+        Stats,
+    };
+    use rstest::rstest;
+
+    use super::{
+        advance, award_xp, Advancement, Change, ADVANCEMENT_COST, MAX_TIER,
+    };
+    use crate::{pools::stat, testing::character, ErrorCode};
+
+    fn with_xp(tier: i32, free_xp: i32) -> Character {
+        Character {
+            progress: Some(Progress {
+                tier,
+                free_xp,
+                total_xp: free_xp,
+                max_effort: 1,
+                advancements: Some(Advancements::default()),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn skill(uuid: &str, level: SkillLevel) -> Skill {
+        Skill {
+            uuid: uuid.to_owned(),
+            name: uuid.to_owned(),
+            level: level.into(),
+            ..Default::default()
+        }
+    }
+
+    fn core_advancements() -> Vec<Advancement> {
+        vec![
+            Advancement::IncreaseCapabilities {
+                might: 2,
+                speed: 2,
+                intellect: 0,
+            },
+            Advancement::MoveTowardPerfection(PoolType::Speed),
+            Advancement::ExtraEffort,
+            Advancement::SkillTraining(skill("climbing", SkillLevel::Trained)),
+        ]
+    }
+
+    #[rstest]
+    fn awards_xp() {
+        let character = award_xp(&with_xp(1, 2), 3);
+
+        let progress = character.progress.unwrap();
+        assert_eq!(progress.free_xp, 5);
+        assert_eq!(progress.total_xp, 5);
+    }
+
+    #[rstest]
+    fn increases_capabilities() {
+        let advanced = advance(
+            &with_xp(1, 5),
+            Advancement::IncreaseCapabilities {
+                might: 3,
+                speed: 0,
+                intellect: 1,
+            },
+        )
+        .unwrap();
+
+        let might = stat(&advanced.character, PoolType::Might);
+        assert_eq!((might.cap, might.pool), (3, 3));
+        assert_eq!(stat(&advanced.character, PoolType::Speed).cap, 0);
+        assert_eq!(stat(&advanced.character, PoolType::Intellect).cap, 1);
+        let progress = advanced.character.progress.unwrap();
+        assert_eq!(progress.free_xp, 1);
+        assert!(progress.advancements.unwrap().increase_capabilities);
+        assert_eq!(
+            advanced.changes,
+            [
+                Change::SpentXp(4),
+                Change::IncreasedPool {
+                    pool: PoolType::Might,
+                    points: 3
+                },
+                Change::IncreasedPool {
+                    pool: PoolType::Intellect,
+                    points: 1
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn rejects_wrong_capability_points() {
+        let err = advance(
+            &with_xp(1, 4),
+            Advancement::IncreaseCapabilities {
+                might: 2,
+                speed: 1,
+                intellect: 0,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::InvalidAdvancement);
+    }
+
+    #[rstest]
+    fn moves_toward_perfection() {
+        let advanced = advance(
+            &with_xp(1, 4),
+            Advancement::MoveTowardPerfection(PoolType::Intellect),
+        )
+        .unwrap();
+
+        assert_eq!(stat(&advanced.character, PoolType::Intellect).edge, 1);
+    }
+
+    #[rstest]
+    fn adds_extra_effort() {
+        let advanced =
+            advance(&with_xp(1, 4), Advancement::ExtraEffort).unwrap();
+
+        assert_eq!(advanced.character.progress.unwrap().max_effort, 2);
+        assert_eq!(
+            advanced.changes[1].to_string(),
+            "increased maximum effort to 2"
+        );
+    }
+
+    #[rstest]
+    fn saturates_at_maximum_values() {
+        let maxed = Stat {
+            r#type: PoolType::Might.into(),
+            pool: i32::MAX,
+            cap: i32::MAX,
+            edge: i32::MAX,
+        };
+        let mut character = with_xp(1, 12);
+        character.stats = Some(Stats {
+            might: Some(maxed.clone()),
+            ..Default::default()
+        });
+        character.progress.as_mut().unwrap().max_effort = i32::MAX;
+
+        for advancement in [
+            Advancement::IncreaseCapabilities {
+                might: 4,
+                speed: 0,
+                intellect: 0,
+            },
+            Advancement::MoveTowardPerfection(PoolType::Might),
+            Advancement::ExtraEffort,
+        ] {
+            character = advance(&character, advancement).unwrap().character;
+        }
+
+        assert_eq!(stat(&character, PoolType::Might), maxed);
+        assert_eq!(character.progress.unwrap().max_effort, i32::MAX);
+    }
+
+    #[rstest]
+    #[case::new_skill(vec![], Some(SkillLevel::Trained), 1)]
+    #[case::trained(
+        vec![skill("climbing", SkillLevel::Trained)],
+        Some(SkillLevel::Specialized),
+        1
+    )]
+    #[case::inability(
+        vec![skill("climbing", SkillLevel::Inability)],
+        None,
+        0
+    )]
+    fn trains_skills(
+        #[case] skills: Vec<Skill>,
+        #[case] level: Option<SkillLevel>,
+        #[case] count: usize,
+    ) {
+        let character = Character {
+            skills,
+            ..with_xp(1, 4)
+        };
+
+        let advanced = advance(
+            &character,
+            Advancement::SkillTraining(skill(
+                "climbing",
+                SkillLevel::Inability,
+            )),
+        )
+        .unwrap();
+
+        assert_eq!(advanced.character.skills.len(), count);
+        if let Some(level) = level {
+            assert_eq!(advanced.character.skills[0].level(), level);
+        }
+        assert_eq!(
+            advanced.changes[1],
+            Change::TrainedSkill {
+                name: "climbing".to_owned(),
+                level
+            }
+        );
+    }
+
+    #[rstest]
+    fn rejects_specializing_twice() {
+        let character = Character {
+            skills: vec![skill("climbing", SkillLevel::Specialized)],
+            ..with_xp(1, 4)
+        };
+
+        let err = advance(
+            &character,
+            Advancement::SkillTraining(skill("climbing", SkillLevel::Trained)),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::InvalidAdvancement);
+    }
+
+    #[rstest]
+    fn rejects_without_xp() {
+        let err =
+            advance(&with_xp(1, 3), Advancement::ExtraEffort).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::NotEnoughXp);
+    }
+
+    #[rstest]
+    fn rejects_buying_twice_per_tier() {
+        let advanced =
+            advance(&with_xp(1, 8), Advancement::ExtraEffort).unwrap();
+
+        let err =
+            advance(&advanced.character, Advancement::ExtraEffort).unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::AlreadyAdvanced);
+    }
+
+    #[rstest]
+    fn other_replaces_skill_training() {
+        let advanced = advance(
+            &with_xp(1, 8),
+            Advancement::Other("reduce armor cost".to_owned()),
+        )
+        .unwrap();
+        let advancements = advanced
+            .character
+            .progress
+            .as_ref()
+            .unwrap()
+            .advancements
+            .clone()
+            .unwrap();
+        assert!(advancements.other && advancements.skill_training);
+
+        let err = advance(
+            &advanced.character,
+            Advancement::SkillTraining(skill("climbing", SkillLevel::Trained)),
+        )
+        .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::AlreadyAdvanced);
+    }
+
+    #[rstest]
+    fn reaches_next_tier() {
+        let mut character = with_xp(1, 16);
+        let mut changes = vec![];
+        for advancement in core_advancements() {
+            let advanced = advance(&character, advancement).unwrap();
+            character = advanced.character;
+            changes = advanced.changes;
+        }
+
+        let progress = character.progress.unwrap();
+        assert_eq!(progress.tier, 2);
+        assert_eq!(progress.free_xp, 0);
+        assert_eq!(progress.advancements, Some(Advancements::default()));
+        assert_eq!(changes.last(), Some(&Change::ReachedTier(2)));
+    }
+
+    #[rstest]
+    fn stays_at_max_tier() {
+        let mut character = with_xp(MAX_TIER, 20);
+        for advancement in core_advancements() {
+            character = advance(&character, advancement).unwrap().character;
+        }
+
+        assert_eq!(character.progress.as_ref().unwrap().tier, MAX_TIER);
+        assert_eq!(
+            advance(&character, Advancement::ExtraEffort)
+                .unwrap_err()
+                .code(),
+            ErrorCode::AlreadyAdvanced
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn advancing_spends_xp_once_per_tier(
+            character in character(),
+            xp in 0u32..20,
+            order in Just(core_advancements()).prop_shuffle(),
+        ) {
+            let mut character = award_xp(&character, xp);
+            let tier = character.progress.as_ref().unwrap().tier;
+            let free_xp = character.progress.as_ref().unwrap().free_xp;
+
+            let mut bought = 0;
+            for advancement in order {
+                match advance(&character, advancement) {
+                    Ok(advanced) => {
+                        character = advanced.character;
+                        bought += 1;
+                    }
+                    Err(err) => {
+                        prop_assert_eq!(err.code(), ErrorCode::NotEnoughXp)
+                    }
+                }
+            }
+
+            let progress = character.progress.unwrap();
+            prop_assert_eq!(
+                progress.free_xp,
+                free_xp - bought * ADVANCEMENT_COST
+            );
+            let expected_tier = match bought {
+                4 => (tier + 1).min(MAX_TIER),
+                _ => tier,
+            };
+            prop_assert_eq!(progress.tier, expected_tier);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use std::{fmt::Display, str::FromStr};
+
+use proto_rs::character::Character;
+use rand::Rng;
+
+use crate::{
+    dice::{Dice, DiceRoll},
+    error::{Error, ErrorCode},
+};
+
+// Depletion of a cypher or artifact, parsed from texts like "1 in 1d6",
+// "1-2 in 1d10" or "automatic".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Depletion {
+    // Never depletes, written as "", "-" or "none".
+    Never,
+    // Automatic depletion after a single use.
+    Automatic,
+    // Chance to deplete when rolling between low and high on dice.
+    Chance { low: i64, high: i64, dice: Dice },
+}
+
+impl Depletion {
+    // roll whether a use depletes, returning the roll if dice were rolled.
+    pub fn roll<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> (bool, Option<DiceRoll>) {
+        match self {
+            Depletion::Never => (false, None),
+            Depletion::Automatic => (true, None),
+            Depletion::Chance { low, high, dice } => {
+                let roll = dice.roll(rng);
+                ((*low..=*high).contains(&roll.total), Some(roll))
+            }
+        }
+    }
+}
+
+impl FromStr for Depletion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| {
+            Error::new(
+                ErrorCode::InvalidDepletion,
+                &format!("invalid depletion \"{s}\": {reason}"),
+            )
+        };
+        let text = s.trim().to_lowercase();
+        match text.as_str() {
+            "" | "-" | "—" | "none" => return Ok(Depletion::Never),
+            "automatic" => return Ok(Depletion::Automatic),
+            _ => {}
+        }
+
+        let (range, dice) = text
+            .split_once(" in ")
+            .ok_or_else(|| invalid("expected a chance like 1 in 1d6"))?;
+        let dice: Dice =
+            dice.parse().map_err(|err: Error| invalid(&err.message()))?;
+        let number = |n: &str| {
+            n.trim()
+                .parse::<i64>()
+                .map_err(|_| invalid("chance is not a number"))
+        };
+        let (low, high) = match range.split_once(['-', '–']) {
+            Some((low, high)) => (number(low)?, number(high)?),
+            None => (number(range)?, number(range)?),
+        };
+
+        if low > high || low < dice.min() || high > dice.max() {
+            return Err(invalid(&format!("chance can't be rolled on {dice}")));
+        }
+        Ok(Depletion::Chance { low, high, dice })
+    }
+}
+
+impl Display for Depletion {
+    // fmt in normalized form, e.g. "1 in 1d6" or "1-2 in 1d10".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Depletion::Never => f.write_str("none"),
+            Depletion::Automatic => f.write_str("automatic"),
+            Depletion::Chance { low, high, dice } => {
+                if low == high {
+                    write!(f, "{low} in ")?;
+                } else {
+                    write!(f, "{low}-{high} in ")?;
+                }
+                if dice.count() == 1 {
+                    f.write_str("1")?;
+                }
+                write!(f, "{dice}")
+            }
+        }
+    }
+}
+
+// check_cypher_limit fails if character carries more cyphers than its
+// Character.cypher_limit. A limit of 0 is unknown and never exceeded.
+pub fn check_cypher_limit(character: &Character) -> Result<(), Error> {
+    let limit = usize::try_from(character.cypher_limit).unwrap_or(0);
+    if limit == 0 || character.cyphers.len() <= limit {
+        return Ok(());
+    }
+    Err(Error::new(
+        ErrorCode::CypherLimitExceeded,
+        &format!(
+            "carrying {} cyphers exceeds the limit of {limit}",
+            character.cyphers.len()
+        ),
+    ))
+}
+
+// ArtifactUse is the character after using an artifact.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtifactUse {
+    pub character: Character,
+    // depleted is true if the artifact was depleted by the use.
+    pub depleted: bool,
+    // roll of the depletion dice, None if no dice were rolled.
+    pub roll: Option<DiceRoll>,
+}
+
+// use_artifact rolls the depletion of the artifact with the given uuid,
+// marking it inactive if it is depleted. Depleted artifacts can't be used.
+pub fn use_artifact<R: Rng + ?Sized>(
+    character: &Character,
+    uuid: &str,
+    rng: &mut R,
+) -> Result<ArtifactUse, Error> {
+    let mut character = character.clone();
+    let artifact = character
+        .artifacts
+        .iter_mut()
+        .find(|artifact| artifact.uuid == uuid)
+        .ok_or_else(|| {
+            Error::new(ErrorCode::NotFound, &format!("no artifact {uuid}"))
+        })?;
+    if !artifact.active {
+        return Err(Error::new(
+            ErrorCode::ArtifactDepleted,
+            &format!("{} is depleted", artifact.name),
+        ));
+    }
+
+    let (depleted, roll) = artifact.depletion.parse::<Depletion>()?.roll(rng);
+    if depleted {
+        artifact.active = false;
+    }
+    Ok(ArtifactUse {
+        character,
+        depleted,
+        roll,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use proto_rs::character::{Artifact, Character, Cypher};
+    use rand::{rngs::StdRng, SeedableRng};
+    use rstest::rstest;
+
+    use super::{check_cypher_limit, use_artifact, Depletion};
+    use crate::{Dice, ErrorCode};
+
+    fn d(sides: u32) -> Dice {
+        Dice::new(1, sides, 0).unwrap()
+    }
+
+    fn chance(low: i64, high: i64, dice: Dice) -> Depletion {
+        Depletion::Chance { low, high, dice }
+    }
+
+    fn artifact(depletion: &str, active: bool) -> Character {
+        Character {
+            artifacts: vec![Artifact {
+                uuid: "artifact-1".to_owned(),
+                name: "Lightning Gloves".to_owned(),
+                depletion: depletion.to_owned(),
+                active,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case("1 in 1d6", chance(1, 1, d(6)))]
+    #[case("1 in d20", chance(1, 1, d(20)))]
+    #[case("1-2 in 1d10", chance(1, 2, d(10)))]
+    #[case(" 1 – 3 IN 1d100 ", chance(1, 3, d(100)))]
+    #[case("Automatic", Depletion::Automatic)]
+    #[case("", Depletion::Never)]
+    #[case("—", Depletion::Never)]
+    fn parses_depletion(#[case] text: &str, #[case] expected: Depletion) {
+        assert_eq!(text.parse::<Depletion>(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::no_chance("1d6")]
+    #[case::words("sometimes")]
+    #[case::not_a_number("one in 1d6")]
+    #[case::outside_dice("7 in 1d6")]
+    #[case::reversed_range("3-1 in 1d6")]
+    fn rejects_invalid_depletion(#[case] text: &str) {
+        assert_eq!(
+            text.parse::<Depletion>().map_err(|err| err.code()),
+            Err(ErrorCode::InvalidDepletion)
+        );
+    }
+
+    #[rstest]
+    fn rejects_invalid_depletion_dice() {
+        assert_eq!(
+            "1 in 1x6".parse::<Depletion>().map_err(|err| err.code()),
+            Err(ErrorCode::InvalidDepletion)
+        );
+    }
+
+    #[rstest]
+    #[case("1 in d6", "1 in 1d6")]
+    #[case("1–2 in 1d10", "1-2 in 1d10")]
+    #[case("2 in 2d6", "2 in 2d6")]
+    #[case("AUTOMATIC", "automatic")]
+    #[case("-", "none")]
+    fn displays_normalized(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(text.parse::<Depletion>().unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::unknown_limit(0, 4, true)]
+    #[case::below_limit(3, 2, true)]
+    #[case::at_limit(3, 3, true)]
+    #[case::above_limit(3, 4, false)]
+    fn checks_cypher_limit(
+        #[case] limit: i32,
+        #[case] cyphers: usize,
+        #[case] ok: bool,
+    ) {
+        let character = Character {
+            cypher_limit: limit,
+            cyphers: vec![Cypher::default(); cyphers],
+            ..Default::default()
+        };
+
+        let result = check_cypher_limit(&character);
+
+        assert_eq!(result.is_ok(), ok);
+        if let Err(err) = result {
+            assert_eq!(err.code(), ErrorCode::CypherLimitExceeded);
+        }
+    }
+
+    #[rstest]
+    fn automatic_depletion_deactivates() {
+        let used = use_artifact(
+            &artifact("automatic", true),
+            "artifact-1",
+            &mut StdRng::seed_from_u64(1),
+        )
+        .unwrap();
+
+        assert!(used.depleted);
+        assert_eq!(used.roll, None);
+        assert!(!used.character.artifacts[0].active);
+    }
+
+    #[rstest]
+    fn never_depletes() {
+        let used = use_artifact(
+            &artifact("", true),
+            "artifact-1",
+            &mut StdRng::seed_from_u64(1),
+        )
+        .unwrap();
+
+        assert!(!used.depleted);
+        assert!(used.character.artifacts[0].active);
+    }
+
+    #[rstest]
+    #[case::missing("artifact-2", true, ErrorCode::NotFound)]
+    #[case::depleted("artifact-1", false, ErrorCode::ArtifactDepleted)]
+    fn rejects_unusable_artifacts(
+        #[case] uuid: &str,
+        #[case] active: bool,
+        #[case] expected: ErrorCode,
+    ) {
+        let err = use_artifact(
+            &artifact("1 in 1d6", active),
+            uuid,
+            &mut StdRng::seed_from_u64(1),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code(), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn depletes_on_chance(
+            sides in 1u32..100,
+            seed in any::<u64>(),
+        ) {
+            let depletion = format!("1 in 1d{sides}");
+            let used = use_artifact(
+                &artifact(&depletion, true),
+                "artifact-1",
+                &mut StdRng::seed_from_u64(seed),
+            )
+            .unwrap();
+
+            let roll = used.roll.unwrap();
+            prop_assert_eq!(used.depleted, roll.total == 1);
+            prop_assert_eq!(used.character.artifacts[0].active, !used.depleted);
+        }
+
+        #[test]
+        fn display_parses_back(
+            low in 1i64..10,
+            width in 0i64..10,
+            sides in 20u32..100,
+        ) {
+            let depletion = chance(low, low + width, d(sides));
+
+            prop_assert_eq!(
+                depletion.to_string().parse::<Depletion>(),
+                Ok(depletion)
+            );
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use proto_rs::character::{Character, Damage, PoolType};
+
+use crate::pools::{points, stat, stat_mut, POOLS};
+
+// DamageTrack of a character, each pool reaching 0 moves the character one
+// step down the track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DamageTrack {
+    Hale,
+    Impaired,
+    Debilitated,
+    Dead,
+}
+
+impl DamageTrack {
+    // of character as recorded in its Damage. Dead characters are debilitated
+    // with all pools at 0.
+    pub fn of(character: &Character) -> DamageTrack {
+        let damage = character.damage.clone().unwrap_or_default();
+        if damage.debilitated {
+            let empty =
+                POOLS.iter().all(|pool| stat(character, *pool).pool <= 0);
+            if empty {
+                DamageTrack::Dead
+            } else {
+                DamageTrack::Debilitated
+            }
+        } else if damage.impaired {
+            DamageTrack::Impaired
+        } else {
+            DamageTrack::Hale
+        }
+    }
+
+    // worsen moves one step down the track.
+    pub fn worsen(self) -> DamageTrack {
+        match self {
+            DamageTrack::Hale => DamageTrack::Impaired,
+            DamageTrack::Impaired => DamageTrack::Debilitated,
+            DamageTrack::Debilitated | DamageTrack::Dead => DamageTrack::Dead,
+        }
+    }
+
+    // improve moves one step up the track, there is no recovering from death.
+    pub fn improve(self) -> DamageTrack {
+        match self {
+            DamageTrack::Hale | DamageTrack::Impaired => DamageTrack::Hale,
+            DamageTrack::Debilitated => DamageTrack::Impaired,
+            DamageTrack::Dead => DamageTrack::Dead,
+        }
+    }
+
+    // set records the track in the Damage of character, emptying all pools of
+    // dead characters.
+    pub fn set(self, character: &mut Character) {
+        character.damage = Some(Damage {
+            impaired: self >= DamageTrack::Impaired,
+            debilitated: self >= DamageTrack::Debilitated,
+        });
+        if self == DamageTrack::Dead {
+            for pool in POOLS {
+                stat_mut(character, pool).pool = 0;
+            }
+        }
+    }
+}
+
+// apply_damage deals amount points of damage to pool. Damage exceeding the
+// pool cascades through the other pools in the order of POOLS, every pool
+// reaching 0 moves the character down the damage track.
+pub fn apply_damage(
+    character: &Character,
+    pool: PoolType,
+    amount: u32,
+) -> Character {
+    let mut character = character.clone();
+    let cascade = std::iter::once(pool)
+        .chain(POOLS.into_iter().filter(|other| *other != pool));
+
+    let mut remaining = amount;
+    for pool in cascade {
+        if remaining == 0 {
+            break;
+        }
+        remaining -= reduce_pool(&mut character, pool, remaining);
+    }
+    character
+}
+
+// reduce_pool takes up to amount points from pool, returning how many were
+// taken. Emptying the pool moves the character down the damage track.
+pub(crate) fn reduce_pool(
+    character: &mut Character,
+    pool: PoolType,
+    amount: u32,
+) -> u32 {
+    let stat = stat_mut(character, pool);
+    if stat.pool <= 0 {
+        return 0;
+    }
+
+    let taken = stat.pool.min(points(amount));
+    stat.pool -= taken;
+    if stat.pool == 0 {
+        DamageTrack::of(character).worsen().set(character);
+    }
+    taken.unsigned_abs()
+}
+
+// restore_pool adds up to amount points to pool without exceeding its cap,
+// returning how many were added. Refilling an empty pool moves the character
+// up the damage track.
+pub(crate) fn restore_pool(
+    character: &mut Character,
+    pool: PoolType,
+    amount: u32,
+) -> u32 {
+    let stat = stat_mut(character, pool);
+    let before = stat.pool.max(0);
+    let added = (stat.cap - before).clamp(0, points(amount));
+    stat.pool = before + added;
+    if before == 0 && added > 0 {
+        DamageTrack::of(character).improve().set(character);
+    }
+    added.unsigned_abs()
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use proto_rs::character::{Character, Damage, PoolType, Stat, Stats};
+    use rstest::rstest;
+
+    use super::{apply_damage, DamageTrack};
+    use crate::{
+        pools::{stat, POOLS},
+        testing::{character, pool_type},
+    };
+
+    fn pools(might: i32, speed: i32, intellect: i32) -> Character {
+        let stat = |pool_type: PoolType, pool| {
+            Some(Stat {
+                r#type: pool_type.into(),
+                cap: 10,
+                pool,
+                edge: 0,
+            })
+        };
+        Character {
+            stats: Some(Stats {
+                might: stat(PoolType::Might, might),
+                speed: stat(PoolType::Speed, speed),
+                intellect: stat(PoolType::Intellect, intellect),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn pool_values(character: &Character) -> [i32; 3] {
+        POOLS.map(|pool| stat(character, pool).pool)
+    }
+
+    #[rstest]
+    #[case::hale(false, false, DamageTrack::Hale)]
+    #[case::impaired(true, false, DamageTrack::Impaired)]
+    #[case::debilitated(true, true, DamageTrack::Debilitated)]
+    #[case::debilitated_only(false, true, DamageTrack::Debilitated)]
+    fn reads_damage_track(
+        #[case] impaired: bool,
+        #[case] debilitated: bool,
+        #[case] expected: DamageTrack,
+    ) {
+        let character = Character {
+            damage: Some(Damage {
+                impaired,
+                debilitated,
+            }),
+            ..pools(5, 5, 5)
+        };
+
+        assert_eq!(DamageTrack::of(&character), expected);
+    }
+
+    #[rstest]
+    fn debilitated_without_pools_is_dead() {
+        let mut character = pools(0, 0, 0);
+        DamageTrack::Debilitated.set(&mut character);
+
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Dead);
+    }
+
+    #[rstest]
+    #[case(DamageTrack::Hale, DamageTrack::Impaired, DamageTrack::Hale)]
+    #[case(DamageTrack::Impaired, DamageTrack::Debilitated, DamageTrack::Hale)]
+    #[case(DamageTrack::Debilitated, DamageTrack::Dead, DamageTrack::Impaired)]
+    #[case(DamageTrack::Dead, DamageTrack::Dead, DamageTrack::Dead)]
+    fn moves_along_track(
+        #[case] track: DamageTrack,
+        #[case] worse: DamageTrack,
+        #[case] better: DamageTrack,
+    ) {
+        assert_eq!(track.worsen(), worse);
+        assert_eq!(track.improve(), better);
+    }
+
+    #[rstest]
+    fn damage_reduces_might() {
+        let character = apply_damage(&pools(5, 5, 5), PoolType::Might, 3);
+
+        assert_eq!(pool_values(&character), [2, 5, 5]);
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Hale);
+    }
+
+    #[rstest]
+    fn damage_cascades_to_speed_and_intellect() {
+        let character = apply_damage(&pools(2, 3, 5), PoolType::Might, 7);
+
+        assert_eq!(pool_values(&character), [0, 0, 3]);
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Debilitated);
+    }
+
+    #[rstest]
+    fn damage_to_speed_cascades_to_might_first() {
+        let character = apply_damage(&pools(5, 2, 5), PoolType::Speed, 4);
+
+        assert_eq!(pool_values(&character), [3, 0, 5]);
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Impaired);
+    }
+
+    #[rstest]
+    fn damage_skips_empty_pools() {
+        let mut character = pools(0, 5, 5);
+        DamageTrack::Impaired.set(&mut character);
+
+        let character = apply_damage(&character, PoolType::Might, 2);
+
+        assert_eq!(pool_values(&character), [0, 3, 5]);
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Impaired);
+    }
+
+    #[rstest]
+    fn emptying_all_pools_kills() {
+        let character = apply_damage(&pools(1, 1, 1), PoolType::Might, 10);
+
+        assert_eq!(pool_values(&character), [0, 0, 0]);
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Dead);
+    }
+
+    #[rstest]
+    fn emptying_pool_while_debilitated_kills() {
+        let mut character = pools(5, 5, 5);
+        DamageTrack::Debilitated.set(&mut character);
+
+        let character = apply_damage(&character, PoolType::Might, 5);
+
+        assert_eq!(pool_values(&character), [0, 0, 0]);
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Dead);
+    }
+
+    proptest! {
+        #[test]
+        fn damage_takes_points_from_pools(
+            character in character(),
+            pool in pool_type(),
+            amount in 0u32..60,
+        ) {
+            let damaged = apply_damage(&character, pool, amount);
+
+            let before = pool_values(&character);
+            let after = pool_values(&damaged);
+            let track = DamageTrack::of(&damaged);
+            prop_assert!(track >= DamageTrack::of(&character));
+            for (before, after) in before.iter().zip(after) {
+                prop_assert!(after >= 0 && after <= *before);
+            }
+            if track != DamageTrack::Dead {
+                let total: i32 = before.iter().sum();
+                let taken = total - after.iter().sum::<i32>();
+                prop_assert_eq!(taken, total.min(amount as i32));
+            }
+        }
+
+        #[test]
+        fn damage_cascades_in_order(
+            character in character(),
+            amount in 0u32..60,
+        ) {
+            let damaged = apply_damage(&character, PoolType::Might, amount);
+
+            let [might, speed, _] = pool_values(&damaged);
+            let [_, speed_before, intellect_before] = pool_values(&character);
+            if speed < speed_before {
+                prop_assert_eq!(might, 0);
+            }
+            if pool_values(&damaged)[2] < intellect_before {
+                prop_assert_eq!(speed, 0);
+            }
+        }
+
+        #[test]
+        fn each_emptied_pool_worsens_track(
+            character in character(),
+            pool in pool_type(),
+            amount in 0u32..60,
+        ) {
+            let damaged = apply_damage(&character, pool, amount);
+
+            let emptied = pool_values(&character)
+                .iter()
+                .zip(pool_values(&damaged))
+                .filter(|(before, after)| **before > 0 && *after == 0)
+                .count();
+            let mut expected = DamageTrack::of(&character);
+            for _ in 0..emptied {
+                expected = expected.worsen();
+            }
+            prop_assert_eq!(DamageTrack::of(&damaged), expected);
+        }
+    }
+}
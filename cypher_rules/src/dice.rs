@@ -0,0 +1,407 @@
+use std::{fmt::Display, str::FromStr};
+
+use rand::Rng;
+
+use crate::error::{Error, ErrorCode};
+
+// MAX_DICE rolled by a single expression.
+pub const MAX_DICE: u32 = 100;
+
+// MAX_SIDES of a die.
+pub const MAX_SIDES: u32 = 1000;
+
+// D20 is the die rolled for tasks.
+pub const D20: Dice = Dice {
+    count: 1,
+    sides: 20,
+    modifier: 0,
+};
+
+// Dice is a dice expression like "d20", "d6+2" or "2d6", created by parsing
+// or Dice::new so it can always be rolled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dice {
+    count: u32,
+    sides: u32,
+    modifier: i32,
+}
+
+// DiceRoll is the result of rolling Dice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiceRoll {
+    pub dice: Dice,
+    // rolls of the single dice.
+    pub rolls: Vec<u32>,
+    pub total: i64,
+}
+
+impl Dice {
+    // new Dice rolling count dice with the given sides, from 1 to MAX_DICE
+    // dice with 1 to MAX_SIDES sides.
+    pub fn new(count: u32, sides: u32, modifier: i32) -> Result<Dice, Error> {
+        let dice = Dice {
+            count,
+            sides,
+            modifier,
+        };
+        dice.check().map_err(|reason| {
+            Error::new(
+                ErrorCode::InvalidDice,
+                &format!("invalid dice \"{dice}\": {reason}"),
+            )
+        })?;
+        Ok(dice)
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn sides(&self) -> u32 {
+        self.sides
+    }
+
+    pub fn modifier(&self) -> i32 {
+        self.modifier
+    }
+
+    // roll the dice using rng, pass a seeded rng for reproducible rolls.
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> DiceRoll {
+        let rolls: Vec<u32> = (0..self.count)
+            .map(|_| rng.gen_range(1..=self.sides))
+            .collect();
+        let total = rolls.iter().map(|roll| i64::from(*roll)).sum::<i64>()
+            + i64::from(self.modifier);
+        DiceRoll {
+            dice: *self,
+            rolls,
+            total,
+        }
+    }
+
+    pub fn min(&self) -> i64 {
+        i64::from(self.count) + i64::from(self.modifier)
+    }
+
+    pub fn max(&self) -> i64 {
+        i64::from(self.count) * i64::from(self.sides) + i64::from(self.modifier)
+    }
+
+    // check the number of dice and their sides.
+    fn check(&self) -> Result<(), String> {
+        if !(1..=MAX_DICE).contains(&self.count) {
+            return Err(format!("roll 1 to {MAX_DICE} dice"));
+        }
+        if !(1..=MAX_SIDES).contains(&self.sides) {
+            return Err(format!("dice have 1 to {MAX_SIDES} sides"));
+        }
+        Ok(())
+    }
+}
+
+// digits checks text is a plain number without sign.
+fn digits(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_digit())
+}
+
+impl FromStr for Dice {
+    type Err = Error;
+
+    // from_str parses [count]d<sides>[+-modifier], ignoring whitespace and
+    // case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| {
+            Error::new(
+                ErrorCode::InvalidDice,
+                &format!("invalid dice \"{s}\": {reason}"),
+            )
+        };
+        let expr: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+
+        let (count, rest) = expr
+            .split_once('d')
+            .ok_or_else(|| invalid("expected a d like in 2d6"))?;
+        let count = match count {
+            "" => 1,
+            count if digits(count) => count
+                .parse()
+                .map_err(|_| invalid("count is not a number"))?,
+            _ => return Err(invalid("count is not a number")),
+        };
+
+        let (sides, modifier) = match rest.find(['+', '-']) {
+            Some(at) => rest.split_at(at),
+            None => (rest, ""),
+        };
+        let sides: u32 = match sides {
+            sides if digits(sides) => sides
+                .parse()
+                .map_err(|_| invalid("sides are not a number"))?,
+            _ => return Err(invalid("sides are not a number")),
+        };
+        // The sign is part of the modifier, so only digits may follow it.
+        let modifier = match modifier.split_at(modifier.len().min(1)) {
+            ("", _) => 0,
+            (sign, number) if digits(number) => format!("{sign}{number}")
+                .parse()
+                .map_err(|_| invalid("modifier is not a number"))?,
+            _ => return Err(invalid("modifier is not a number")),
+        };
+
+        let dice = Dice {
+            count,
+            sides,
+            modifier,
+        };
+        dice.check().map_err(|reason| invalid(&reason))?;
+        Ok(dice)
+    }
+}
+
+impl Display for Dice {
+    // fmt in normalized form, e.g. d20, 2d6 or d6+2.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.count != 1 {
+            write!(f, "{}", self.count)?;
+        }
+        write!(f, "d{}", self.sides)?;
+        match self.modifier {
+            0 => Ok(()),
+            modifier if modifier > 0 => write!(f, "+{modifier}"),
+            modifier => write!(f, "{modifier}"),
+        }
+    }
+}
+
+// Special outcomes of a task roll depending on the natural d20 roll. Only
+// successful tasks get damage bonuses and effects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Special {
+    // GmIntrusion on a natural 1.
+    GmIntrusion,
+    // DamageBonus of 1 on a 17 and 2 on an 18.
+    DamageBonus(u32),
+    // MinorEffect on a 19, or 3 additional damage.
+    MinorEffect,
+    // MajorEffect on a 20, or 4 additional damage.
+    MajorEffect,
+}
+
+impl Special {
+    // of the natural d20 roll of a task, if any. A GM intrusion happens
+    // whether the task succeeded or not.
+    pub fn of(natural: u32, success: bool) -> Option<Special> {
+        match natural {
+            1 => Some(Special::GmIntrusion),
+            _ if !success => None,
+            17 => Some(Special::DamageBonus(1)),
+            18 => Some(Special::DamageBonus(2)),
+            19 => Some(Special::MinorEffect),
+            20 => Some(Special::MajorEffect),
+            _ => None,
+        }
+    }
+}
+
+// TaskRoll is a d20 roll against the target number of a task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskRoll {
+    pub natural: u32,
+    pub target: u32,
+    pub success: bool,
+    pub special: Option<Special>,
+}
+
+impl TaskRoll {
+    // new interprets the natural d20 roll against target.
+    pub fn new(natural: u32, target: u32) -> TaskRoll {
+        let success = natural >= target;
+        TaskRoll {
+            natural,
+            target,
+            success,
+            special: Special::of(natural, success),
+        }
+    }
+}
+
+// roll_task rolls a d20 against target, the target number of a task.
+pub fn roll_task<R: Rng + ?Sized>(rng: &mut R, target: u32) -> TaskRoll {
+    TaskRoll::new(rng.gen_range(1..=20), target)
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use rstest::rstest;
+
+    use super::{roll_task, Dice, Special, TaskRoll, D20};
+    use crate::ErrorCode;
+
+    #[rstest]
+    #[case("d20", D20)]
+    #[case("1d20", D20)]
+    #[case(
+        "2d6",
+        Dice {
+            count: 2,
+            sides: 6,
+            modifier: 0
+        }
+    )]
+    #[case(
+        "d6+2",
+        Dice {
+            count: 1,
+            sides: 6,
+            modifier: 2
+        }
+    )]
+    #[case(
+        " 3D10 - 1 ",
+        Dice {
+            count: 3,
+            sides: 10,
+            modifier: -1
+        }
+    )]
+    fn parses_dice(#[case] expr: &str, #[case] expected: Dice) {
+        assert_eq!(expr.parse::<Dice>(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::number("20")]
+    #[case::no_sides("2d")]
+    #[case::no_dice("0d6")]
+    #[case::too_many_dice("101d6")]
+    #[case::no_faces("d0")]
+    #[case::words("d twenty")]
+    #[case::double_modifier("d6+2+1")]
+    #[case::missing_modifier("d6+")]
+    #[case::signed_count("+2d6")]
+    #[case::negative_count("-2d6")]
+    #[case::signed_sides("d+6")]
+    #[case::double_sign("d6+-2")]
+    fn rejects_invalid_dice(#[case] expr: &str) {
+        assert_eq!(
+            expr.parse::<Dice>().map_err(|err| err.code()),
+            Err(ErrorCode::InvalidDice)
+        );
+    }
+
+    #[rstest]
+    #[case("d20", "d20")]
+    #[case("1d6+0", "d6")]
+    #[case("2D6 + 2", "2d6+2")]
+    #[case("d6-1", "d6-1")]
+    fn displays_normalized(#[case] expr: &str, #[case] expected: &str) {
+        assert_eq!(expr.parse::<Dice>().unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(2, 6, 1, Ok("2d6+1"))]
+    #[case(0, 6, 0, Err(()))]
+    #[case(1, 0, 0, Err(()))]
+    #[case(101, 6, 0, Err(()))]
+    fn creates_valid_dice(
+        #[case] count: u32,
+        #[case] sides: u32,
+        #[case] modifier: i32,
+        #[case] expected: Result<&str, ()>,
+    ) {
+        let dice = Dice::new(count, sides, modifier);
+
+        match expected {
+            Ok(expr) => assert_eq!(dice.unwrap().to_string(), expr),
+            Err(()) => {
+                assert_eq!(dice.unwrap_err().code(), ErrorCode::InvalidDice)
+            }
+        }
+    }
+
+    #[rstest]
+    fn seeded_rolls_are_reproducible() {
+        let dice: Dice = "4d6+1".parse().unwrap();
+
+        let first = dice.roll(&mut StdRng::seed_from_u64(7));
+        let second = dice.roll(&mut StdRng::seed_from_u64(7));
+
+        assert_eq!(first, second);
+        assert_eq!(first.rolls.len(), 4);
+    }
+
+    #[rstest]
+    #[case::gm_intrusion(1, 3, false, Some(Special::GmIntrusion))]
+    #[case::failure(8, 9, false, None)]
+    #[case::success(9, 9, true, None)]
+    #[case::damage_bonus(17, 12, true, Some(Special::DamageBonus(1)))]
+    #[case::more_damage(18, 12, true, Some(Special::DamageBonus(2)))]
+    #[case::minor_effect(19, 12, true, Some(Special::MinorEffect))]
+    #[case::major_effect(20, 20, true, Some(Special::MajorEffect))]
+    #[case::missed_major_effect(20, 30, false, None)]
+    #[case::missed_damage_bonus(17, 18, false, None)]
+    #[case::routine(1, 0, true, Some(Special::GmIntrusion))]
+    fn interprets_task_rolls(
+        #[case] natural: u32,
+        #[case] target: u32,
+        #[case] success: bool,
+        #[case] special: Option<Special>,
+    ) {
+        assert_eq!(
+            TaskRoll::new(natural, target),
+            TaskRoll {
+                natural,
+                target,
+                success,
+                special,
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn rolls_within_bounds(
+            count in 1u32..10,
+            sides in 1u32..30,
+            modifier in -5i32..5,
+            seed in any::<u64>(),
+        ) {
+            let dice = Dice { count, sides, modifier };
+            let roll = dice.roll(&mut StdRng::seed_from_u64(seed));
+
+            prop_assert!(roll.rolls.iter().all(|r| (1..=sides).contains(r)));
+            prop_assert!(dice.min() <= roll.total && roll.total <= dice.max());
+        }
+
+        #[test]
+        fn display_parses_back(
+            count in 1u32..100,
+            sides in 1u32..1000,
+            modifier in -50i32..50,
+        ) {
+            let dice = Dice { count, sides, modifier };
+
+            prop_assert_eq!(dice.to_string().parse::<Dice>(), Ok(dice));
+        }
+
+        #[test]
+        fn task_rolls_d20(seed in any::<u64>(), target in 0u32..=30) {
+            let roll = roll_task(&mut StdRng::seed_from_u64(seed), target);
+
+            prop_assert!((1..=20).contains(&roll.natural));
+            prop_assert_eq!(roll.success, roll.natural >= target);
+            if !roll.success {
+                prop_assert!(matches!(
+                    roll.special,
+                    None | Some(Special::GmIntrusion)
+                ));
+            }
+        }
+    }
+}
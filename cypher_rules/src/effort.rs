@@ -0,0 +1,325 @@
+use proto_rs::character::{Character, PoolType, Skill, SkillLevel};
+
+use crate::{
+    damage::reduce_pool,
+    error::{Error, ErrorCode},
+    pools::{check_pool, cost_with_edge, stat},
+};
+
+// MAX_DIFFICULTY of a task, tasks of this difficulty are impossible.
+pub const MAX_DIFFICULTY: u32 = 10;
+
+// MAX_ASSETS counting towards a single task.
+pub const MAX_ASSETS: u32 = 2;
+
+// Task a character attempts, rolled against a stat.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Task<'a> {
+    pub pool: PoolType,
+    // difficulty of the task before any modifiers, between 0 and
+    // MAX_DIFFICULTY.
+    pub difficulty: u32,
+    // skills applying to the task, e.g. trained in climbing.
+    pub skills: &'a [Skill],
+    // assets easing the task, at most MAX_ASSETS count.
+    pub assets: u32,
+    // effort levels spent on easing the task.
+    pub effort: u32,
+    // cost of the ability used for the task, spent together with the effort.
+    pub ability_cost: u32,
+}
+
+// TaskPlan is what it takes to attempt a Task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskPlan {
+    pub pool: PoolType,
+    // difficulty of the task after all modifiers.
+    pub difficulty: u32,
+    // target number the d20 roll needs to reach.
+    pub target: u32,
+    // cost taken from the pool, reduced by the edge of the stat.
+    pub cost: u32,
+}
+
+impl TaskPlan {
+    // spend takes the cost of the plan from the pool of character.
+    pub fn spend(&self, character: &Character) -> Character {
+        let mut character = character.clone();
+        reduce_pool(&mut character, self.pool, self.cost);
+        character
+    }
+}
+
+// effort_cost of spending levels of effort, 3 points for the first level and
+// 2 for each further one. Costs beyond u32::MAX saturate.
+pub fn effort_cost(levels: u32) -> u32 {
+    match levels {
+        0 => 0,
+        levels => (levels - 1).saturating_mul(2).saturating_add(3),
+    }
+}
+
+// skill_modifier by how many steps skills ease a task. Trained eases by one
+// step and specialized by two, an inability hinders by one step. Skills add
+// up, but never beyond specialized or a single inability.
+pub fn skill_modifier(skills: &[Skill]) -> i32 {
+    skills
+        .iter()
+        .map(|skill| match skill.level() {
+            SkillLevel::Specialized => 2,
+            SkillLevel::Trained => 1,
+            SkillLevel::Inability => -1,
+        })
+        .sum::<i32>()
+        .clamp(-1, 2)
+}
+
+// plan_task works out the difficulty and cost of task for character. Spending
+// more effort than Progress.max_effort allows or more points than are in the
+// pool fails.
+pub fn plan_task(
+    character: &Character,
+    task: &Task,
+) -> Result<TaskPlan, Error> {
+    if task.difficulty > MAX_DIFFICULTY {
+        return Err(Error::new(
+            ErrorCode::InvalidDifficulty,
+            &format!(
+                "difficulty {} is above {MAX_DIFFICULTY}",
+                task.difficulty
+            ),
+        ));
+    }
+
+    let max_effort = character
+        .progress
+        .as_ref()
+        .map_or(0, |progress| progress.max_effort.max(0).unsigned_abs());
+    if task.effort > max_effort {
+        return Err(Error::new(
+            ErrorCode::TooMuchEffort,
+            &format!(
+                "{} levels of effort exceed the maximum of {max_effort}",
+                task.effort
+            ),
+        ));
+    }
+
+    let cost = cost_with_edge(
+        &stat(character, task.pool),
+        effort_cost(task.effort).saturating_add(task.ability_cost),
+    );
+    check_pool(character, task.pool, cost)?;
+
+    let eased = i64::from(skill_modifier(task.skills))
+        + i64::from(task.assets.min(MAX_ASSETS))
+        + i64::from(task.effort);
+    let difficulty = (i64::from(task.difficulty) - eased)
+        .clamp(0, i64::from(MAX_DIFFICULTY)) as u32;
+
+    Ok(TaskPlan {
+        pool: task.pool,
+        difficulty,
+        target: difficulty * 3,
+        cost,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use proto_rs::character::{
+        Character, PoolType, Progress, Skill, SkillLevel, Stat, Stats,
+    };
+    use rstest::rstest;
+
+    use super::{effort_cost, plan_task, skill_modifier, Task, TaskPlan};
+    use crate::{
+        pools::{cost_with_edge, stat},
+        testing::{character, pool_type},
+        ErrorCode,
+    };
+
+    fn skill(level: SkillLevel) -> Skill {
+        Skill {
+            level: level.into(),
+            ..Default::default()
+        }
+    }
+
+    fn character_with(pool: i32, edge: i32, max_effort: i32) -> Character {
+        Character {
+            stats: Some(Stats {
+                speed: Some(Stat {
+                    r#type: PoolType::Speed.into(),
+                    cap: 20,
+                    pool,
+                    edge,
+                }),
+                ..Default::default()
+            }),
+            progress: Some(Progress {
+                max_effort,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn task(difficulty: u32, effort: u32) -> Task<'static> {
+        Task {
+            pool: PoolType::Speed,
+            difficulty,
+            skills: &[],
+            assets: 0,
+            effort,
+            ability_cost: 0,
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 3)]
+    #[case(2, 5)]
+    #[case(3, 7)]
+    #[case(6, 13)]
+    #[case(u32::MAX / 2 - 1, u32::MAX - 2)]
+    #[case(u32::MAX, u32::MAX)]
+    fn costs_effort(#[case] levels: u32, #[case] expected: u32) {
+        assert_eq!(effort_cost(levels), expected);
+    }
+
+    #[rstest]
+    #[case::none(&[], 0)]
+    #[case::trained(&[SkillLevel::Trained], 1)]
+    #[case::specialized(&[SkillLevel::Specialized], 2)]
+    #[case::inability(&[SkillLevel::Inability], -1)]
+    #[case::trained_twice(&[SkillLevel::Trained, SkillLevel::Trained], 2)]
+    #[case::beyond_specialized(
+        &[SkillLevel::Specialized, SkillLevel::Trained],
+        2
+    )]
+    #[case::cancel_out(&[SkillLevel::Trained, SkillLevel::Inability], 0)]
+    #[case::inabilities(&[SkillLevel::Inability, SkillLevel::Inability], -1)]
+    fn modifies_by_skills(
+        #[case] levels: &[SkillLevel],
+        #[case] expected: i32,
+    ) {
+        let skills: Vec<Skill> = levels.iter().map(|l| skill(*l)).collect();
+
+        assert_eq!(skill_modifier(&skills), expected);
+    }
+
+    #[rstest]
+    fn plans_task() {
+        let skills = [skill(SkillLevel::Trained)];
+        let task = Task {
+            skills: &skills,
+            assets: 3,
+            ability_cost: 1,
+            ..task(6, 2)
+        };
+
+        assert_eq!(
+            plan_task(&character_with(10, 1, 2), &task),
+            Ok(TaskPlan {
+                pool: PoolType::Speed,
+                difficulty: 1,
+                target: 3,
+                cost: 5,
+            })
+        );
+    }
+
+    #[rstest]
+    fn plans_task_without_effort() {
+        let plan = plan_task(&character_with(0, 0, 0), &task(4, 0)).unwrap();
+
+        assert_eq!(plan.difficulty, 4);
+        assert_eq!(plan.target, 12);
+        assert_eq!(plan.cost, 0);
+    }
+
+    #[rstest]
+    fn difficulty_stays_within_bounds() {
+        let skills = [skill(SkillLevel::Inability)];
+        let hindered = Task {
+            skills: &skills,
+            ..task(10, 0)
+        };
+        let character = character_with(20, 0, 6);
+
+        assert_eq!(plan_task(&character, &hindered).unwrap().difficulty, 10);
+        assert_eq!(plan_task(&character, &task(1, 3)).unwrap().difficulty, 0);
+    }
+
+    #[rstest]
+    #[case::too_much_effort(
+        character_with(20, 0, 1),
+        task(5, 2),
+        ErrorCode::TooMuchEffort
+    )]
+    #[case::insufficient_pool(
+        character_with(4, 0, 2),
+        task(5, 2),
+        ErrorCode::InsufficientPool
+    )]
+    #[case::invalid_difficulty(
+        character_with(20, 0, 1),
+        task(11, 0),
+        ErrorCode::InvalidDifficulty
+    )]
+    fn rejects_invalid_spends(
+        #[case] character: Character,
+        #[case] task: Task<'static>,
+        #[case] expected: ErrorCode,
+    ) {
+        assert_eq!(
+            plan_task(&character, &task).map_err(|err| err.code()),
+            Err(expected)
+        );
+    }
+
+    #[rstest]
+    fn spends_planned_cost() {
+        let character = character_with(10, 1, 2);
+        let plan = plan_task(&character, &task(5, 2)).unwrap();
+
+        let spent = plan.spend(&character);
+
+        assert_eq!(stat(&spent, PoolType::Speed).pool, 6);
+    }
+
+    proptest! {
+        #[test]
+        fn plan_is_consistent(
+            character in character(),
+            pool in pool_type(),
+            difficulty in 0u32..=10,
+            effort in 0u32..=6,
+            assets in 0u32..4,
+        ) {
+            let task = Task { pool, assets, ..task(difficulty, effort) };
+
+            match plan_task(&character, &task) {
+                Ok(plan) => {
+                    let before = stat(&character, pool);
+                    prop_assert_eq!(plan.target, plan.difficulty * 3);
+                    prop_assert!(plan.difficulty <= difficulty);
+                    prop_assert_eq!(
+                        plan.cost,
+                        cost_with_edge(&before, effort_cost(effort))
+                    );
+                    prop_assert_eq!(
+                        stat(&plan.spend(&character), pool).pool,
+                        before.pool - plan.cost as i32
+                    );
+                }
+                Err(err) => prop_assert!(matches!(
+                    err.code(),
+                    ErrorCode::TooMuchEffort | ErrorCode::InsufficientPool
+                )),
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ErrorCode {
+    // InsufficientPool errors are spends exceeding the points in a pool.
+    InsufficientPool,
+    // TooMuchEffort errors are spends of more effort than a character can
+    // apply.
+    TooMuchEffort,
+    // InvalidDifficulty errors are tasks with a difficulty outside of 0 to 10.
+    InvalidDifficulty,
+    // InvalidDice errors are dice expressions that can't be parsed.
+    InvalidDice,
+    // NotEnoughXp errors are advancements costing more XP than is left.
+    NotEnoughXp,
+    // AlreadyAdvanced errors are advancements bought twice in a tier.
+    AlreadyAdvanced,
+    // InvalidAdvancement errors are advancements that can't be applied to the
+    // character.
+    InvalidAdvancement,
+    // InvalidRecovery errors are recovery rolls the character can't take or
+    // distribute.
+    InvalidRecovery,
+    // InvalidDepletion errors are depletion texts that can't be parsed.
+    InvalidDepletion,
+    // CypherLimitExceeded errors are characters carrying more cyphers than
+    // their limit.
+    CypherLimitExceeded,
+    // ArtifactDepleted errors are uses of depleted artifacts.
+    ArtifactDepleted,
+    // InvalidLevel errors are level texts that can't be parsed or rolled.
+    InvalidLevel,
+    // NotFound errors reference items the character doesn't have.
+    NotFound,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Error {
+    code: ErrorCode,
+    message: String,
+}
+
+impl Error {
+    pub fn new(code: ErrorCode, message: &str) -> Error {
+        Error {
+            code,
+            message: message.to_owned(),
+        }
+    }
+
+    pub fn message(&self) -> String {
+        self.message.to_owned()
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,291 @@
+use std::{fmt::Display, str::FromStr};
+
+use proto_rs::character::Character;
+use rand::Rng;
+
+use crate::{
+    dice::Dice,
+    error::{Error, ErrorCode},
+};
+
+// Level of a cypher or artifact, parsed from texts like "5", "1d6+2" or
+// "3-6".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    // Unknown level, written as "", "?" or "-".
+    Unknown,
+    Fixed(u32),
+    // Dice rolled for the level once the item is found.
+    Dice(Dice),
+    // Range of levels between low and high, rolled once the item is found.
+    Range { low: u32, high: u32 },
+}
+
+impl Level {
+    // roll a concrete level, at least 1. Unknown levels and ranges starting
+    // above their end can't be rolled.
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<u32> {
+        match self {
+            Level::Unknown => None,
+            Level::Fixed(level) => Some(*level),
+            Level::Dice(dice) => {
+                let total = dice.roll(rng).total.max(1);
+                Some(u32::try_from(total).unwrap_or(u32::MAX))
+            }
+            Level::Range { low, high } if low <= high => {
+                Some(rng.gen_range(*low..=*high).max(1))
+            }
+            Level::Range { .. } => None,
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| {
+            Error::new(
+                ErrorCode::InvalidLevel,
+                &format!("invalid level \"{s}\": {reason}"),
+            )
+        };
+        let text = s.trim().to_lowercase();
+        match text.as_str() {
+            "" | "?" | "-" | "—" => return Ok(Level::Unknown),
+            text if text.contains('d') => {
+                let dice = text
+                    .parse()
+                    .map_err(|err: Error| invalid(&err.message()))?;
+                return Ok(Level::Dice(dice));
+            }
+            _ => {}
+        }
+
+        let number = |n: &str| {
+            n.trim()
+                .parse::<u32>()
+                .map_err(|_| invalid("level is not a number"))
+        };
+        let level = match text.split_once(['-', '–']) {
+            Some((low, high)) => Level::Range {
+                low: number(low)?,
+                high: number(high)?,
+            },
+            None => Level::Fixed(number(&text)?),
+        };
+
+        match level {
+            Level::Fixed(0) | Level::Range { low: 0, .. } => {
+                Err(invalid("levels start at 1"))
+            }
+            Level::Range { low, high } if low > high => {
+                Err(invalid("range starts above its end"))
+            }
+            Level::Range { low, high } if low == high => Ok(Level::Fixed(low)),
+            level => Ok(level),
+        }
+    }
+}
+
+impl Display for Level {
+    // fmt in normalized form, e.g. "5", "1d6+2" or "3-6".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Unknown => f.write_str("?"),
+            Level::Fixed(level) => write!(f, "{level}"),
+            Level::Dice(dice) => {
+                if dice.count() == 1 {
+                    f.write_str("1")?;
+                }
+                write!(f, "{dice}")
+            }
+            Level::Range { low, high } => write!(f, "{low}-{high}"),
+        }
+    }
+}
+
+// roll_cypher_level rolls the level of the cypher with the given uuid, e.g.
+// when an unidentified cypher is found, and stores it as a fixed level.
+pub fn roll_cypher_level<R: Rng + ?Sized>(
+    character: &Character,
+    uuid: &str,
+    rng: &mut R,
+) -> Result<Character, Error> {
+    let mut character = character.clone();
+    let cypher = character
+        .cyphers
+        .iter_mut()
+        .find(|cypher| cypher.uuid == uuid)
+        .ok_or_else(|| {
+            Error::new(ErrorCode::NotFound, &format!("no cypher {uuid}"))
+        })?;
+
+    let level = cypher.level.parse::<Level>()?.roll(rng).ok_or_else(|| {
+        Error::new(
+            ErrorCode::InvalidLevel,
+            &format!("{} has no level to roll", cypher.name),
+        )
+    })?;
+    cypher.level = level.to_string();
+    Ok(character)
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use proto_rs::character::{Character, Cypher};
+    use rand::{rngs::StdRng, SeedableRng};
+    use rstest::rstest;
+
+    use super::{roll_cypher_level, Level};
+    use crate::{Dice, ErrorCode};
+
+    fn cypher(level: &str) -> Character {
+        Character {
+            cyphers: vec![Cypher {
+                uuid: "cypher-1".to_owned(),
+                name: "Detonation".to_owned(),
+                level: level.to_owned(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case("5", Level::Fixed(5))]
+    #[case(" 12 ", Level::Fixed(12))]
+    #[case(
+        "1d6+2",
+        Level::Dice(Dice::new(1, 6, 2).unwrap())
+    )]
+    #[case(
+        "D10",
+        Level::Dice(Dice::new(1, 10, 0).unwrap())
+    )]
+    #[case("3-6", Level::Range { low: 3, high: 6 })]
+    #[case("3 – 6", Level::Range { low: 3, high: 6 })]
+    #[case("4-4", Level::Fixed(4))]
+    #[case("", Level::Unknown)]
+    #[case("?", Level::Unknown)]
+    fn parses_level(#[case] text: &str, #[case] expected: Level) {
+        assert_eq!(text.parse::<Level>(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::words("high")]
+    #[case::zero("0")]
+    #[case::negative("-3")]
+    #[case::reversed_range("6-3")]
+    #[case::open_range("3-")]
+    fn rejects_invalid_level(#[case] text: &str) {
+        assert_eq!(
+            text.parse::<Level>().map_err(|err| err.code()),
+            Err(ErrorCode::InvalidLevel)
+        );
+    }
+
+    #[rstest]
+    fn rejects_invalid_level_dice() {
+        assert_eq!(
+            "1d".parse::<Level>().map_err(|err| err.code()),
+            Err(ErrorCode::InvalidLevel)
+        );
+        assert_eq!(
+            "add".parse::<Level>().map_err(|err| err.code()),
+            Err(ErrorCode::InvalidLevel)
+        );
+    }
+
+    #[rstest]
+    #[case("05", "5")]
+    #[case("d6 + 2", "1d6+2")]
+    #[case("2D6", "2d6")]
+    #[case("3–6", "3-6")]
+    #[case("", "?")]
+    fn displays_normalized(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(text.parse::<Level>().unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    fn rolls_fixed_level() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        assert_eq!(Level::Fixed(7).roll(&mut rng), Some(7));
+        assert_eq!(Level::Unknown.roll(&mut rng), None);
+        assert_eq!(Level::Range { low: 6, high: 3 }.roll(&mut rng), None);
+        assert_eq!(Level::Range { low: 0, high: 0 }.roll(&mut rng), Some(1));
+    }
+
+    #[rstest]
+    fn rolls_cypher_level() {
+        let rolled = roll_cypher_level(
+            &cypher("1d6+2"),
+            "cypher-1",
+            &mut StdRng::seed_from_u64(3),
+        )
+        .unwrap();
+
+        let level = rolled.cyphers[0].level.parse::<Level>().unwrap();
+        assert!(matches!(level, Level::Fixed(3..=8)), "{level:?}");
+    }
+
+    #[rstest]
+    #[case::missing("cypher-2", "5", ErrorCode::NotFound)]
+    #[case::unknown_level("cypher-1", "", ErrorCode::InvalidLevel)]
+    #[case::invalid_level("cypher-1", "high", ErrorCode::InvalidLevel)]
+    fn rejects_unrollable_cyphers(
+        #[case] uuid: &str,
+        #[case] level: &str,
+        #[case] expected: ErrorCode,
+    ) {
+        let err = roll_cypher_level(
+            &cypher(level),
+            uuid,
+            &mut StdRng::seed_from_u64(1),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code(), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn rolls_within_bounds(
+            low in 1u32..10,
+            width in 0u32..10,
+            sides in 1u32..20,
+            modifier in -5i32..5,
+            seed in any::<u64>(),
+        ) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let high = low + width;
+            let range = Level::Range { low, high }.roll(&mut rng).unwrap();
+            let dice = Dice::new(1, sides, modifier).unwrap();
+            let rolled = Level::Dice(dice).roll(&mut rng).unwrap();
+
+            prop_assert!((low..=high).contains(&range));
+            prop_assert!(rolled >= 1 && i64::from(rolled) <= dice.max().max(1));
+        }
+
+        #[test]
+        fn display_parses_back(
+            low in 1u32..10,
+            width in 1u32..10,
+            count in 1u32..10,
+            sides in 1u32..20,
+            modifier in -5i32..5,
+        ) {
+            let levels = [
+                Level::Fixed(low),
+                Level::Range { low, high: low + width },
+                Level::Dice(Dice::new(count, sides, modifier).unwrap()),
+            ];
+
+            for level in levels {
+                prop_assert_eq!(level.to_string().parse::<Level>(), Ok(level));
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+mod advancement;
+mod cyphers;
+mod damage;
+mod dice;
+mod effort;
+mod error;
+mod level;
+mod pools;
+mod recovery;
+#[cfg(test)]
+mod testing;
+
+pub use advancement::{
+    advance, award_xp, Advanced, Advancement, Change, ADVANCEMENT_COST,
+    CAPABILITY_POINTS, MAX_TIER,
+};
+pub use cyphers::{check_cypher_limit, use_artifact, ArtifactUse, Depletion};
+pub use damage::{apply_damage, DamageTrack};
+pub use dice::{
+    roll_task, Dice, DiceRoll, Special, TaskRoll, D20, MAX_DICE, MAX_SIDES,
+};
+pub use effort::{
+    effort_cost, plan_task, skill_modifier, Task, TaskPlan, MAX_ASSETS,
+    MAX_DIFFICULTY,
+};
+pub use error::{Error, ErrorCode};
+pub use level::{roll_cypher_level, Level};
+pub use pools::{cost_with_edge, spend, stat, POOLS};
+pub use recovery::{
+    next_recovery, recover, reset_recovery, roll_recovery, Distribution,
+    RecoveryRoll, RecoverySlot,
+};
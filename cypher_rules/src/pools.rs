@@ -0,0 +1,187 @@
+use proto_rs::character::{Character, PoolType, Stat, Stats};
+
+use crate::{
+    damage::reduce_pool,
+    error::{Error, ErrorCode},
+};
+
+// POOLS in the order damage cascades through them.
+pub const POOLS: [PoolType; 3] =
+    [PoolType::Might, PoolType::Speed, PoolType::Intellect];
+
+// stat of the given pool, a character without the stat has an empty pool.
+pub fn stat(character: &Character, pool: PoolType) -> Stat {
+    let stat = character.stats.as_ref().and_then(|stats| match pool {
+        PoolType::Intellect => stats.intellect.as_ref(),
+        PoolType::Speed => stats.speed.as_ref(),
+        PoolType::Might => stats.might.as_ref(),
+    });
+    stat.cloned().unwrap_or_else(|| empty_stat(pool))
+}
+
+// stat_mut returns the stat of the given pool, adding an empty one if the
+// character doesn't have it.
+pub(crate) fn stat_mut(character: &mut Character, pool: PoolType) -> &mut Stat {
+    let stats = character.stats.get_or_insert_with(Stats::default);
+    let stat = match pool {
+        PoolType::Intellect => &mut stats.intellect,
+        PoolType::Speed => &mut stats.speed,
+        PoolType::Might => &mut stats.might,
+    };
+    stat.get_or_insert_with(|| empty_stat(pool))
+}
+
+fn empty_stat(pool: PoolType) -> Stat {
+    Stat {
+        r#type: pool.into(),
+        ..Default::default()
+    }
+}
+
+// points converts an amount of points to the type of the pool fields.
+pub(crate) fn points(amount: u32) -> i32 {
+    i32::try_from(amount).unwrap_or(i32::MAX)
+}
+
+// cost_with_edge reduces cost by the edge of stat, a cost never drops below 0.
+pub fn cost_with_edge(stat: &Stat, cost: u32) -> u32 {
+    cost.saturating_sub(stat.edge.max(0).unsigned_abs())
+}
+
+// spend cost points reduced by the edge of the stat from pool. Spending a pool
+// down to 0 moves the character down the damage track.
+pub fn spend(
+    character: &Character,
+    pool: PoolType,
+    cost: u32,
+) -> Result<Character, Error> {
+    let cost = cost_with_edge(&stat(character, pool), cost);
+    check_pool(character, pool, cost)?;
+
+    let mut character = character.clone();
+    reduce_pool(&mut character, pool, cost);
+    Ok(character)
+}
+
+// check_pool fails if pool holds less than cost points.
+pub(crate) fn check_pool(
+    character: &Character,
+    pool: PoolType,
+    cost: u32,
+) -> Result<(), Error> {
+    let available = stat(character, pool).pool.max(0);
+    if points(cost) > available {
+        return Err(Error::new(
+            ErrorCode::InsufficientPool,
+            &format!(
+                "spending {cost} {} needs more than the {available} points in \
+                 the pool",
+                pool.as_str_name(),
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use proto_rs::character::{Character, PoolType, Stat};
+    use rstest::rstest;
+
+    use super::{cost_with_edge, spend, stat, stat_mut};
+    use crate::{
+        testing::{character, pool_type},
+        DamageTrack, ErrorCode,
+    };
+
+    fn with_speed(cap: i32, pool: i32, edge: i32) -> Character {
+        let mut character = Character::default();
+        *stat_mut(&mut character, PoolType::Speed) = Stat {
+            r#type: PoolType::Speed.into(),
+            cap,
+            pool,
+            edge,
+        };
+        character
+    }
+
+    #[rstest]
+    fn missing_stat_is_empty() {
+        let stat = stat(&Character::default(), PoolType::Might);
+
+        assert_eq!(stat.r#type(), PoolType::Might);
+        assert_eq!(stat.pool, 0);
+    }
+
+    #[rstest]
+    #[case::no_edge(3, 0, 3)]
+    #[case::edge(3, 1, 2)]
+    #[case::edge_covers_cost(2, 3, 0)]
+    #[case::negative_edge(3, -1, 3)]
+    fn edge_reduces_cost(
+        #[case] cost: u32,
+        #[case] edge: i32,
+        #[case] expected: u32,
+    ) {
+        let stat = Stat {
+            edge,
+            ..Default::default()
+        };
+
+        assert_eq!(cost_with_edge(&stat, cost), expected);
+    }
+
+    #[rstest]
+    fn spend_reduces_pool() {
+        let character = spend(&with_speed(10, 8, 1), PoolType::Speed, 3)
+            .expect("should spend from pool");
+
+        assert_eq!(stat(&character, PoolType::Speed).pool, 6);
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Hale);
+    }
+
+    #[rstest]
+    fn spend_rejects_insufficient_pool() {
+        let err = spend(&with_speed(10, 2, 0), PoolType::Speed, 3)
+            .expect_err("should reject spend over pool");
+
+        assert_eq!(err.code(), ErrorCode::InsufficientPool);
+    }
+
+    #[rstest]
+    fn spending_pool_empty_impairs() {
+        let character = spend(&with_speed(10, 3, 0), PoolType::Speed, 3)
+            .expect("should spend whole pool");
+
+        assert_eq!(stat(&character, PoolType::Speed).pool, 0);
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Impaired);
+    }
+
+    proptest! {
+        #[test]
+        fn spend_takes_cost_with_edge(
+            character in character(),
+            pool in pool_type(),
+            cost in 0u32..30,
+        ) {
+            let before = stat(&character, pool);
+            let cost_with_edge = cost_with_edge(&before, cost) as i32;
+
+            match spend(&character, pool, cost) {
+                Ok(spent) => {
+                    prop_assert!(cost_with_edge <= before.pool);
+                    let after = stat(&spent, pool);
+                    prop_assert_eq!(after.pool, before.pool - cost_with_edge);
+                    prop_assert!(
+                        DamageTrack::of(&spent) >= DamageTrack::of(&character)
+                    );
+                }
+                Err(err) => {
+                    prop_assert!(cost_with_edge > before.pool);
+                    prop_assert_eq!(err.code(), ErrorCode::InsufficientPool);
+                }
+            }
+        }
+    }
+}
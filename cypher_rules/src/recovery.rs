@@ -0,0 +1,490 @@
+use proto_rs::character::{Character, PoolType, Recovery};
+use rand::Rng;
+
+use crate::{
+    damage::{restore_pool, DamageTrack},
+    error::{Error, ErrorCode},
+    pools::stat,
+};
+
+// RecoverySlot of the recovery rolls a character has each day, taken in
+// order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoverySlot {
+    OneAction,
+    TenMinutes,
+    OneHour,
+    TenHours,
+}
+
+impl RecoverySlot {
+    fn used(self, recovery: &mut Recovery) -> &mut bool {
+        match self {
+            RecoverySlot::OneAction => &mut recovery.one_action,
+            RecoverySlot::TenMinutes => &mut recovery.ten_minutes,
+            RecoverySlot::OneHour => &mut recovery.one_hour,
+            RecoverySlot::TenHours => &mut recovery.ten_hours,
+        }
+    }
+}
+
+const SLOTS: [RecoverySlot; 4] = [
+    RecoverySlot::OneAction,
+    RecoverySlot::TenMinutes,
+    RecoverySlot::OneHour,
+    RecoverySlot::TenHours,
+];
+
+// RecoveryRoll taking a recovery slot, its points can be distributed across
+// the pools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecoveryRoll {
+    pub slot: RecoverySlot,
+    // roll of the d6.
+    pub roll: u32,
+    // points recovered, the roll plus tier and recovery bonus.
+    pub points: u32,
+}
+
+// Distribution of recovered points across the pools.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Distribution {
+    pub might: u32,
+    pub speed: u32,
+    pub intellect: u32,
+}
+
+impl Distribution {
+    fn pools(&self) -> [(PoolType, u32); 3] {
+        [
+            (PoolType::Might, self.might),
+            (PoolType::Speed, self.speed),
+            (PoolType::Intellect, self.intellect),
+        ]
+    }
+
+    fn total(&self) -> u64 {
+        self.pools()
+            .iter()
+            .map(|(_, points)| u64::from(*points))
+            .sum()
+    }
+}
+
+// next_recovery is the next unused recovery slot of character, None once all
+// recoveries of the day are used.
+pub fn next_recovery(character: &Character) -> Option<RecoverySlot> {
+    let mut recovery = character.recovery.clone().unwrap_or_default();
+    SLOTS.into_iter().find(|slot| !*slot.used(&mut recovery))
+}
+
+// roll_recovery rolls 1d6 plus tier and recovery bonus for the next recovery
+// slot of character. The roll is applied with recover.
+pub fn roll_recovery<R: Rng + ?Sized>(
+    character: &Character,
+    rng: &mut R,
+) -> Result<RecoveryRoll, Error> {
+    check_alive(character)?;
+    let slot = next_recovery(character).ok_or_else(|| {
+        Error::new(
+            ErrorCode::InvalidRecovery,
+            "all recovery rolls of the day are used",
+        )
+    })?;
+
+    let tier = character.progress.as_ref().map_or(0, |p| p.tier);
+    let bonus = character.recovery.as_ref().map_or(0, |r| r.bonus);
+    let roll = rng.gen_range(1..=6);
+    let points = (i64::from(roll) + i64::from(tier) + i64::from(bonus))
+        .clamp(0, i64::from(u32::MAX)) as u32;
+    Ok(RecoveryRoll { slot, roll, points })
+}
+
+// recover takes the slot of roll and restores the distributed points. At most
+// the rolled points can be distributed and no pool can exceed its cap.
+// Refilling an empty pool moves the character up the damage track, dead
+// characters can't recover.
+pub fn recover(
+    character: &Character,
+    roll: &RecoveryRoll,
+    distribution: Distribution,
+) -> Result<Character, Error> {
+    check_alive(character)?;
+    let mut character = character.clone();
+    let recovery = character.recovery.get_or_insert_with(Recovery::default);
+    let used = roll.slot.used(recovery);
+    if *used {
+        return Err(Error::new(
+            ErrorCode::InvalidRecovery,
+            &format!("recovery {:?} was already used", roll.slot),
+        ));
+    }
+    *used = true;
+
+    if distribution.total() > u64::from(roll.points) {
+        return Err(Error::new(
+            ErrorCode::InvalidRecovery,
+            &format!(
+                "distributed {} points, but only {} were recovered",
+                distribution.total(),
+                roll.points
+            ),
+        ));
+    }
+    for (pool, points) in distribution.pools() {
+        let stat = stat(&character, pool);
+        if i64::from(stat.pool.max(0)) + i64::from(points) > i64::from(stat.cap)
+        {
+            return Err(Error::new(
+                ErrorCode::InvalidRecovery,
+                &format!(
+                    "recovering {points} {} exceeds its cap of {}",
+                    pool.as_str_name(),
+                    stat.cap
+                ),
+            ));
+        }
+    }
+
+    for (pool, points) in distribution.pools() {
+        restore_pool(&mut character, pool, points);
+    }
+    Ok(character)
+}
+
+fn check_alive(character: &Character) -> Result<(), Error> {
+    if DamageTrack::of(character) == DamageTrack::Dead {
+        return Err(Error::new(
+            ErrorCode::InvalidRecovery,
+            "dead characters can't recover",
+        ));
+    }
+    Ok(())
+}
+
+// reset_recovery makes all recovery rolls available again for a new day. The
+// recovery bonus is kept.
+pub fn reset_recovery(character: &Character) -> Character {
+    let bonus = character
+        .recovery
+        .as_ref()
+        .map_or(0, |recovery| recovery.bonus);
+    Character {
+        recovery: Some(Recovery {
+            bonus,
+            ..Default::default()
+        }),
+        ..character.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use proto_rs::character::{
+        Character, PoolType, Progress, Recovery, Stat, Stats,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+    use rstest::rstest;
+
+    use super::{
+        next_recovery, recover, reset_recovery, roll_recovery, Distribution,
+        RecoveryRoll, RecoverySlot,
+    };
+    use crate::{
+        damage::DamageTrack, pools::stat, testing::character, ErrorCode,
+    };
+
+    fn wounded(might: i32, speed: i32, intellect: i32) -> Character {
+        let stat = |pool_type: PoolType, pool| {
+            Some(Stat {
+                r#type: pool_type.into(),
+                cap: 10,
+                pool,
+                edge: 0,
+            })
+        };
+        let mut character = Character {
+            stats: Some(Stats {
+                might: stat(PoolType::Might, might),
+                speed: stat(PoolType::Speed, speed),
+                intellect: stat(PoolType::Intellect, intellect),
+            }),
+            progress: Some(Progress {
+                tier: 2,
+                ..Default::default()
+            }),
+            recovery: Some(Recovery {
+                bonus: 1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let empty = [might, speed, intellect]
+            .iter()
+            .filter(|pool| **pool == 0)
+            .count();
+        let mut track = DamageTrack::Hale;
+        for _ in 0..empty {
+            track = track.worsen();
+        }
+        track.set(&mut character);
+        character
+    }
+
+    fn roll(slot: RecoverySlot, points: u32) -> RecoveryRoll {
+        RecoveryRoll {
+            slot,
+            roll: 1,
+            points,
+        }
+    }
+
+    #[rstest]
+    #[case::none_used(
+        [false, false, false, false],
+        Some(RecoverySlot::OneAction)
+    )]
+    #[case::first_used(
+        [true, false, false, false],
+        Some(RecoverySlot::TenMinutes)
+    )]
+    #[case::out_of_order(
+        [false, true, true, false],
+        Some(RecoverySlot::OneAction)
+    )]
+    #[case::last_left([true, true, true, false], Some(RecoverySlot::TenHours))]
+    #[case::all_used([true, true, true, true], None)]
+    fn takes_next_recovery(
+        #[case] used: [bool; 4],
+        #[case] expected: Option<RecoverySlot>,
+    ) {
+        let character = Character {
+            recovery: Some(Recovery {
+                bonus: 0,
+                one_action: used[0],
+                ten_minutes: used[1],
+                one_hour: used[2],
+                ten_hours: used[3],
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(next_recovery(&character), expected);
+    }
+
+    #[rstest]
+    fn rolls_d6_plus_tier_and_bonus() {
+        let roll =
+            roll_recovery(&wounded(5, 5, 5), &mut StdRng::seed_from_u64(3))
+                .unwrap();
+
+        assert_eq!(roll.slot, RecoverySlot::OneAction);
+        assert!((1..=6).contains(&roll.roll));
+        assert_eq!(roll.points, roll.roll + 3);
+    }
+
+    #[rstest]
+    fn rejects_roll_without_recoveries() {
+        let character = Character {
+            recovery: Some(Recovery {
+                bonus: 0,
+                one_action: true,
+                ten_minutes: true,
+                one_hour: true,
+                ten_hours: true,
+            }),
+            ..wounded(5, 5, 5)
+        };
+
+        let err = roll_recovery(&character, &mut StdRng::seed_from_u64(3))
+            .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::InvalidRecovery);
+    }
+
+    #[rstest]
+    fn rejects_roll_when_dead() {
+        let character = wounded(0, 0, 0);
+
+        let err = roll_recovery(&character, &mut StdRng::seed_from_u64(3))
+            .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::InvalidRecovery);
+    }
+
+    #[rstest]
+    fn rejects_recovery_when_dead() {
+        let character = wounded(0, 0, 0);
+
+        let err = recover(
+            &character,
+            &roll(RecoverySlot::OneAction, 6),
+            Distribution {
+                might: 6,
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code(), ErrorCode::InvalidRecovery);
+    }
+
+    #[rstest]
+    fn distributes_points() {
+        let character = recover(
+            &wounded(5, 2, 9),
+            &roll(RecoverySlot::OneAction, 6),
+            Distribution {
+                might: 3,
+                speed: 2,
+                intellect: 1,
+            },
+        )
+        .unwrap();
+
+        let pools = [PoolType::Might, PoolType::Speed, PoolType::Intellect]
+            .map(|pool| stat(&character, pool).pool);
+        assert_eq!(pools, [8, 4, 10]);
+        assert_eq!(next_recovery(&character), Some(RecoverySlot::TenMinutes));
+    }
+
+    #[rstest]
+    fn refilling_empty_pool_improves_track() {
+        let character = wounded(0, 0, 5);
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Debilitated);
+
+        let character = recover(
+            &character,
+            &roll(RecoverySlot::OneAction, 4),
+            Distribution {
+                might: 4,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(DamageTrack::of(&character), DamageTrack::Impaired);
+    }
+
+    #[rstest]
+    #[case::too_many_points(
+        roll(RecoverySlot::OneAction, 3),
+        Distribution { might: 2, speed: 2, intellect: 0 }
+    )]
+    #[case::above_cap(
+        roll(RecoverySlot::OneAction, 8),
+        Distribution { might: 0, speed: 0, intellect: 2 }
+    )]
+    #[case::used_slot(roll(RecoverySlot::TenHours, 3), Distribution::default())]
+    fn rejects_invalid_recovery(
+        #[case] roll: RecoveryRoll,
+        #[case] distribution: Distribution,
+    ) {
+        let character = Character {
+            recovery: Some(Recovery {
+                ten_hours: true,
+                ..Default::default()
+            }),
+            ..wounded(5, 5, 9)
+        };
+
+        assert_eq!(
+            recover(&character, &roll, distribution).map_err(|e| e.code()),
+            Err(ErrorCode::InvalidRecovery)
+        );
+    }
+
+    #[rstest]
+    fn resets_used_recoveries() {
+        let character = Character {
+            recovery: Some(Recovery {
+                bonus: 2,
+                one_action: true,
+                ten_minutes: true,
+                one_hour: true,
+                ten_hours: false,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            reset_recovery(&character).recovery,
+            Some(Recovery {
+                bonus: 2,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[rstest]
+    fn resets_missing_recovery() {
+        assert_eq!(
+            reset_recovery(&Character::default()).recovery,
+            Some(Recovery::default())
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn reset_only_changes_recovery(character in character()) {
+            let reset = reset_recovery(&character);
+
+            prop_assert_eq!(
+                reset.recovery.as_ref().unwrap().bonus,
+                character.recovery.as_ref().unwrap().bonus
+            );
+            prop_assert_eq!(
+                Character { recovery: None, ..reset.clone() },
+                Character { recovery: None, ..character }
+            );
+            prop_assert_eq!(reset_recovery(&reset), reset);
+        }
+
+        #[test]
+        fn recovery_restores_up_to_cap(
+            character in character(),
+            seed in any::<u64>(),
+            split in (0u32..=100, 0u32..=100),
+        ) {
+            let character = reset_recovery(&character);
+            let mut rng = StdRng::seed_from_u64(seed);
+            let roll = match roll_recovery(&character, &mut rng) {
+                Ok(roll) => roll,
+                Err(_) => {
+                    let track = DamageTrack::of(&character);
+                    prop_assert_eq!(track, DamageTrack::Dead);
+                    return Ok(());
+                }
+            };
+
+            // Distribute the points by the split, capped at each pool's cap.
+            let missing = |pool| {
+                let stat = stat(&character, pool);
+                (stat.cap - stat.pool) as u32
+            };
+            let might =
+                (roll.points * split.0 / 100).min(missing(PoolType::Might));
+            let speed = ((roll.points - might) * split.1 / 100)
+                .min(missing(PoolType::Speed));
+            let intellect = (roll.points - might - speed)
+                .min(missing(PoolType::Intellect));
+            let distribution = Distribution { might, speed, intellect };
+
+            let recovered = recover(&character, &roll, distribution).unwrap();
+            for (pool, points) in distribution.pools() {
+                let before = stat(&character, pool);
+                let after = stat(&recovered, pool);
+                prop_assert_eq!(after.pool, before.pool + points as i32);
+                prop_assert!(after.pool <= after.cap);
+            }
+            prop_assert!(
+                DamageTrack::of(&recovered) <= DamageTrack::of(&character)
+            );
+            prop_assert_eq!(
+                next_recovery(&recovered),
+                Some(RecoverySlot::TenMinutes)
+            );
+        }
+    }
+}
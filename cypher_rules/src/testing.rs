@@ -0,0 +1,71 @@
+// Strategies generating the messages rules operate on for property tests.
+
+use proptest::prelude::*;
+use proto_rs::character::{
+    Character, Damage, PoolType, Progress, Recovery, Stat, Stats,
+};
+
+pub fn pool_type() -> impl Strategy<Value = PoolType> {
+    prop_oneof![
+        Just(PoolType::Intellect),
+        Just(PoolType::Speed),
+        Just(PoolType::Might),
+    ]
+}
+
+pub fn stat(pool_type: PoolType) -> impl Strategy<Value = Stat> {
+    (1..30, 0..4)
+        .prop_flat_map(|(cap, edge)| (Just(cap), 0..=cap, Just(edge)))
+        .prop_map(move |(cap, pool, edge)| Stat {
+            r#type: pool_type.into(),
+            cap,
+            pool,
+            edge,
+        })
+}
+
+pub fn recovery() -> impl Strategy<Value = Recovery> {
+    (0..5, any::<[bool; 4]>()).prop_map(|(bonus, used)| Recovery {
+        bonus,
+        one_action: used[0],
+        ten_minutes: used[1],
+        one_hour: used[2],
+        ten_hours: used[3],
+    })
+}
+
+pub fn character() -> impl Strategy<Value = Character> {
+    (
+        stat(PoolType::Might),
+        stat(PoolType::Speed),
+        stat(PoolType::Intellect),
+        recovery(),
+        1..=6,
+    )
+        .prop_map(|(might, speed, intellect, recovery, tier)| {
+            // Each empty pool is one step down the damage track.
+            let empty = [&might, &speed, &intellect]
+                .iter()
+                .filter(|stat| stat.pool == 0)
+                .count();
+            let damage = Damage {
+                impaired: empty >= 1,
+                debilitated: empty >= 2,
+            };
+            Character {
+                stats: Some(Stats {
+                    might: Some(might),
+                    speed: Some(speed),
+                    intellect: Some(intellect),
+                }),
+                damage: Some(damage),
+                recovery: Some(recovery),
+                progress: Some(Progress {
+                    tier,
+                    max_effort: tier.min(6),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+        })
+}
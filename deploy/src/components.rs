@@ -10,6 +10,10 @@ pub enum Component {
         #[command(flatten)]
         options: discord_bot::Options,
     },
+    CharacterService {
+        #[command(flatten)]
+        options: Box<character_service::Options>,
+    },
 }
 
 impl Component {
@@ -33,9 +37,18 @@ impl Component {
         self.spec().environment_variables
     }
 
+    // grpc_health_port is the port serving the gRPC health service, if the
+    // component has one.
+    pub fn grpc_health_port(&self) -> Option<i32> {
+        self.spec().grpc_health_port
+    }
+
     fn spec(&self) -> ComponentSpec {
         match self {
             Component::DiscordBot { options } => discord_bot_spec(options),
+            Component::CharacterService { options } => {
+                character_service_spec(options)
+            }
         }
     }
 }
@@ -45,6 +58,7 @@ pub struct ComponentSpec<'a> {
     image_name: &'a str,
     resource_name: &'a str,
     environment_variables: BTreeMap<String, String>,
+    grpc_health_port: Option<i32>,
 }
 
 fn discord_bot_spec(options: &discord_bot::Options) -> ComponentSpec<'static> {
@@ -55,5 +69,24 @@ fn discord_bot_spec(options: &discord_bot::Options) -> ComponentSpec<'static> {
         image_name: "cypher-sheet-discord-bot",
         resource_name: "discord-bot",
         environment_variables,
+        grpc_health_port: None,
     }
 }
+
+fn character_service_spec(
+    options: &character_service::Options,
+) -> ComponentSpec<'static> {
+    let environment_variables = options.get_env_pairs();
+
+    ComponentSpec {
+        name: "character_service",
+        image_name: "cypher-sheet-character-service",
+        resource_name: "character-service",
+        environment_variables,
+        grpc_health_port: port(&options.listen_address),
+    }
+}
+
+fn port(address: &str) -> Option<i32> {
+    address.rsplit_once(':')?.1.parse().ok()
+}
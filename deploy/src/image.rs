@@ -111,6 +111,7 @@ fn container_file(
     image.set_workdir(workdir);
     image.set_env("CARGO_REGISTRIES_CRATES_IO_PROTOCOL", "sparse");
 
+    image.copy("proto", "/usr/src/proto");
     image.copy("proto_rs", "/usr/src/proto_rs");
 
     image.prepare_dependency_build(component);
@@ -156,6 +157,7 @@ pub async fn image(
         .expect("should create a valid Containerfile path");
 
     build_image(
+        component,
         container_file_path,
         image_name,
         image_tag,
@@ -168,6 +170,7 @@ pub async fn image(
 }
 
 pub fn build_image(
+    component: &str,
     container_file_path: &str,
     image_name: &str,
     image_tag: &str,
@@ -189,7 +192,7 @@ pub fn build_image(
 
     if let Some(target_cache) = target_cache {
         cmd.arg(format!(
-            "--volume={target_cache}:/usr/src/discord_bot/target:U,Z"
+            "--volume={target_cache}:/usr/src/{component}/target:U,Z"
         ));
     }
 
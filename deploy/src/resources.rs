@@ -4,8 +4,9 @@ use k8s_openapi::{
     api::{
         apps::v1::{Deployment, DeploymentSpec},
         core::v1::{
-            Container, EnvVar, EnvVarSource, Namespace, PodSpec,
-            PodTemplateSpec, Secret, SecretKeySelector,
+            Container, ContainerPort, EnvVar, EnvVarSource, GRPCAction,
+            Namespace, PodSpec, PodTemplateSpec, Probe, Secret,
+            SecretKeySelector,
         },
     },
     apimachinery::pkg::apis::meta::v1::LabelSelector,
@@ -57,6 +58,8 @@ pub fn expected_deployment(
 
     let labels = default_labels(env, component);
 
+    let grpc_health_port = component.grpc_health_port();
+
     Deployment {
         metadata: ObjectMeta {
             name: Some(component.resource_name().to_owned()),
@@ -79,6 +82,25 @@ pub fn expected_deployment(
                         name: component.resource_name().to_owned(),
                         image: Some(component.image(image_registry, tag)),
                         env: Some(environment_variables),
+                        ports: grpc_health_port.map(|port| {
+                            vec![ContainerPort {
+                                name: Some("grpc".to_owned()),
+                                container_port: port,
+                                ..Default::default()
+                            }]
+                        }),
+                        readiness_probe: grpc_health_port.map(|port| {
+                            grpc_probe(port, "characters.Characters")
+                        }),
+                        // Liveness is only probed once the startup probe
+                        // saw the store loaded, so slow loads don't get the
+                        // pod restarted.
+                        startup_probe: grpc_health_port.map(|port| Probe {
+                            failure_threshold: Some(STARTUP_PROBE_FAILURES),
+                            ..grpc_probe(port, "")
+                        }),
+                        liveness_probe: grpc_health_port
+                            .map(|port| grpc_probe(port, "")),
                         ..Default::default()
                     }],
                     ..Default::default()
@@ -90,6 +112,22 @@ pub fn expected_deployment(
     }
 }
 
+// STARTUP_PROBE_FAILURES gives the service 30 minutes to load its store.
+const STARTUP_PROBE_FAILURES: i32 = 180;
+
+// grpc_probe checks the serving status of service using the gRPC health
+// service.
+fn grpc_probe(port: i32, service: &str) -> Probe {
+    Probe {
+        grpc: Some(GRPCAction {
+            port,
+            service: Some(service.to_owned()),
+        }),
+        period_seconds: Some(10),
+        ..Default::default()
+    }
+}
+
 pub fn expected_secret(env: Environment, component: &Component) -> Secret {
     let labels = default_labels(env, component);
 
@@ -1,5 +1,6 @@
 // use core::unicode::conversions::to_upper;
 
+use cypher_rules::Level;
 use proto_rs::character::{
     Ability, Artifact, Cypher, Item, ItemType, Note, NoteType, PoolType, Skill,
     SkillLevel,
@@ -15,36 +16,29 @@ impl Embedable for Cypher {
         let mut embed = CreateEmbed::default();
         embed.title(format!("[Cypher] {}", self.name));
         if !self.short_description.is_empty() {
-            embed.description(self.get_short_description());
+            embed.description(&self.short_description);
         }
 
-        embed.field(
-            "Level",
-            match self.get_level().is_empty() {
-                false => self.get_level(),
-                true => "?",
-            },
-            true,
-        );
+        embed.field("Level", printable_level(&self.level), true);
 
-        if !self.get_depletion().is_empty() {
-            embed.field("Depletion", self.get_depletion(), true);
+        if !&self.depletion.is_empty() {
+            embed.field("Depletion", &self.depletion, true);
         };
 
-        if !self.get_effect().is_empty() {
-            embed.field("Effect", self.get_effect(), false);
+        if !&self.effect.is_empty() {
+            embed.field("Effect", &self.effect, false);
         }
 
-        if !self.get_internal().is_empty() {
-            embed.field("Internal", self.get_internal(), false);
+        if !&self.internal.is_empty() {
+            embed.field("Internal", &self.internal, false);
         }
 
-        if !self.get_wearable().is_empty() {
-            embed.field("Wearable", self.get_wearable(), false);
+        if !&self.wearable.is_empty() {
+            embed.field("Wearable", &self.wearable, false);
         }
 
-        if !self.get_usable().is_empty() {
-            embed.field("Usable", self.get_usable(), false);
+        if !&self.usable.is_empty() {
+            embed.field("Usable", &self.usable, false);
         }
 
         embed
@@ -56,28 +50,21 @@ impl Embedable for Artifact {
         let mut embed = CreateEmbed::default();
         embed.title(format!("[Artifact] {}", self.name));
         if !self.short_description.is_empty() {
-            embed.description(self.get_short_description());
+            embed.description(&self.short_description);
         }
 
-        embed.field(
-            "Level",
-            match self.get_level().is_empty() {
-                false => self.get_level(),
-                true => "?",
-            },
-            true,
-        );
+        embed.field("Level", printable_level(&self.level), true);
 
-        if !self.get_depletion().is_empty() {
-            embed.field("Depletion", self.get_depletion(), true);
+        if !&self.depletion.is_empty() {
+            embed.field("Depletion", &self.depletion, true);
         };
 
-        if !self.get_effect().is_empty() {
-            embed.field("Effect", self.get_effect(), false);
+        if !&self.effect.is_empty() {
+            embed.field("Effect", &self.effect, false);
         }
 
-        if !self.get_form().is_empty() {
-            embed.field("Form", self.get_form(), false);
+        if !&self.form.is_empty() {
+            embed.field("Form", &self.form, false);
         }
 
         embed
@@ -93,26 +80,26 @@ impl Embedable for Ability {
         let has_description = !self.description.is_empty();
 
         if has_short_description {
-            embed.description(self.get_short_description());
+            embed.description(&self.short_description);
         } else if has_description {
-            embed.description(self.get_description());
+            embed.description(&self.description);
         }
 
-        embed.field("Type", printable_pool_type(self.get_field_type()), true);
+        embed.field("Type", printable_pool_type(self.r#type()), true);
 
         embed.field(
             "Cost",
-            match self.get_cost().is_empty() {
-                false => self.get_cost(),
+            match self.cost.is_empty() {
+                false => self.cost.as_str(),
                 true => "?",
             },
             true,
         );
 
-        embed.field("Enabler", self.get_enabler(), false);
+        embed.field("Enabler", self.enabler, false);
 
         if has_description && has_short_description {
-            embed.field("Description", self.get_description(), false);
+            embed.field("Description", &self.description, false);
         }
 
         embed
@@ -127,12 +114,12 @@ impl Embedable for Skill {
         let has_description = !self.description.is_empty();
 
         if has_description {
-            embed.description(self.get_description());
+            embed.description(&self.description);
         }
 
-        embed.field("Type", printable_pool_type(self.get_field_type()), true);
+        embed.field("Type", printable_pool_type(self.r#type()), true);
 
-        embed.field("Level", printable_skill_level(self.get_level()), true);
+        embed.field("Level", printable_skill_level(self.level()), true);
 
         embed
     }
@@ -147,32 +134,32 @@ impl Embedable for Item {
         let has_description = !self.description.is_empty();
 
         if has_short_description {
-            embed.description(self.get_short_description());
+            embed.description(&self.short_description);
         } else if has_description {
-            embed.description(self.get_description());
+            embed.description(&self.description);
         }
 
         let types: Vec<String> =
-            self.types.iter().map(|t| printable_item_type(*t)).collect();
+            self.types().map(printable_item_type).collect();
 
         embed.field("Type", types.join(", "), false);
 
-        if self.get_value() > 0.0 {
-            embed.field("Value", self.get_value(), true);
+        if self.value > 0.0 {
+            embed.field("Value", self.value, true);
         };
 
-        if self.has_armor() && self.get_armor() > 0 {
-            embed.field("Armor", self.get_armor(), true);
+        if let Some(armor) = self.armor.filter(|armor| *armor > 0) {
+            embed.field("Armor", armor, true);
         }
 
         if has_description && has_short_description {
-            embed.field("Description", self.get_description(), false);
+            embed.field("Description", &self.description, false);
         }
 
-        if self.has_sub_item_type() {
+        if self.sub_item_type.is_some() {
             embed.field(
                 "Has Sub-Items",
-                printable_item_type(self.get_sub_item_type()),
+                printable_item_type(self.sub_item_type()),
                 false,
             );
         }
@@ -186,60 +173,89 @@ impl Embedable for Note {
         let mut embed = CreateEmbed::default();
         embed.title(format!("[Note] {}", self.title));
 
-        let has_short_description = !self.shortDescription.is_empty();
+        let has_short_description = !self.short_description.is_empty();
 
         if has_short_description {
-            embed.description(self.get_shortDescription());
+            embed.description(&self.short_description);
         }
 
-        embed.field("Type", printable_note_type(self.get_field_type()), true);
+        embed.field("Type", printable_note_type(self.r#type()), true);
 
-        embed.field("Text", self.get_text(), false);
+        embed.field("Text", &self.text, false);
 
         embed
     }
 }
 
+// printable_level tells levels rolled once the item is found apart from fixed
+// ones, levels that can't be parsed are shown as written.
+fn printable_level(level: &str) -> String {
+    match level.parse::<Level>() {
+        Ok(Level::Unknown) => "Unknown".to_owned(),
+        Ok(level @ Level::Fixed(_)) => level.to_string(),
+        Ok(level) => format!("{level} (roll on find)"),
+        Err(_) => level.to_owned(),
+    }
+}
+
 fn printable_item_type(item_type: ItemType) -> String {
     match item_type {
-        ItemType::armor => "Armor",
-        ItemType::weapon => "Weapon",
-        ItemType::clothing => "Clothing",
-        ItemType::tool => "Tool",
-        ItemType::oddity => "Oddity",
-        ItemType::material => "Material",
-        ItemType::ammo => "Ammo",
-        ItemType::plan => "Plan",
-        ItemType::others => "Others",
+        ItemType::Armor => "Armor",
+        ItemType::Weapon => "Weapon",
+        ItemType::Clothing => "Clothing",
+        ItemType::Tool => "Tool",
+        ItemType::Oddity => "Oddity",
+        ItemType::Material => "Material",
+        ItemType::Ammo => "Ammo",
+        ItemType::Plan => "Plan",
+        ItemType::Others => "Others",
     }
     .to_owned()
 }
 
 fn printable_pool_type(pool_type: PoolType) -> String {
     match pool_type {
-        PoolType::intellect => "Intellect",
-        PoolType::speed => "Speed",
-        PoolType::might => "Might",
+        PoolType::Intellect => "Intellect",
+        PoolType::Speed => "Speed",
+        PoolType::Might => "Might",
     }
     .to_owned()
 }
 
 fn printable_skill_level(skill_level: SkillLevel) -> String {
     match skill_level {
-        SkillLevel::specialized => "Specialized",
-        SkillLevel::trained => "Trained",
-        SkillLevel::inability => "Inability",
+        SkillLevel::Specialized => "Specialized",
+        SkillLevel::Trained => "Trained",
+        SkillLevel::Inability => "Inability",
     }
     .to_owned()
 }
 
 fn printable_note_type(note_type: NoteType) -> String {
     match note_type {
-        NoteType::misc => "Misc",
-        NoteType::location => "Location",
-        NoteType::character => "Character",
-        NoteType::item => "Item",
-        NoteType::quest => "Quest",
+        NoteType::Misc => "Misc",
+        NoteType::Location => "Location",
+        NoteType::Character => "Character",
+        NoteType::Item => "Item",
+        NoteType::Quest => "Quest",
     }
     .to_owned()
 }
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::printable_level;
+
+    #[rstest]
+    #[case("", "Unknown")]
+    #[case("?", "Unknown")]
+    #[case(" 5 ", "5")]
+    #[case("d6+2", "1d6+2 (roll on find)")]
+    #[case("3–6", "3-6 (roll on find)")]
+    #[case("level 3", "level 3")]
+    fn prints_level(#[case] level: &str, #[case] expected: &str) {
+        assert_eq!(printable_level(level), expected);
+    }
+}
@@ -2,7 +2,7 @@ use clap::Parser;
 use discord_bot::Options;
 use dotenv::dotenv;
 use embeds::Embedable;
-use proto_rs::character::{SharedObject, SharedObject_oneof_object};
+use proto_rs::character::{shared_object::Object, SharedObject};
 use serenity::{
     async_trait,
     builder::{CreateEmbed, CreateMessage},
@@ -15,7 +15,7 @@ use serenity::{
 use tracing::{debug, error, Level};
 use tracing_subscriber::FmtSubscriber;
 
-use protobuf::Message;
+use prost::Message;
 
 mod embeds;
 
@@ -41,7 +41,7 @@ impl EventHandler for Handler {
                 }
             };
 
-            let shared_object = match SharedObject::parse_from_bytes(&raw) {
+            let shared_object = match SharedObject::decode(raw.as_slice()) {
                 Ok(shared_object) => shared_object,
                 Err(err) => {
                     debug!(
@@ -92,16 +92,16 @@ fn shared_object_to_embed(obj: SharedObject) -> Option<CreateEmbed> {
     };
 
     match inner {
-        SharedObject_oneof_object::character(_) => {
+        Object::Character(_) => {
             // Characters are unsupported right now.
             None
         }
-        SharedObject_oneof_object::skill(skill) => Some(skill.embed()),
-        SharedObject_oneof_object::ability(ability) => Some(ability.embed()),
-        SharedObject_oneof_object::cypher(cypher) => Some(cypher.embed()),
-        SharedObject_oneof_object::artifact(artifact) => Some(artifact.embed()),
-        SharedObject_oneof_object::item(item) => Some(item.embed()),
-        SharedObject_oneof_object::note(note) => Some(note.embed()),
+        Object::Skill(skill) => Some(skill.embed()),
+        Object::Ability(ability) => Some(ability.embed()),
+        Object::Cypher(cypher) => Some(cypher.embed()),
+        Object::Artifact(artifact) => Some(artifact.embed()),
+        Object::Item(item) => Some(item.embed()),
+        Object::Note(note) => Some(note.embed()),
     }
 }
 
@@ -1,5 +1,8 @@
 use heck::ToShoutySnakeCase;
-use syn::{self, ext::IdentExt, Data, DataStruct, Fields, Ident};
+use syn::{
+    self, ext::IdentExt, Data, DataStruct, Fields, GenericArgument, Ident,
+    PathArguments, Type,
+};
 use synstructure::quote;
 
 synstructure::decl_derive!([EnvArgs] => env_args_macro);
@@ -25,18 +28,28 @@ fn env_args_macro(
                     let env = ident.unraw().to_string();
                     let env = env.to_shouty_snake_case();
 
-                    (env, ident)
+                    (env, ident, is_option(&field.ty))
                 })
-                .collect::<Vec<(String, Ident)>>();
+                .collect::<Vec<(String, Ident, bool)>>();
 
             let mut body = quote!();
 
             for field in fields {
                 let env = field.0;
                 let name = field.1;
-                body.extend(quote!(
-                    map.insert(#env.to_owned(), self.#name.clone());
-                ));
+                // Unset optional arguments are left out so they don't end up
+                // as empty variables in the environment.
+                if field.2 {
+                    body.extend(quote!(
+                        if let Some(value) = &self.#name {
+                            map.insert(#env.to_owned(), value.to_string());
+                        }
+                    ));
+                } else {
+                    body.extend(quote!(
+                        map.insert(#env.to_owned(), self.#name.to_string());
+                    ));
+                }
             }
 
             Ok(s.gen_impl(quote!(
@@ -56,6 +69,27 @@ fn env_args_macro(
     }
 }
 
+// is_option checks if the field is declared as an Option<T>.
+fn is_option(ty: &Type) -> bool {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return false,
+    };
+    let segment = match path.segments.last() {
+        Some(segment) => segment,
+        None => return false,
+    };
+    if segment.ident != "Option" {
+        return false;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => {
+            matches!(args.args.first(), Some(GenericArgument::Type(_)))
+        }
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use synstructure::test_derive;
@@ -79,7 +113,37 @@ mod test {
                         fn get_env_pairs(&self) -> BTreeMap<String, String> {
                             let mut map  = BTreeMap::new();
 
-                            map.insert("DISCORD_TOKEN".to_owned(), self.discord_token.clone());
+                            map.insert("DISCORD_TOKEN".to_owned(), self.discord_token.to_string());
+
+                            map
+                        }
+                    }
+                };
+            }
+        }
+    }
+
+    #[test]
+    fn derives_optional_fields() {
+        test_derive! {
+            env_args_macro {
+                struct Options {
+                    pub listen_address: String,
+                    pub tls_cert: Option<String>,
+                }
+            }
+            expands to {
+                #[allow(non_upper_case_globals)]
+                const _DERIVE_EnvArgs_FOR_Options: () = {
+                    use std::collections::BTreeMap;
+                    impl EnvArgs for Options {
+                        fn get_env_pairs(&self) -> BTreeMap<String, String> {
+                            let mut map  = BTreeMap::new();
+
+                            map.insert("LISTEN_ADDRESS".to_owned(), self.listen_address.to_string());
+                            if let Some(value) = &self.tls_cert {
+                                map.insert("TLS_CERT".to_owned(), value.to_string());
+                            }
 
                             map
                         }
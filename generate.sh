@@ -1,3 +0,0 @@
-protoc --rust_out=./character_service/src/proto --grpc_out=./proto_rs/src --plugin=protoc-gen-grpc=grpc_rust_plugin -I ./proto -I proto ./proto/character.proto
-protoc --rust_out=./character_service/src/proto --grpc_out=./proto_rs/src --plugin=protoc-gen-grpc=grpc_rust_plugin -I ./proto -I proto ./proto/characters.proto
-protoc --rust_out=./character_service/src/proto --grpc_out=./proto_rs/src --plugin=protoc-gen-grpc=grpc_rust_plugin -I ./proto -I proto ./proto/storage.proto
\ No newline at end of file
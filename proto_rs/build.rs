@@ -0,0 +1,173 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use prost::Message;
+use prost_types::{DescriptorProto, FileDescriptorSet};
+
+// PROTOS are compiled into the modules of this crate, the package of each file
+// decides the module its types end up in.
+const PROTOS: &[&str] = &[
+    "character.proto",
+    "characters.proto",
+    "error_details.proto",
+    "google/rpc/status.proto",
+    "grpc/reflection/v1alpha/reflection.proto",
+];
+
+// MODULES maps proto packages to the modules of this crate.
+const MODULES: &[(&str, &str)] = &[
+    ("character", "character"),
+    ("characters", "characters"),
+    ("google.rpc", "status"),
+    ("grpc.reflection.v1alpha", "reflection"),
+];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?).join("../proto");
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    // Use the vendored protoc unless one is configured explicitly.
+    if env::var_os("PROTOC").is_none() {
+        env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+    if env::var_os("PROTOC_INCLUDE").is_none() {
+        env::set_var("PROTOC_INCLUDE", protoc_bin_vendored::include_path()?);
+    }
+
+    let protos: Vec<PathBuf> =
+        PROTOS.iter().map(|proto| root.join(proto)).collect();
+    grpcio_compiler::prost_codegen::compile_protos(
+        &protos,
+        std::slice::from_ref(&root),
+        out_dir.to_str().ok_or("OUT_DIR is not valid UTF-8")?,
+    )?;
+
+    let descriptors = out_dir.join("descriptors.bin");
+    compile_descriptors(&root, &protos, &descriptors)?;
+    let types = Types::collect(&fs::read(descriptors)?)?;
+    fs::write(out_dir.join("names.rs"), types.names()?)?;
+    fs::write(out_dir.join("serde.rs"), types.serde()?)?;
+    Ok(())
+}
+
+// compile_descriptors writes the FileDescriptorSet of protos and their
+// imports, used for the JSON mapping and server reflection.
+fn compile_descriptors(
+    root: &Path,
+    protos: &[PathBuf],
+    out: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let status = Command::new(env::var("PROTOC")?)
+        .arg("--include_imports")
+        .arg("--include_source_info")
+        .arg("-o")
+        .arg(out)
+        .arg("-I")
+        .arg(root)
+        .arg("-I")
+        .arg(env::var("PROTOC_INCLUDE")?)
+        .args(protos)
+        .status()?;
+    if !status.success() {
+        return Err(format!("protoc failed with {status}").into());
+    }
+    Ok(())
+}
+
+// Types lists the generated messages and enums of the packages in MODULES.
+#[derive(Default)]
+struct Types {
+    // Rust path and full proto name of every message.
+    messages: Vec<(String, String)>,
+    // Rust path of every enum.
+    enums: Vec<String>,
+}
+
+impl Types {
+    fn collect(descriptors: &[u8]) -> Result<Types, Box<dyn Error>> {
+        let set = FileDescriptorSet::decode(descriptors)?;
+        let mut types = Types::default();
+        for file in &set.file {
+            let package = file.package();
+            let module = match MODULES.iter().find(|(name, _)| *name == package)
+            {
+                Some((_, module)) => format!("crate::{module}"),
+                None => continue,
+            };
+            for message in &file.message_type {
+                types.add_message(&module, package, message);
+            }
+            for item in &file.enum_type {
+                types.enums.push(format!("{module}::{}", item.name()));
+            }
+        }
+        Ok(types)
+    }
+
+    fn add_message(
+        &mut self,
+        module: &str,
+        scope: &str,
+        message: &DescriptorProto,
+    ) {
+        let name = format!("{scope}.{}", message.name());
+        self.messages
+            .push((format!("{module}::{}", message.name()), name.clone()));
+
+        // Nested types live in a module named after their parent.
+        let module = format!("{module}::{}", snake_case(message.name()));
+        for nested in &message.nested_type {
+            if !nested.options.as_ref().is_some_and(|o| o.map_entry()) {
+                self.add_message(&module, &name, nested);
+            }
+        }
+        for item in &message.enum_type {
+            self.enums.push(format!("{module}::{}", item.name()));
+        }
+    }
+
+    // names implements json::MessageName for every message, so messages can
+    // be looked up in the descriptor pool.
+    fn names(&self) -> Result<String, Box<dyn Error>> {
+        let mut names = String::new();
+        for (path, name) in &self.messages {
+            writeln!(
+                names,
+                "impl crate::json::MessageName for {path} {{ \
+                    const NAME: &'static str = \"{name}\"; }}",
+            )?;
+        }
+        Ok(names)
+    }
+
+    // serde lists every message and enum for the impl macros of the
+    // serialize module.
+    fn serde(&self) -> Result<String, Box<dyn Error>> {
+        let mut serde = String::new();
+        for (path, _) in &self.messages {
+            writeln!(serde, "impl_message!({path});")?;
+        }
+        for path in &self.enums {
+            writeln!(serde, "impl_enum!({path});")?;
+        }
+        Ok(serde)
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
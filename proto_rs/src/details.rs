@@ -0,0 +1,138 @@
+use grpcio::{RpcStatus, RpcStatusCode};
+use prost::{DecodeError, Message};
+use prost_types::Any;
+
+use crate::{characters::ErrorDetails, status::Status};
+
+// Type URL of ErrorDetails packed into a google.rpc.Status.
+pub const ERROR_DETAILS_TYPE_URL: &str =
+    "type.googleapis.com/characters.ErrorDetails";
+
+// rpc_status creates an RpcStatus carrying details as a google.rpc.Status
+// with the same code and message.
+pub fn rpc_status(
+    code: RpcStatusCode,
+    message: String,
+    details: &ErrorDetails,
+) -> RpcStatus {
+    let status = Status {
+        code: code.into(),
+        message: message.clone(),
+        details: vec![Any {
+            type_url: ERROR_DETAILS_TYPE_URL.to_owned(),
+            value: details.encode_to_vec(),
+        }],
+    };
+
+    RpcStatus::with_details(code, message, status.encode_to_vec())
+}
+
+// error_details decodes the ErrorDetails sent with a failed call, returning
+// None if the server didn't send any.
+pub fn error_details(
+    status: &RpcStatus,
+) -> Result<Option<ErrorDetails>, DecodeError> {
+    if status.details().is_empty() {
+        return Ok(None);
+    }
+
+    let status = Status::decode(status.details())?;
+    status
+        .details
+        .iter()
+        .find(|any| any.type_url == ERROR_DETAILS_TYPE_URL)
+        .map(|any| ErrorDetails::decode(any.value.as_slice()))
+        .transpose()
+}
+
+#[cfg(test)]
+mod test {
+    use grpcio::{RpcStatus, RpcStatusCode};
+    use prost::Message;
+    use prost_types::Any;
+    use rstest::rstest;
+
+    use super::{error_details, rpc_status, ERROR_DETAILS_TYPE_URL};
+    use crate::{
+        characters::{error_details::Reason, ErrorDetails, FieldViolation},
+        status::Status,
+    };
+
+    fn details() -> ErrorDetails {
+        ErrorDetails {
+            reason: Reason::InvalidArgument.into(),
+            field_violations: vec![FieldViolation {
+                field: "character.skills[2].uuid".to_owned(),
+                description: "must not be empty".to_owned(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn decodes_encoded_details() {
+        let status = rpc_status(
+            RpcStatusCode::INVALID_ARGUMENT,
+            "invalid character".to_owned(),
+            &details(),
+        );
+
+        assert_eq!(status.code(), RpcStatusCode::INVALID_ARGUMENT);
+        assert_eq!(status.message(), "invalid character");
+        assert_eq!(error_details(&status).unwrap(), Some(details()));
+    }
+
+    #[rstest]
+    fn encodes_google_rpc_status() {
+        let status = rpc_status(
+            RpcStatusCode::INVALID_ARGUMENT,
+            "invalid character".to_owned(),
+            &details(),
+        );
+
+        let decoded = Status::decode(status.details()).unwrap();
+        assert_eq!(decoded.code, 3);
+        assert_eq!(decoded.message, "invalid character");
+        assert_eq!(decoded.details.len(), 1);
+        assert_eq!(decoded.details[0].type_url, ERROR_DETAILS_TYPE_URL);
+    }
+
+    #[rstest]
+    fn no_details() {
+        let status = RpcStatus::with_message(
+            RpcStatusCode::INTERNAL,
+            "internal".to_owned(),
+        );
+
+        assert_eq!(error_details(&status).unwrap(), None);
+    }
+
+    #[rstest]
+    fn ignores_unknown_details() {
+        let decoded = Status {
+            details: vec![Any {
+                type_url: "type.googleapis.com/google.rpc.Help".to_owned(),
+                value: vec![],
+            }],
+            ..Default::default()
+        };
+        let status = RpcStatus::with_details(
+            RpcStatusCode::INTERNAL,
+            "internal".to_owned(),
+            decoded.encode_to_vec(),
+        );
+
+        assert_eq!(error_details(&status).unwrap(), None);
+    }
+
+    #[rstest]
+    fn rejects_malformed_details() {
+        let status = RpcStatus::with_details(
+            RpcStatusCode::INTERNAL,
+            "internal".to_owned(),
+            vec![0xff],
+        );
+
+        assert!(error_details(&status).is_err());
+    }
+}
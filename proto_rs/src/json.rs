@@ -0,0 +1,286 @@
+// Canonical proto3 JSON mapping of the messages in this crate, see
+// https://protobuf.dev/programming-guides/proto3/#json.
+//
+// prost messages don't carry their descriptors, so messages are transcoded
+// through their wire format into prost-reflect DynamicMessages using the
+// descriptors compiled by build.rs.
+
+use std::{fmt::Display, sync::OnceLock};
+
+use prost::Message;
+use prost_reflect::{
+    DescriptorPool, DeserializeOptions, DynamicMessage, MessageDescriptor,
+    SerializeOptions,
+};
+use serde_json::Value;
+
+// MessageName is the fully qualified protobuf name of a message, implemented
+// by build.rs for every generated message.
+pub trait MessageName {
+    const NAME: &'static str;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    fn new(message: String) -> Error {
+        Error { message }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<prost::DecodeError> for Error {
+    fn from(err: prost::DecodeError) -> Self {
+        Error::new(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::new(err.to_string())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+// to_value converts message to its JSON representation. Fields set to their
+// default value are omitted.
+pub fn to_value<M: Message + MessageName>(message: &M) -> Result<Value> {
+    message_to_value(message, SerializeOptions::new())
+}
+
+pub fn to_string<M: Message + MessageName>(message: &M) -> Result<String> {
+    Ok(serde_json::to_string(&to_value(message)?)?)
+}
+
+// to_value_with_defaults converts message to its JSON representation
+// including fields set to their default value, for clients that don't know
+// the defaults. Unset message and optional fields are still omitted.
+pub fn to_value_with_defaults<M: Message + MessageName>(
+    message: &M,
+) -> Result<Value> {
+    message_to_value(
+        message,
+        SerializeOptions::new().skip_default_fields(false),
+    )
+}
+
+fn message_to_value<M: Message + MessageName>(
+    message: &M,
+    options: SerializeOptions,
+) -> Result<Value> {
+    let message = DynamicMessage::decode(
+        descriptor::<M>()?,
+        message.encode_to_vec().as_slice(),
+    )?;
+    Ok(message
+        .serialize_with_options(serde_json::value::Serializer, &options)?)
+}
+
+// from_value parses message from its JSON representation. Fields may use
+// their JSON or proto name, unknown fields are rejected.
+pub fn from_value<M: Message + MessageName + Default>(
+    value: &Value,
+) -> Result<M> {
+    let message = DynamicMessage::deserialize_with_options(
+        descriptor::<M>()?,
+        value,
+        &DeserializeOptions::new().deny_unknown_fields(true),
+    )?;
+    Ok(M::decode(message.encode_to_vec().as_slice())?)
+}
+
+pub fn from_str<M: Message + MessageName + Default>(json: &str) -> Result<M> {
+    from_value(&serde_json::from_str(json)?)
+}
+
+// descriptor_pool holds the descriptors of all messages in this crate.
+pub fn descriptor_pool() -> &'static DescriptorPool {
+    static POOL: OnceLock<DescriptorPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        DescriptorPool::decode(crate::FILE_DESCRIPTOR_SET)
+            .expect("descriptors compiled by build.rs should be valid")
+    })
+}
+
+fn descriptor<M: MessageName>() -> Result<MessageDescriptor> {
+    descriptor_pool()
+        .get_message_by_name(M::NAME)
+        .ok_or_else(|| Error::new(format!("unknown message {}", M::NAME)))
+}
+
+#[cfg(test)]
+mod test {
+    use prost::{encoding, Message};
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::{from_str, from_value, to_value, to_value_with_defaults};
+    use crate::{
+        character::{Character, Item, ItemPath, ItemType, Skill, Stat, Stats},
+        characters::{RevisionRead, WriteRevision},
+    };
+
+    fn character() -> Character {
+        Character {
+            uuid: "character".to_owned(),
+            name: "Aria".to_owned(),
+            stats: Some(Stats {
+                might: Some(Stat {
+                    pool: 10,
+                    edge: 1,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            skills: vec![Skill {
+                uuid: "skill".to_owned(),
+                name: "Climbing".to_owned(),
+                ..Default::default()
+            }],
+            items: vec![Item {
+                path: Some(ItemPath {
+                    self_: "item".to_owned(),
+                    ..Default::default()
+                }),
+                types: vec![ItemType::Armor.into(), ItemType::Tool.into()],
+                sub_item_type: Some(ItemType::Armor.into()),
+                armor: Some(0),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn writes_canonical_json() {
+        let read = RevisionRead {
+            uuid: "character".to_owned(),
+            revision: 12,
+            character: Some(character()),
+        };
+
+        let value = to_value(&read).unwrap();
+
+        assert_eq!(value["uuid"], json!("character"));
+        // 64 bit integers are written as strings.
+        assert_eq!(value["revision"], json!("12"));
+        assert_eq!(value["character"]["name"], json!("Aria"));
+        assert_eq!(
+            value["character"]["stats"]["might"],
+            json!({"pool": 10, "edge": 1})
+        );
+        assert_eq!(
+            value["character"]["skills"],
+            json!([{"uuid": "skill", "name": "Climbing"}])
+        );
+        // Enums are written by name and explicitly set optional fields are
+        // kept even with default values.
+        assert_eq!(
+            value["character"]["items"],
+            json!([{
+                "path": {"self": "item"},
+                "types": ["armor", "tool"],
+                "subItemType": "armor",
+                "armor": 0,
+            }])
+        );
+    }
+
+    #[rstest]
+    fn writes_defaults() {
+        let write = WriteRevision {
+            character: Some(Character {
+                items: vec![Item {
+                    armor: Some(0),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let value = to_value_with_defaults(&write).unwrap();
+
+        assert_eq!(value["uuid"], json!(""));
+        assert_eq!(value["revision"], json!("0"));
+        assert_eq!(value["character"]["skills"], json!([]));
+        assert_eq!(value["character"]["money"], json!(0.0));
+        // Unset messages and optional fields have no default.
+        assert_eq!(value["character"].get("stats"), None);
+        let item = &value["character"]["items"][0];
+        assert_eq!(item["types"], json!([]));
+        assert_eq!(item["armor"], json!(0));
+        assert_eq!(item.get("subItemType"), None);
+        assert_eq!(from_value::<WriteRevision>(&value).unwrap(), write);
+    }
+
+    #[rstest]
+    fn round_trips() {
+        let write = WriteRevision {
+            uuid: "character".to_owned(),
+            character: Some(character()),
+            revision: u64::MAX,
+        };
+
+        let value = to_value(&write).unwrap();
+        assert_eq!(from_value::<WriteRevision>(&value).unwrap(), write);
+    }
+
+    #[rstest]
+    fn reads_proto_names_and_numbers() {
+        let write: WriteRevision = from_str(
+            r#"{
+                "uuid": "character",
+                "revision": 3,
+                "character": {
+                    "name": "Aria",
+                    "items": [{"sub_item_type": 30, "types": ["tool"]}]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(write.revision, 3);
+        let character = write.character.unwrap();
+        assert_eq!(character.name, "Aria");
+        let item = &character.items[0];
+        assert_eq!(item.sub_item_type(), ItemType::Tool);
+        assert_eq!(item.types().collect::<Vec<_>>(), [ItemType::Tool]);
+    }
+
+    #[rstest]
+    #[case(r#"{"unknown": 1}"#, "unrecognized field name 'unknown'")]
+    #[case(r#"{"revision": "a"}"#, "invalid digit")]
+    #[case(r#"{"revision": -1}"#, "out of range integral type conversion")]
+    #[case(r#"{"uuid": 1}"#, "invalid type: integer `1`, expected a string")]
+    #[case(r#"{"character": {"skills": {}}}"#, "expected a list")]
+    fn rejects_invalid_json(#[case] json: &str, #[case] message: &str) {
+        let err = from_str::<WriteRevision>(json).unwrap_err().to_string();
+        assert!(err.contains(message), "{err}");
+    }
+
+    #[rstest]
+    fn skips_unknown_wire_fields() {
+        let mut bytes = vec![];
+        encoding::string::encode(1, &"character".to_owned(), &mut bytes);
+        encoding::string::encode(
+            99,
+            &"from a newer version".to_owned(),
+            &mut bytes,
+        );
+
+        let write = WriteRevision::decode(bytes.as_slice()).unwrap();
+        assert_eq!(to_value(&write).unwrap(), json!({"uuid": "character"}));
+    }
+}
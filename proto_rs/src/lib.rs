@@ -1,5 +1,35 @@
-// @generated
+// Types of the protobuf messages and gRPC services in ../proto, generated by
+// build.rs.
 
-pub mod character;
-pub mod characters;
-pub mod characters_grpc;
+pub mod character {
+    include!(concat!(env!("OUT_DIR"), "/character.rs"));
+}
+
+// characters holds the Characters service and its messages.
+pub mod characters {
+    include!(concat!(env!("OUT_DIR"), "/characters.rs"));
+}
+
+pub mod status {
+    include!(concat!(env!("OUT_DIR"), "/google.rpc.rs"));
+}
+
+// reflection holds the gRPC server reflection service.
+pub mod reflection {
+    include!(concat!(env!("OUT_DIR"), "/grpc.reflection.v1alpha.rs"));
+}
+
+// FILE_DESCRIPTOR_SET holds the descriptors of all compiled files and their
+// imports.
+pub const FILE_DESCRIPTOR_SET: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/descriptors.bin"));
+
+include!(concat!(env!("OUT_DIR"), "/names.rs"));
+
+pub mod details;
+pub mod json;
+#[cfg(feature = "serde")]
+mod serialize;
+
+#[cfg(test)]
+mod golden;
@@ -0,0 +1,492 @@
+// Serde support for the messages and enums of this crate using the canonical
+// proto3 JSON mapping of the json module, enabled by the serde feature.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::Value;
+
+use crate::json;
+
+macro_rules! impl_message {
+    ($($message:ty),* $(,)?) => {$(
+        impl Serialize for $message {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                json::to_value(self)
+                    .map_err(serde::ser::Error::custom)?
+                    .serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $message {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                json::from_value(&Value::deserialize(deserializer)?)
+                    .map_err(de::Error::custom)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_enum {
+    ($($enum:ty),* $(,)?) => {$(
+        impl Enumeration for $enum {
+            fn from_name(name: &str) -> Option<Self> {
+                <$enum>::from_str_name(name)
+            }
+
+            fn from_number(number: i32) -> Option<Self> {
+                <$enum>::from_i32(number)
+            }
+        }
+
+        impl Serialize for $enum {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str_name())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $enum {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                deserializer.deserialize_any(EnumVisitor::<$enum>(PhantomData))
+            }
+        }
+    )*};
+}
+
+// The messages and enums of the compiled packages, listed by build.rs so new
+// types can't be missed.
+include!(concat!(env!("OUT_DIR"), "/serde.rs"));
+
+// Enumeration looks up the values of a generated enum.
+trait Enumeration: Sized {
+    fn from_name(name: &str) -> Option<Self>;
+    fn from_number(number: i32) -> Option<Self>;
+}
+
+// EnumVisitor parses enum values from their name or number.
+struct EnumVisitor<E>(PhantomData<E>);
+
+impl<'de, E: Enumeration> Visitor<'de> for EnumVisitor<E> {
+    type Value = E;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an enum value name or number")
+    }
+
+    fn visit_str<Err: de::Error>(self, name: &str) -> Result<E, Err> {
+        E::from_name(name).ok_or_else(|| Err::unknown_variant(name, &[]))
+    }
+
+    fn visit_i64<Err: de::Error>(self, number: i64) -> Result<E, Err> {
+        i32::try_from(number)
+            .ok()
+            .and_then(E::from_number)
+            .ok_or_else(|| {
+                Err::invalid_value(de::Unexpected::Signed(number), &self)
+            })
+    }
+
+    fn visit_u64<Err: de::Error>(self, number: u64) -> Result<E, Err> {
+        i32::try_from(number)
+            .ok()
+            .and_then(E::from_number)
+            .ok_or_else(|| {
+                Err::invalid_value(de::Unexpected::Unsigned(number), &self)
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Debug;
+
+    use prost::Message;
+    use rstest::rstest;
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_json::{json, Value};
+
+    use crate::{
+        character::{
+            Ability, Advancement, AdvancementInfo, Advancements, Artifact,
+            Character, CharacterColor, CharacterMetadata, Cypher, CypherType,
+            Damage, Inventory, Item, ItemPath, ItemType, Note, NoteType,
+            PoolType, Progress, Recover, Recovery, SharedObject, Skill,
+            SkillLevel, Stat, Stats,
+        },
+        characters::{error_details::Reason, ErrorDetails, FieldViolation},
+        characters::{
+            CharacterCreated, CharacterDeleted, CreateCharacter,
+            DeleteCharacter, ReadLatestRevision, ReadRevision, RevisionRead,
+            RevisionWritten, WriteRevision,
+        },
+        reflection::{ServerReflectionRequest, ServerReflectionResponse},
+        status::Status,
+    };
+
+    // round_trip checks that the JSON of a message survives decoding,
+    // protobuf encoding and encoding again unchanged.
+    fn round_trip<M>(value: Value)
+    where
+        M: Message + Default + Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        let message: M = serde_json::from_value(value.clone()).unwrap();
+        let decoded = M::decode(message.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, message);
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), value);
+    }
+
+    fn character() -> Value {
+        json!({
+            "uuid": "character",
+            "name": "Aria",
+            "descriptor": "Clever",
+            "type": "Nano",
+            "focus": "Talks to Machines",
+            "color": {"r": 10, "g": 20, "b": 30},
+            "progress": {
+                "tier": 2,
+                "freeXp": 3,
+                "totalXp": 12,
+                "maxEffort": 2,
+                "advancements": {"other": true},
+            },
+            "stats": {
+                "intellect": {"cap": 16, "pool": 14},
+                "speed": {"type": "speed", "cap": 10, "pool": 9, "edge": 1},
+                "might": {"type": "might", "cap": 8, "pool": 8},
+            },
+            "recovery": {"bonus": 2, "oneAction": true},
+            "damage": {"impaired": true},
+            "skills": [skill()],
+            "abilities": [ability()],
+            "cypherLimit": 3,
+            "cyphers": [cypher()],
+            "artifacts": [artifact()],
+            "money": 12.5,
+            "inventories": [{"uuid": "bag", "name": "Bag", "order": 1}],
+            "items": [item()],
+            "notes": [note()],
+        })
+    }
+
+    fn skill() -> Value {
+        json!({
+            "uuid": "skill",
+            "name": "Climbing",
+            "description": "Up walls",
+            "type": "might",
+            "level": "trained",
+        })
+    }
+
+    fn ability() -> Value {
+        json!({
+            "uuid": "ability",
+            "name": "Onslaught",
+            "cost": "1",
+            "type": "speed",
+            "enabler": true,
+            "description": "Attack twice",
+            "shortDescription": "Attack",
+        })
+    }
+
+    fn cypher() -> Value {
+        json!({
+            "uuid": "cypher",
+            "name": "Detonation",
+            "level": "1d6+2",
+            "shortDescription": "Explodes",
+            "effect": "Inflicts 3 damage",
+            "active": true,
+            "internal": "pill",
+        })
+    }
+
+    fn artifact() -> Value {
+        json!({
+            "uuid": "artifact",
+            "name": "Lightning Gloves",
+            "level": "5",
+            "effect": "Inflicts 4 damage",
+            "depletion": "1 in 1d20",
+            "form": "gloves",
+        })
+    }
+
+    fn item() -> Value {
+        json!({
+            "path": {"inventory": "bag", "parent": "box", "self": "item"},
+            "name": "Rope",
+            "description": "50 feet",
+            "types": ["tool", "armor"],
+            "amount": 2.5,
+            "value": 1.5,
+            "subItemType": "tool",
+            "armor": 1,
+        })
+    }
+
+    fn note() -> Value {
+        json!({
+            "uuid": "note",
+            "title": "Qi",
+            "type": "location",
+            "text": "A city",
+        })
+    }
+
+    #[rstest]
+    #[case::character_metadata(
+        round_trip::<CharacterMetadata>,
+        json!({
+            "uuid": "character",
+            "name": "Aria",
+            "revisions": [0, 1],
+            "storageSize": 42,
+        }),
+    )]
+    #[case::character(round_trip::<Character>, character())]
+    #[case::character_color(
+        round_trip::<CharacterColor>,
+        json!({"r": 255, "b": 1}),
+    )]
+    #[case::advancement_info(
+        round_trip::<AdvancementInfo>,
+        json!({"name": "Extra Effort", "description": "+1 Effort"}),
+    )]
+    #[case::advancements(
+        round_trip::<Advancements>,
+        json!({"other": true}),
+    )]
+    #[case::progress(
+        round_trip::<Progress>,
+        json!({"tier": 3, "freeXp": 4, "totalXp": 20, "maxEffort": 3}),
+    )]
+    #[case::stat(
+        round_trip::<Stat>,
+        json!({"type": "might", "cap": 12, "pool": 10, "edge": 1}),
+    )]
+    #[case::stats(
+        round_trip::<Stats>,
+        json!({"speed": {"type": "speed", "pool": 5}}),
+    )]
+    #[case::recovery(
+        round_trip::<Recovery>,
+        json!({"bonus": 1, "tenMinutes": true, "tenHours": true}),
+    )]
+    #[case::damage(
+        round_trip::<Damage>,
+        json!({"impaired": true, "debilitated": true}),
+    )]
+    #[case::skill(round_trip::<Skill>, skill())]
+    #[case::ability(round_trip::<Ability>, ability())]
+    #[case::cypher(round_trip::<Cypher>, cypher())]
+    #[case::artifact(round_trip::<Artifact>, artifact())]
+    #[case::inventory(
+        round_trip::<Inventory>,
+        json!({"uuid": "bag", "name": "Bag", "order": 2}),
+    )]
+    #[case::item_path(
+        round_trip::<ItemPath>,
+        json!({"inventory": "bag", "self": "item"}),
+    )]
+    #[case::item(round_trip::<Item>, item())]
+    #[case::item_with_default_oneofs(
+        round_trip::<Item>,
+        json!({"name": "Vest", "subItemType": "armor", "armor": 0}),
+    )]
+    #[case::note(round_trip::<Note>, note())]
+    #[case::shared_character(
+        round_trip::<SharedObject>,
+        json!({"uuid": "shared", "name": "Aria", "character": character()}),
+    )]
+    #[case::shared_item(
+        round_trip::<SharedObject>,
+        json!({"uuid": "shared", "item": item()}),
+    )]
+    #[case::shared_note(
+        round_trip::<SharedObject>,
+        json!({"uuid": "shared", "note": note()}),
+    )]
+    #[case::create_character(round_trip::<CreateCharacter>, json!({}))]
+    #[case::character_created(
+        round_trip::<CharacterCreated>,
+        json!({"uuid": "character"}),
+    )]
+    #[case::write_revision(
+        round_trip::<WriteRevision>,
+        json!({
+            "uuid": "character",
+            "character": character(),
+            "revision": "18446744073709551615",
+        }),
+    )]
+    #[case::revision_written(
+        round_trip::<RevisionWritten>,
+        json!({"uuid": "character", "revision": "3"}),
+    )]
+    #[case::read_revision(
+        round_trip::<ReadRevision>,
+        json!({"uuid": "character", "revision": "3"}),
+    )]
+    #[case::revision_read(
+        round_trip::<RevisionRead>,
+        json!({
+            "uuid": "character",
+            "revision": "3",
+            "character": character(),
+        }),
+    )]
+    #[case::read_latest_revision(
+        round_trip::<ReadLatestRevision>,
+        json!({"uuid": "character"}),
+    )]
+    #[case::delete_character(
+        round_trip::<DeleteCharacter>,
+        json!({"uuid": "character"}),
+    )]
+    #[case::character_deleted(round_trip::<CharacterDeleted>, json!({}))]
+    #[case::error_details(
+        round_trip::<ErrorDetails>,
+        json!({
+            "reason": "OUT_OF_ORDER",
+            "latestRevision": "4",
+            "fieldViolations": [
+                {"field": "character.uuid", "description": "must match"},
+            ],
+        }),
+    )]
+    #[case::field_violation(
+        round_trip::<FieldViolation>,
+        json!({"field": "character.uuid", "description": "must match"}),
+    )]
+    #[case::status(
+        round_trip::<Status>,
+        json!({"code": 5, "message": "character not found"}),
+    )]
+    #[case::server_reflection_request(
+        round_trip::<ServerReflectionRequest>,
+        json!({"host": "localhost", "listServices": "*"}),
+    )]
+    #[case::server_reflection_response(
+        round_trip::<ServerReflectionResponse>,
+        json!({
+            "validHost": "localhost",
+            "listServicesResponse": {
+                "service": [{"name": "characters.Characters"}],
+            },
+        }),
+    )]
+    fn round_trips_messages(#[case] check: fn(Value), #[case] value: Value) {
+        check(value);
+    }
+
+    fn round_trip_enum<E>(values: &[(E, &str)])
+    where
+        E: Serialize + DeserializeOwned + PartialEq + Debug + Copy,
+    {
+        for (value, name) in values {
+            assert_eq!(serde_json::to_value(value).unwrap(), json!(name));
+            assert_eq!(
+                serde_json::from_value::<E>(json!(name)).unwrap(),
+                *value
+            );
+        }
+    }
+
+    #[rstest]
+    fn round_trips_enums() {
+        round_trip_enum(&[
+            (Advancement::IncreaseCapabilities, "increaseCapabilities"),
+            (Advancement::MoveTowardPerfection, "moveTowardPerfection"),
+            (Advancement::ExtraEffort, "extraEffort"),
+            (Advancement::SkillTraining, "skillTraining"),
+            (Advancement::Other, "other"),
+        ]);
+        round_trip_enum(&[
+            (PoolType::Intellect, "intellect"),
+            (PoolType::Speed, "speed"),
+            (PoolType::Might, "might"),
+        ]);
+        round_trip_enum(&[
+            (Recover::OneAction, "one_action"),
+            (Recover::TenMinutes, "ten_minutes"),
+            (Recover::OneHour, "one_hour"),
+            (Recover::TenHours, "ten_hours"),
+        ]);
+        round_trip_enum(&[
+            (SkillLevel::Specialized, "specialized"),
+            (SkillLevel::Trained, "trained"),
+            (SkillLevel::Inability, "inability"),
+        ]);
+        round_trip_enum(&[
+            (CypherType::Cypher, "cypher"),
+            (CypherType::Artifact, "artifact"),
+        ]);
+        round_trip_enum(&[
+            (ItemType::Armor, "armor"),
+            (ItemType::Weapon, "weapon"),
+            (ItemType::Clothing, "clothing"),
+            (ItemType::Tool, "tool"),
+            (ItemType::Oddity, "oddity"),
+            (ItemType::Material, "material"),
+            (ItemType::Ammo, "ammo"),
+            (ItemType::Plan, "plan"),
+            (ItemType::Others, "others"),
+        ]);
+        round_trip_enum(&[
+            (NoteType::Misc, "misc"),
+            (NoteType::Location, "location"),
+            (NoteType::Character, "character"),
+            (NoteType::Item, "item"),
+            (NoteType::Quest, "quest"),
+        ]);
+        round_trip_enum(&[
+            (Reason::Unknown, "UNKNOWN"),
+            (Reason::NotFound, "NOT_FOUND"),
+            (Reason::OutOfOrder, "OUT_OF_ORDER"),
+            (Reason::ResourceExhausted, "RESOURCE_EXHAUSTED"),
+        ]);
+    }
+
+    #[rstest]
+    #[case(json!(2), Ok(PoolType::Might))]
+    #[case(json!("speed"), Ok(PoolType::Speed))]
+    #[case(json!("strength"), Err(()))]
+    #[case(json!(7), Err(()))]
+    #[case(json!(true), Err(()))]
+    fn parses_enum_names_and_numbers(
+        #[case] value: Value,
+        #[case] expected: Result<PoolType, ()>,
+    ) {
+        assert_eq!(
+            serde_json::from_value::<PoolType>(value).map_err(|_| ()),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn rejects_invalid_messages() {
+        let err =
+            serde_json::from_value::<Stat>(json!({"pool": "ten"})).unwrap_err();
+        assert!(err.to_string().contains("invalid digit"), "{err}");
+
+        let err =
+            serde_json::from_value::<Stat>(json!({"unknown": 1})).unwrap_err();
+        assert!(err.to_string().contains("unknown"), "{err}");
+    }
+}